@@ -0,0 +1,93 @@
+//! Measures the time of checking a set of representative files with `cargo bench`.
+//!
+//! The result of the previous run is kept in `target/lint-bench.txt`, and the output shows
+//! the change against it, so a regression is visible after running the benchmark on both
+//! versions of the code.
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 50;
+
+/// Files of a few typical shapes: mostly plain assignments, values with quotes, substitutions
+/// and comments, and a file with a problem on almost every line
+fn fixtures() -> Vec<(&'static str, String)> {
+    let simple = (0..1000)
+        .map(|i| format!("KEY_{:04}=value_{}\n", i, i))
+        .collect();
+
+    let mixed = (0..1000)
+        .map(|i| match i % 5 {
+            0 => format!("# Section {}\n", i),
+            1 => format!("DATABASE_URL_{:04}=postgres://app@db:5432/app_{}\n", i, i),
+            2 => format!("GREETING_{:04}=\"Hello, world {}\"\n", i, i),
+            3 => format!("PATH_{:04}=${{HOME}}/data/{}\n", i, i),
+            _ => format!("export TOKEN_{:04}='s3cr3t' # token {}\n", i, i),
+        })
+        .collect();
+
+    let invalid = (0..1000)
+        .map(|i| match i % 4 {
+            0 => format!("key-{}=value\n", i),
+            1 => format!(" KEY_{} = value \n", i),
+            2 => format!("KEY_{}=\"unclosed\n", i),
+            _ => format!("KEY_{}==value\n", i),
+        })
+        .collect();
+
+    vec![
+        (".env", simple),
+        (".env.mixed", mixed),
+        (".env.invalid", invalid),
+    ]
+}
+
+fn measure(name: &str, content: &str) -> Duration {
+    // A run before the measurement warms up the caches
+    dotenv_linter::lint_str(content, name);
+
+    let started = Instant::now();
+    for _ in 0..ITERATIONS {
+        dotenv_linter::lint_str(content, name);
+    }
+
+    started.elapsed() / ITERATIONS
+}
+
+fn baseline_path() -> PathBuf {
+    let target_dir = std::env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| String::from("target"));
+    PathBuf::from(target_dir).join("lint-bench.txt")
+}
+
+fn main() {
+    let path = baseline_path();
+    let previous = fs::read_to_string(&path).unwrap_or_default();
+    let mut current = String::new();
+
+    for (name, content) in fixtures() {
+        let elapsed = measure(name, &content);
+        let micros = elapsed.as_micros();
+        current.push_str(&format!("{} {}\n", name, micros));
+
+        let before = previous
+            .lines()
+            .filter_map(|line| line.split_once(' '))
+            .find(|(n, _)| *n == name)
+            .and_then(|(_, value)| value.parse::<u128>().ok());
+
+        match before {
+            Some(before) if before > 0 => println!(
+                "{:<14} {:>8} µs/file (before {} µs/file, {:+.1}%)",
+                name,
+                micros,
+                before,
+                (micros as f64 - before as f64) / before as f64 * 100.0
+            ),
+            _ => println!("{:<14} {:>8} µs/file", name, micros),
+        }
+    }
+
+    if let Err(e) = fs::write(&path, current) {
+        eprintln!("The result can't be saved to {}: {}", path.display(), e);
+    }
+}
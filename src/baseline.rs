@@ -0,0 +1,172 @@
+use crate::common::Warning;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// A fingerprint of a warning saved in a baseline file
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Entry {
+    file: String,
+    line: usize,
+    check: String,
+    message: String,
+    /// Baselines written by older versions have no fingerprints
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    fingerprint: String,
+}
+
+impl Entry {
+    fn new(warning: &Warning) -> Self {
+        Self {
+            file: warning.file_path().display().to_string(),
+            line: warning.line_number(),
+            check: warning.check_name.clone(),
+            message: warning.message().to_string(),
+            fingerprint: warning.fingerprint(),
+        }
+    }
+
+    /// Entries with fingerprints are matched by them, so they survive lines added above the
+    /// warnings. Otherwise lines are compared only if `ignore_lines` is false
+    fn matches(&self, other: &Self, ignore_lines: bool) -> bool {
+        if !self.fingerprint.is_empty() {
+            return self.fingerprint == other.fingerprint;
+        }
+
+        self.file == other.file
+            && self.check == other.check
+            && self.message == other.message
+            && (ignore_lines || self.line == other.line)
+    }
+}
+
+/// Warnings which existed when the baseline was written, they are not reported by later runs
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Baseline {
+    warnings: Vec<Entry>,
+}
+
+impl Baseline {
+    pub fn new(warnings: &[Warning]) -> Self {
+        Self {
+            warnings: warnings.iter().map(Entry::new).collect(),
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid baseline {}: {}", path.display(), e).into())
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(path, content + "\n")
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e).into())
+    }
+
+    /// Removes the warnings found in the baseline, each entry of the baseline suppresses one warning
+    pub fn filter(&self, warnings: Vec<Warning>, ignore_lines: bool) -> Vec<Warning> {
+        let mut is_used = vec![false; self.warnings.len()];
+
+        warnings
+            .into_iter()
+            .filter(|warning| {
+                let entry = Entry::new(warning);
+                let index = (0..self.warnings.len())
+                    .find(|&i| !is_used[i] && self.warnings[i].matches(&entry, ignore_lines));
+
+                match index {
+                    Some(i) => {
+                        is_used[i] = true;
+                        false
+                    }
+                    None => true,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::*;
+    use std::path::PathBuf;
+
+    fn warning(number: usize, check_name: &str) -> Warning {
+        let line = LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 10,
+            },
+            raw_string: String::from("foo=bar"),
+        };
+
+        Warning::new(line, check_name, String::from("The message"))
+    }
+
+    // A baseline written by an older version, without the fingerprints
+    fn old_baseline(warnings: &[Warning]) -> Baseline {
+        let mut baseline = Baseline::new(warnings);
+        for entry in baseline.warnings.iter_mut() {
+            entry.fingerprint = String::new();
+        }
+        baseline
+    }
+
+    #[test]
+    fn filter_test() {
+        let baseline = old_baseline(&[warning(1, "LowercaseKey"), warning(2, "LowercaseKey")]);
+        let warnings = vec![
+            warning(1, "LowercaseKey"),
+            warning(3, "LowercaseKey"),
+            warning(1, "UnorderedKey"),
+        ];
+
+        assert_eq!(
+            vec![warning(3, "LowercaseKey"), warning(1, "UnorderedKey")],
+            baseline.filter(warnings, false)
+        );
+    }
+
+    #[test]
+    fn filter_ignoring_lines_test() {
+        let baseline = old_baseline(&[warning(1, "LowercaseKey")]);
+        let warnings = vec![warning(2, "LowercaseKey"), warning(3, "LowercaseKey")];
+
+        assert_eq!(
+            vec![warning(3, "LowercaseKey")],
+            baseline.filter(warnings, true)
+        );
+    }
+
+    #[test]
+    fn filter_by_fingerprint_test() {
+        let baseline = Baseline::new(&[warning(1, "LowercaseKey")]);
+        let mut next = warning(3, "LowercaseKey");
+        next.occurrence = 1;
+
+        assert_eq!(
+            vec![next.clone()],
+            baseline.filter(vec![warning(2, "LowercaseKey"), next], false)
+        );
+    }
+
+    #[test]
+    fn write_and_read_test() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("baseline.json");
+        let baseline = Baseline::new(&[warning(1, "LowercaseKey")]);
+
+        baseline.write(&path).expect("write baseline");
+        assert_eq!(baseline, Baseline::from_file(&path).expect("read baseline"));
+
+        dir.close().expect("temp dir deleted");
+    }
+}
@@ -1,69 +1,658 @@
 use crate::common::*;
+use crate::config::Config;
+use crate::fixes;
+use std::collections::HashMap;
+use std::time::Instant;
 
+mod ansi_c_quote;
+mod assignment_shape;
+mod backslash_path;
+mod boolean_consistency;
+mod circular_substitution;
+mod comment_spacing;
+mod comment_style;
+mod commented_key;
+mod credential_in_url;
+mod cross_file_reference;
+mod duplicated_delimiter;
 mod duplicated_key;
+mod duration_unit;
+mod empty_file;
+mod empty_production_secret;
+mod empty_value_style;
 mod ending_blank_line;
+mod escaped_closing_quote;
+mod export_style;
 mod extra_blank_line;
+mod forbidden_key;
+mod inconsistent_ending_newline;
+mod inconsistent_export;
+mod inconsistent_value_type;
 mod incorrect_delimiter;
+mod index_gap;
+mod integer_range;
+mod invalid_control_comment;
+mod invisible_character;
+mod key_before_header;
+mod key_case_conflict;
+mod key_whitespace;
 mod key_without_value;
 mod leading_character;
+mod line_continuation;
+mod list_separator;
+mod localhost_value;
 mod lowercase_key;
+mod missing_path;
+mod multiline_value;
+mod normalized_collision;
+mod numbered_key;
+mod numeric_key_boolean_value;
+mod padded_number;
+mod pair_list;
+mod path_separator;
+mod port_value;
 mod quote_character;
+mod quoted_substitution;
+mod redundant_key_prefix_value;
+mod redundant_quotes;
+mod repeated_comment;
+mod required_keys;
+mod reserved_identifier;
+mod separator_consistency;
+mod shell_unsafe_value;
+mod smart_quote;
 mod space_character;
+mod split_assignment;
+mod swallowed_comment;
+mod systemd_compat;
+mod trailing_comma;
 mod trailing_whitespace;
+mod truncated_value;
+mod unchanged_example_value;
+mod unescaped_quote;
 mod unordered_key;
+mod unresolved_template;
+mod unused_key;
+mod url_scheme;
+mod url_trailing_slash;
+mod whitespace_report;
+mod whitespace_value;
+mod windows_expansion;
+
+pub use boolean_consistency::BooleanStyle;
+pub use comment_style::CommentStyle;
+pub(crate) use duplicated_key::normalized_value;
+pub use empty_value_style::EmptyValueStyle;
+pub use export_style::ExportStyle;
+pub use integer_range::IntegerWidth;
+pub use list_separator::ListSeparator;
+pub use path_separator::Platform;
+pub use reserved_identifier::Language;
+pub use separator_consistency::SeparatorStyle;
+pub(crate) use smart_quote::{smart_quoted_value, straighten_quotes};
+pub use url_trailing_slash::TrailingSlash;
+pub(crate) use whitespace_report::{find_whitespace, WhitespaceLocation};
+
+// Detailed description of a check, used to explain it to the user
+pub(crate) struct Explanation<'a> {
+    rationale: &'a str,
+    wrong: &'a str,
+    correct: &'a str,
+}
 
 // This trait is used for checks which needs to know of only a single line
-trait Check {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning>;
+trait Check<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning>;
     fn name(&self) -> &str;
+    fn template(&self) -> &str;
+    fn set_template(&mut self, template: &'a str);
+    fn explanation(&self) -> Explanation<'_>;
     fn skip_comments(&self) -> bool {
         true
     }
+    // Checks which can't report a plain `KEY=value` line and don't depend on the previous lines
+    // are not run on such lines
+    fn skip_simple_assignments(&self) -> bool {
+        false
+    }
+    // Optional checks are not run unless they are explicitly enabled
+    fn is_optional(&self) -> bool {
+        false
+    }
+    // Placeholders which were added to the template later, custom templates can leave them out.
+    // The key of such templates can be `{}` too, like in the templates without other placeholders
+    fn optional_placeholders(&self) -> &[&str] {
+        &[]
+    }
+}
+
+// This trait is used for checks which needs to know of all linted files at once
+trait MultiFileCheck<'a> {
+    fn run(&mut self, files: &[Vec<LineEntry>]) -> Vec<Warning>;
+    fn name(&self) -> &str;
+    fn template(&self) -> &str;
+    fn set_template(&mut self, template: &'a str);
+    fn explanation(&self) -> Explanation<'_>;
+    // Optional checks are not run unless they are explicitly enabled
+    fn is_optional(&self) -> bool {
+        false
+    }
 }
 
 // Checklist for checks which needs to know of only a single line
-fn checklist() -> Vec<Box<dyn Check>> {
-    vec![
-        Box::new(duplicated_key::DuplicatedKeyChecker::default()),
+fn checklist(config: &Config) -> Vec<Box<dyn Check<'_> + '_>> {
+    let mut checks: Vec<Box<dyn Check>> = vec![
+        Box::new(ansi_c_quote::AnsiCQuoteChecker::default()),
+        Box::new(assignment_shape::AssignmentShapeChecker::new(
+            &config.checks.assignment_shape,
+        )),
+        Box::new(backslash_path::BackslashPathChecker::default()),
+        Box::new(boolean_consistency::BooleanConsistencyChecker::new(
+            &config.checks.boolean_consistency,
+        )),
+        Box::new(circular_substitution::CircularSubstitutionChecker::default()),
+        Box::new(comment_spacing::CommentSpacingChecker::new(
+            &config.checks.comment_spacing,
+        )),
+        Box::new(comment_style::CommentStyleChecker::new(
+            &config.checks.comment_style,
+        )),
+        Box::new(commented_key::CommentedKeyChecker::new(
+            &config.checks.commented_key,
+        )),
+        Box::new(credential_in_url::CredentialInUrlChecker::default()),
+        Box::new(duplicated_delimiter::DuplicatedDelimiterChecker::default()),
+        Box::new(duplicated_key::DuplicatedKeyChecker::new(
+            &config.checks.duplicated_key,
+        )),
+        Box::new(duration_unit::DurationUnitChecker::new(
+            &config.checks.duration_unit,
+        )),
+        Box::new(empty_file::EmptyFileChecker::default()),
+        Box::new(empty_production_secret::EmptyProductionSecretChecker::new(
+            &config.checks.empty_production_secret,
+        )),
+        Box::new(empty_value_style::EmptyValueStyleChecker::new(
+            &config.checks.empty_value_style,
+        )),
         Box::new(ending_blank_line::EndingBlankLineChecker::default()),
+        Box::new(escaped_closing_quote::EscapedClosingQuoteChecker::default()),
+        Box::new(export_style::ExportStyleChecker::new(
+            &config.checks.export_style,
+        )),
         Box::new(extra_blank_line::ExtraBlankLineChecker::default()),
+        Box::new(forbidden_key::ForbiddenKeyChecker::new(
+            &config.checks.forbidden_key,
+        )),
+        Box::new(inconsistent_export::InconsistentExportChecker::default()),
         Box::new(incorrect_delimiter::IncorrectDelimiterChecker::default()),
+        Box::new(index_gap::IndexGapChecker::new(&config.checks.index_gap)),
+        Box::new(integer_range::IntegerRangeChecker::new(
+            &config.checks.integer_range,
+        )),
+        Box::new(invalid_control_comment::InvalidControlCommentChecker::default()),
+        Box::new(invisible_character::InvisibleCharacterChecker::default()),
+        Box::new(key_before_header::KeyBeforeHeaderChecker::new(
+            &config.checks.key_before_header,
+        )),
         Box::new(leading_character::LeadingCharacterChecker::default()),
+        Box::new(line_continuation::LineContinuationChecker::default()),
+        Box::new(key_whitespace::KeyWhitespaceChecker::default()),
         Box::new(key_without_value::KeyWithoutValueChecker::default()),
+        Box::new(list_separator::ListSeparatorChecker::new(
+            &config.checks.list_separator,
+        )),
+        Box::new(localhost_value::LocalhostValueChecker::new(
+            &config.checks.localhost_value,
+        )),
         Box::new(lowercase_key::LowercaseKeyChecker::default()),
+        Box::new(missing_path::MissingPathChecker::new(
+            &config.checks.missing_path,
+        )),
+        Box::new(multiline_value::MultilineValueChecker::default()),
+        Box::new(normalized_collision::NormalizedCollisionChecker::default()),
+        Box::new(numbered_key::NumberedKeyChecker::new(
+            &config.checks.numbered_key,
+        )),
+        Box::new(
+            numeric_key_boolean_value::NumericKeyBooleanValueChecker::new(
+                &config.checks.numeric_key_boolean_value,
+            ),
+        ),
+        Box::new(padded_number::PaddedNumberChecker::default()),
+        Box::new(pair_list::PairListChecker::new(&config.checks.pair_list)),
+        Box::new(path_separator::PathSeparatorChecker::new(
+            &config.checks.path_separator,
+        )),
+        Box::new(port_value::PortValueChecker::new(&config.checks.port_value)),
         Box::new(quote_character::QuoteCharacterChecker::default()),
-        Box::new(space_character::SpaceCharacterChecker::default()),
+        Box::new(quoted_substitution::QuotedSubstitutionChecker::default()),
+        Box::new(redundant_key_prefix_value::RedundantKeyPrefixValueChecker::default()),
+        Box::new(redundant_quotes::RedundantQuotesChecker::default()),
+        Box::new(repeated_comment::RepeatedCommentChecker::new(
+            &config.checks.repeated_comment,
+        )),
+        Box::new(required_keys::RequiredKeysChecker::new(
+            &config.checks.required_keys,
+        )),
+        Box::new(reserved_identifier::ReservedIdentifierChecker::new(
+            &config.checks.reserved_identifier,
+        )),
+        Box::new(separator_consistency::SeparatorConsistencyChecker::new(
+            &config.checks.separator_consistency,
+        )),
+        Box::new(shell_unsafe_value::ShellUnsafeValueChecker::default()),
+        Box::new(smart_quote::SmartQuoteChecker::default()),
+        Box::new(space_character::SpaceCharacterChecker::new(
+            &config.checks.space_character,
+        )),
+        Box::new(split_assignment::SplitAssignmentChecker::default()),
+        Box::new(swallowed_comment::SwallowedCommentChecker::default()),
+        Box::new(systemd_compat::SystemdCompatChecker::default()),
+        Box::new(trailing_comma::TrailingCommaChecker::default()),
         Box::new(trailing_whitespace::TrailingWhitespaceChecker::default()),
+        Box::new(truncated_value::TruncatedValueChecker::default()),
+        Box::new(unescaped_quote::UnescapedQuoteChecker::default()),
         Box::new(unordered_key::UnorderedKeyChecker::default()),
-    ]
+        Box::new(unresolved_template::UnresolvedTemplateChecker::new(
+            &config.checks.unresolved_template,
+        )),
+        Box::new(url_scheme::UrlSchemeChecker::new(&config.checks.url_scheme)),
+        Box::new(url_trailing_slash::UrlTrailingSlashChecker::new(
+            &config.checks.url_trailing_slash,
+        )),
+        Box::new(whitespace_report::WhitespaceReportChecker::default()),
+        Box::new(whitespace_value::WhitespaceValueChecker::default()),
+        Box::new(windows_expansion::WindowsExpansionChecker::default()),
+    ];
+
+    for check in &mut checks {
+        if let Some(template) = config.templates.get(check.name()) {
+            check.set_template(template);
+        }
+    }
+
+    checks
+}
+
+// Checklist for checks which needs to know of all linted files at once
+fn multi_file_checklist(config: &Config) -> Vec<Box<dyn MultiFileCheck<'_> + '_>> {
+    let mut checks: Vec<Box<dyn MultiFileCheck>> = vec![
+        Box::new(cross_file_reference::CrossFileReferenceChecker::default()),
+        Box::new(inconsistent_ending_newline::InconsistentEndingNewlineChecker::default()),
+        Box::new(inconsistent_value_type::InconsistentValueTypeChecker::default()),
+        Box::new(key_case_conflict::KeyCaseConflictChecker::default()),
+        Box::new(unchanged_example_value::UnchangedExampleValueChecker::new(
+            &config.checks.unchanged_example_value,
+        )),
+        Box::new(unused_key::UnusedKeyChecker::default()),
+    ];
+
+    for check in &mut checks {
+        if let Some(template) = config.templates.get(check.name()) {
+            check.set_template(template);
+        }
+    }
+
+    checks
 }
 
 pub fn available_check_names() -> Vec<String> {
-    checklist()
+    let mut names: Vec<String> = checklist(&Config::default())
         .iter()
         .map(|check| check.name().to_string())
-        .collect()
+        .chain(
+            multi_file_checklist(&Config::default())
+                .iter()
+                .map(|check| check.name().to_string()),
+        )
+        .collect();
+
+    names.sort();
+    names
 }
 
-pub fn run(lines: &[LineEntry], skip_checks: &[&str]) -> Vec<Warning> {
-    let mut checks = checklist();
-    checks.retain(|c| !skip_checks.contains(&c.name()));
+/// Returns the sorted names of the checks which are run with the config
+pub fn active_check_names(config: &Config) -> Vec<String> {
+    let mut names: Vec<String> = checklist(config)
+        .iter()
+        .filter(|check| is_enabled(check.name(), check.is_optional(), config))
+        .map(|check| check.name().to_string())
+        .chain(
+            multi_file_checklist(config)
+                .iter()
+                .filter(|check| is_enabled(check.name(), check.is_optional(), config))
+                .map(|check| check.name().to_string()),
+        )
+        .collect();
+
+    names.sort();
+    names
+}
+
+/// Returns a detailed description of the check: its rationale and examples
+pub fn explain(check_name: &str) -> Option<String> {
+    let config = Config::default();
+    let checks = checklist(&config);
+    let multi_file_checks = multi_file_checklist(&config);
+
+    let explanation = checks
+        .iter()
+        .find(|c| c.name() == check_name)
+        .map(|c| c.explanation())
+        .or_else(|| {
+            multi_file_checks
+                .iter()
+                .find(|c| c.name() == check_name)
+                .map(|c| c.explanation())
+        })?;
+
+    let fix = if fixes::available_fix_names().iter().any(|n| n == check_name) {
+        "Can be fixed automatically with the --fix argument"
+    } else {
+        "Can't be fixed automatically"
+    };
+
+    Some(format!(
+        "{}\n\n{}\n\nWrong:\n{}\n\nCorrect:\n{}\n\nFix: {}",
+        check_name, explanation.rationale, explanation.wrong, explanation.correct, fix
+    ))
+}
+
+/// Runs a single check by its name against the line with the default config, e.g. in the tests
+/// of editor plugins. Optional checks are run too. Checks which report a whole file do it
+/// on its last line, and the multi-file checks get the line as the only line of the only file
+pub fn run_check(check_name: &str, line: &LineEntry) -> Result<Vec<Warning>, String> {
+    let config = Config::default();
+    let mut checks = checklist(&config);
+    let mut multi_file_checks = multi_file_checklist(&config);
+
+    if let Some(check) = checks.iter_mut().find(|c| c.name() == check_name) {
+        if line.is_comment() && check.skip_comments() {
+            return Ok(Vec::new());
+        }
+
+        return Ok(check.run(line));
+    }
+
+    multi_file_checks
+        .iter_mut()
+        .find(|c| c.name() == check_name)
+        .map(|check| check.run(&[vec![line.clone()]]))
+        .ok_or_else(|| with_suggestion(format!("Unknown check: {}", check_name), check_name))
+}
+
+pub fn run(lines: &[LineEntry], config: &Config) -> Vec<Warning> {
+    run_with_stats(lines, config, &mut Stats::default())
+}
+
+/// Runs the checks and adds the time spent by each check to `stats`
+pub fn run_with_stats(lines: &[LineEntry], config: &Config, stats: &mut Stats) -> Vec<Warning> {
+    let mut checks = checklist(config);
+    checks.retain(|c| is_enabled(c.name(), c.is_optional(), config));
 
     let mut warnings: Vec<Warning> = Vec::new();
+    let mut ignores = Ignores::default();
 
     for line in lines {
         let is_comment = line.is_comment();
+        let is_simple = line.is_simple_assignment();
+        if let Some(comment) = Comment::parse(&line.raw_string) {
+            ignores.update(&comment);
+        }
+
         for ch in &mut checks {
-            if is_comment && ch.skip_comments() {
+            if (is_comment && ch.skip_comments()) || (is_simple && ch.skip_simple_assignments()) {
                 continue;
             }
-            if let Some(warning) = ch.run(line) {
-                warnings.push(warning);
+
+            let started = Instant::now();
+            let result = ch.run(line);
+            stats.add_check(ch.name(), started.elapsed(), result.len());
+
+            // Checks still run on ignored lines, since they can depend on the previous lines
+            if ignores.contains(ch.name()) {
+                stats.suppressed += result.len();
+                continue;
             }
+
+            warnings.extend(result);
+        }
+    }
+
+    if config.short_circuit {
+        warnings = short_circuit(warnings, config);
+    }
+
+    warnings
+}
+
+/// Removes the warnings of the checks which are lower in the priority than another check
+/// with a warning on the same line. The checks are still run on all lines, since some of
+/// them depend on the previous lines
+fn short_circuit(warnings: Vec<Warning>, config: &Config) -> Vec<Warning> {
+    let mut top_priorities: HashMap<usize, usize> = HashMap::new();
+    for warning in &warnings {
+        if let Some(priority) = config.priority_of(&warning.check_name) {
+            let top = top_priorities
+                .entry(warning.line_number())
+                .or_insert(priority);
+            *top = (*top).min(priority);
         }
     }
 
     warnings
+        .into_iter()
+        .filter(|w| match config.priority_of(&w.check_name) {
+            Some(priority) => top_priorities.get(&w.line_number()) == Some(&priority),
+            None => true,
+        })
+        .collect()
+}
+
+/// Runs the checks which needs to know of all linted files at once
+/// Checks if one of the checks comparing several files is enabled
+pub fn has_multi_file_checks(config: &Config) -> bool {
+    multi_file_checklist(config)
+        .iter()
+        .any(|c| is_enabled(c.name(), c.is_optional(), config))
+}
+
+pub fn run_multi_file(
+    files: &[Vec<LineEntry>],
+    config: &Config,
+    stats: &mut Stats,
+) -> Vec<Warning> {
+    let mut checks = multi_file_checklist(config);
+    checks.retain(|c| is_enabled(c.name(), c.is_optional(), config));
+
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for ch in &mut checks {
+        let started = Instant::now();
+        let result = ch.run(files);
+        stats.add_check(ch.name(), started.elapsed(), result.len());
+
+        let (ignored, result): (Vec<Warning>, Vec<Warning>) =
+            result.into_iter().partition(|w| is_ignored(files, w));
+        stats.suppressed += ignored.len();
+
+        warnings.extend(result);
+    }
+
+    warnings
+}
+
+/// Checks if the warning is turned off by control comments up to its line
+fn is_ignored(files: &[Vec<LineEntry>], warning: &Warning) -> bool {
+    let lines = match files
+        .iter()
+        .find(|lines| lines.first().map(|l| l.file.path.as_path()) == Some(warning.file_path()))
+    {
+        Some(lines) => lines,
+        None => return false,
+    };
+
+    let mut ignores = Ignores::default();
+    lines
+        .iter()
+        .take_while(|line| line.number <= warning.line_number())
+        .filter_map(|line| Comment::parse(&line.raw_string))
+        .for_each(|comment| ignores.update(&comment));
+
+    ignores.contains(&warning.check_name)
+}
+
+/// Checks that the config refers only to the known checks, e.g. after a check was removed
+pub fn validate_config(config: &Config) -> Result<(), String> {
+    let names = available_check_names();
+    let sections = [
+        ("skip", &config.skip),
+        ("enable", &config.enable),
+        ("only", &config.only),
+        ("priority", &config.priority),
+    ];
+
+    for (section, check_names) in sections {
+        if let Some(name) = check_names.iter().find(|n| !names.contains(n)) {
+            return Err(unknown_check_error(section, name));
+        }
+    }
+
+    if let Some(name) = config.severity.keys().find(|n| !names.contains(n)) {
+        return Err(unknown_check_error("severity", name));
+    }
+
+    // All profiles are checked, not only the one which is used
+    for (profile_name, profile) in &config.profiles {
+        let sections = [("skip", &profile.skip), ("enable", &profile.enable)];
+        for (section, check_names) in sections {
+            if let Some(name) = check_names.iter().find(|n| !names.contains(n)) {
+                let section = format!("profiles.{}.{}", profile_name, section);
+                return Err(unknown_check_error(&section, name));
+            }
+        }
+
+        if let Some(name) = profile.severity.keys().find(|n| !names.contains(n)) {
+            let section = format!("profiles.{}.severity", profile_name);
+            return Err(unknown_check_error(&section, name));
+        }
+    }
+
+    validate_templates(config)
+}
+
+pub(crate) fn unknown_check_error(section: &str, check_name: &str) -> String {
+    with_suggestion(
+        format!("Unknown check in {}: {}", section, check_name),
+        check_name,
+    )
+}
+
+// Adds the closest known check name to the error message about an unknown check
+fn with_suggestion(message: String, check_name: &str) -> String {
+    match suggest_check_name(check_name) {
+        Some(suggestion) => format!("{}, did you mean {}?", message, suggestion),
+        None => message,
+    }
+}
+
+/// Returns the closest known check name, if it differs only by a few characters
+fn suggest_check_name(check_name: &str) -> Option<String> {
+    let max_distance = (check_name.chars().count() / 3).max(2);
+    available_check_names()
+        .into_iter()
+        .map(|name| {
+            (
+                edit_distance(&name.to_lowercase(), &check_name.to_lowercase()),
+                name,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name)
+}
+
+// The Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Checks that the custom templates from the config belong to the known checks
+/// and contain the placeholders of the default templates
+pub fn validate_templates(config: &Config) -> Result<(), String> {
+    let default_config = Config::default();
+    let checks = checklist(&default_config);
+    let multi_file_checks = multi_file_checklist(&default_config);
+
+    for (check_name, template) in &config.templates {
+        let (default_template, optional) = checks
+            .iter()
+            .find(|c| c.name() == check_name)
+            .map(|c| (c.template(), c.optional_placeholders()))
+            .or_else(|| {
+                multi_file_checks
+                    .iter()
+                    .find(|c| c.name() == check_name)
+                    .map(|c| (c.template(), &[][..]))
+            })
+            .ok_or_else(|| unknown_check_error("templates", check_name))?;
+
+        let is_legacy_key = |p: &str| !optional.is_empty() && p == "{1}" && template.contains("{}");
+        if let Some(placeholder) = get_placeholders(default_template)
+            .into_iter()
+            .filter(|p| !optional.contains(p))
+            .find(|p| !template.contains(p) && !is_legacy_key(p))
+        {
+            return Err(format!(
+                "The template for the {} check should contain the {} placeholder",
+                check_name, placeholder
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// Returns placeholders like `{}` or `{1}` used in the template
+fn get_placeholders(template: &str) -> Vec<&str> {
+    let mut placeholders = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        match rest[start..].find('}') {
+            Some(end) => {
+                placeholders.push(&rest[start..=start + end]);
+                rest = &rest[start + end + 1..];
+            }
+            None => break,
+        }
+    }
+
+    placeholders
+}
+
+// Optional checks are run only if they are enabled, any check can be skipped
+fn is_enabled(name: &str, is_optional: bool, config: &Config) -> bool {
+    let contains = |names: &[String]| names.iter().any(|n| n == name);
+
+    if !config.only.is_empty() {
+        return contains(&config.only) && !contains(&config.skip);
+    }
+
+    (!is_optional || contains(&config.enable)) && !contains(&config.skip)
 }
 
 #[cfg(test)]
@@ -95,22 +684,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn active_check_names_test() {
+        let config = Config {
+            skip: vec![String::from("LowercaseKey")],
+            enable: vec![String::from("ShellUnsafeValue"), String::from("UnusedKey")],
+            ..Config::default()
+        };
+        let names = active_check_names(&config);
+
+        assert!(names.contains(&String::from("DuplicatedKey")));
+        assert!(names.contains(&String::from("ShellUnsafeValue")));
+        assert!(names.contains(&String::from("UnusedKey")));
+        assert!(!names.contains(&String::from("LowercaseKey")));
+        assert!(!names.contains(&String::from("NumberedKey")));
+    }
+
+    #[test]
+    fn skip_simple_assignments_test() {
+        let config = Config::default();
+        let lines = [
+            "FOO=bar",
+            "DATABASE_URL=postgres://user@db:5432/app",
+            "HOSTS=a.example.com,b.example.com",
+            "FOO_2=",
+        ];
+
+        for check in checklist(&config)
+            .iter_mut()
+            .filter(|c| c.skip_simple_assignments())
+        {
+            for str in lines {
+                assert!(
+                    check.run(&line_entry(1, 1, str)).is_empty(),
+                    "{} reports the simple assignment {}",
+                    check.name(),
+                    str
+                );
+            }
+        }
+    }
+
     #[test]
     fn run_with_empty_vec_test() {
         let empty: Vec<LineEntry> = Vec::new();
         let expected: Vec<Warning> = Vec::new();
-        let skip_checks: Vec<&str> = Vec::new();
 
-        assert_eq!(expected, run(&empty, &skip_checks));
+        assert_eq!(expected, run(&empty, &Config::default()));
     }
 
     #[test]
     fn run_with_empty_line_test() {
         let lines: Vec<LineEntry> = vec![blank_line_entry(1, 1)];
         let expected: Vec<Warning> = Vec::new();
-        let skip_checks: Vec<&str> = Vec::new();
 
-        assert_eq!(expected, run(&lines, &skip_checks));
+        assert_eq!(expected, run(&lines, &Config::default()));
     }
 
     #[test]
@@ -120,18 +748,67 @@ mod tests {
             blank_line_entry(2, 2),
         ];
         let expected: Vec<Warning> = Vec::new();
-        let skip_checks: Vec<&str> = Vec::new();
 
-        assert_eq!(expected, run(&lines, &skip_checks));
+        assert_eq!(expected, run(&lines, &Config::default()));
     }
 
     #[test]
     fn run_with_valid_line_test() {
         let lines: Vec<LineEntry> = vec![line_entry(1, 2, "FOO=BAR"), blank_line_entry(2, 2)];
         let expected: Vec<Warning> = Vec::new();
-        let skip_checks: Vec<&str> = Vec::new();
 
-        assert_eq!(expected, run(&lines, &skip_checks));
+        assert_eq!(expected, run(&lines, &Config::default()));
+    }
+
+    #[test]
+    fn run_with_control_comments_test() {
+        let lines: Vec<LineEntry> = vec![
+            line_entry(1, 5, "# dotenv-linter:off LowercaseKey"),
+            line_entry(2, 5, "foo=bar"),
+            line_entry(3, 5, "# dotenv-linter:on"),
+            line_entry(4, 5, "bar=foo"),
+            blank_line_entry(5, 5),
+        ];
+        let mut config = Config::default();
+        config.skip.push(String::from("UnorderedKey"));
+        let mut stats = Stats::default();
+
+        let expected = vec![Warning::new(
+            lines[3].clone(),
+            "LowercaseKey",
+            String::from("The bar key should be in uppercase"),
+        )];
+
+        assert_eq!(expected, run_with_stats(&lines, &config, &mut stats));
+        assert_eq!(1, stats.suppressed);
+    }
+
+    #[test]
+    fn run_with_short_circuit_test() {
+        let lines: Vec<LineEntry> = vec![
+            line_entry(1, 3, " foo-bar=x"),
+            line_entry(2, 3, "baz=x"),
+            blank_line_entry(3, 3),
+        ];
+        let config = Config {
+            skip: vec![String::from("UnorderedKey")],
+            short_circuit: true,
+            ..Config::default()
+        };
+        let expected = vec![
+            Warning::new(
+                lines[0].clone(),
+                "LeadingCharacter",
+                String::from("Invalid leading character detected"),
+            ),
+            Warning::new(
+                lines[1].clone(),
+                "LowercaseKey",
+                String::from("The baz key should be in uppercase"),
+            ),
+        ];
+
+        assert_eq!(expected, run(&lines, &config));
     }
 
     #[test]
@@ -144,9 +821,8 @@ mod tests {
         );
         let lines: Vec<LineEntry> = vec![line, blank_line_entry(2, 2)];
         let expected: Vec<Warning> = vec![warning];
-        let skip_checks: Vec<&str> = Vec::new();
 
-        assert_eq!(expected, run(&lines, &skip_checks));
+        assert_eq!(expected, run(&lines, &Config::default()));
     }
 
     #[test]
@@ -159,9 +835,8 @@ mod tests {
         );
         let lines: Vec<LineEntry> = vec![line];
         let expected: Vec<Warning> = vec![warning];
-        let skip_checks: Vec<&str> = Vec::new();
 
-        assert_eq!(expected, run(&lines, &skip_checks));
+        assert_eq!(expected, run(&lines, &Config::default()));
     }
 
     #[test]
@@ -175,9 +850,12 @@ mod tests {
         );
         let lines: Vec<LineEntry> = vec![line1, line2, blank_line_entry(3, 3)];
         let expected: Vec<Warning> = vec![warning];
-        let skip_checks: Vec<&str> = vec!["KeyWithoutValue"];
+        let config = Config {
+            skip: vec![String::from("KeyWithoutValue")],
+            ..Config::default()
+        };
 
-        assert_eq!(expected, run(&lines, &skip_checks));
+        assert_eq!(expected, run(&lines, &config));
     }
 
     #[test]
@@ -185,17 +863,269 @@ mod tests {
         let line = line_entry(1, 1, "FOO");
         let lines: Vec<LineEntry> = vec![line];
         let expected: Vec<Warning> = Vec::new();
-        let skip_checks: Vec<&str> = vec!["KeyWithoutValue", "EndingBlankLine"];
+        let config = Config {
+            skip: vec![
+                String::from("KeyWithoutValue"),
+                String::from("EndingBlankLine"),
+            ],
+            ..Config::default()
+        };
 
-        assert_eq!(expected, run(&lines, &skip_checks));
+        assert_eq!(expected, run(&lines, &config));
+    }
+
+    #[test]
+    fn optional_check_is_disabled_by_default() {
+        let lines: Vec<LineEntry> = vec![line_entry(1, 2, "FOO=\"BAR\""), blank_line_entry(2, 2)];
+        let config = Config {
+            skip: vec![String::from("QuoteCharacter")],
+            ..Config::default()
+        };
+        let expected: Vec<Warning> = Vec::new();
+
+        assert_eq!(expected, run(&lines, &config));
+    }
+
+    #[test]
+    fn enable_optional_check() {
+        let line = line_entry(1, 2, "FOO=\"BAR\"");
+        let warning = Warning::new(
+            line.clone(),
+            "RedundantQuotes",
+            String::from("The FOO key has a value with redundant quotes"),
+        );
+        let lines: Vec<LineEntry> = vec![line, blank_line_entry(2, 2)];
+        let expected: Vec<Warning> = vec![warning];
+        let config = Config {
+            skip: vec![String::from("QuoteCharacter")],
+            ..Config::default()
+        };
+        let config = Config {
+            enable: vec![String::from("RedundantQuotes")],
+            ..config
+        };
+
+        assert_eq!(expected, run(&lines, &config));
+    }
+
+    #[test]
+    fn only_checks_test() {
+        let line = line_entry(1, 2, "foo=\"bar\"");
+        let lines: Vec<LineEntry> = vec![line.clone(), blank_line_entry(2, 2)];
+        let config = Config {
+            only: vec![
+                String::from("LowercaseKey"),
+                String::from("RedundantQuotes"),
+            ],
+            skip: vec![String::from("LowercaseKey")],
+            ..Config::default()
+        };
+        let expected = vec![Warning::new(
+            line,
+            "RedundantQuotes",
+            String::from("The foo key has a value with redundant quotes"),
+        )];
+
+        assert_eq!(expected, run(&lines, &config));
     }
 
     #[test]
     fn check_name_list() {
         let available_check_names = available_check_names();
-        for check in checklist() {
+        for check in checklist(&Config::default()) {
             let check_name = check.name();
             assert!(available_check_names.contains(&check_name.to_string()));
         }
+        for check in multi_file_checklist(&Config::default()) {
+            let check_name = check.name();
+            assert!(available_check_names.contains(&check_name.to_string()));
+        }
+    }
+
+    #[test]
+    fn custom_template() {
+        let line = line_entry(1, 2, "foo=BAR");
+        let warning = Warning::new(
+            line.clone(),
+            "LowercaseKey",
+            String::from("Key foo is not uppercase, see TICKET-1"),
+        );
+        let lines: Vec<LineEntry> = vec![line, blank_line_entry(2, 2)];
+        let mut config = Config::default();
+        config.templates.insert(
+            String::from("LowercaseKey"),
+            String::from("Key {} is not uppercase, see TICKET-1"),
+        );
+
+        assert_eq!(vec![warning], run(&lines, &config));
+    }
+
+    #[test]
+    fn validate_templates_test() {
+        let mut config = Config::default();
+        config.templates.insert(
+            String::from("UnorderedKey"),
+            String::from("{1} should be before {2}"),
+        );
+        config.templates.insert(
+            String::from("EndingBlankLine"),
+            String::from("Add a blank line"),
+        );
+        assert_eq!(Ok(()), validate_templates(&config));
+
+        config.templates.insert(
+            String::from("UnorderedKey"),
+            String::from("{1} is unordered"),
+        );
+        assert_eq!(
+            Err(String::from(
+                "The template for the UnorderedKey check should contain the {2} placeholder"
+            )),
+            validate_templates(&config)
+        );
+    }
+
+    #[test]
+    fn validate_templates_with_optional_placeholders_test() {
+        let mut config = Config::default();
+        for template in [
+            "{} is duplicated",
+            "{1} is duplicated",
+            "{1} is duplicated{2}",
+        ] {
+            config
+                .templates
+                .insert(String::from("DuplicatedKey"), String::from(template));
+            assert_eq!(
+                Ok(()),
+                validate_templates(&config),
+                "Template: {}",
+                template
+            );
+        }
+
+        config.templates.insert(
+            String::from("DuplicatedKey"),
+            String::from("The key is duplicated{2}"),
+        );
+        assert_eq!(
+            Err(String::from(
+                "The template for the DuplicatedKey check should contain the {1} placeholder"
+            )),
+            validate_templates(&config)
+        );
+    }
+
+    #[test]
+    fn validate_templates_with_unknown_check() {
+        let mut config = Config::default();
+        config
+            .templates
+            .insert(String::from("UnknownCheck"), String::from("{}"));
+
+        assert_eq!(
+            Err(String::from("Unknown check in templates: UnknownCheck")),
+            validate_templates(&config)
+        );
+    }
+
+    #[test]
+    fn validate_config_test() {
+        let mut config = Config::default();
+        config.skip.push(String::from("UnorderedKey"));
+        config.enable.push(String::from("PortValue"));
+        assert_eq!(Ok(()), validate_config(&config));
+
+        config.enable.push(String::from("PortValues"));
+        assert_eq!(
+            Err(String::from(
+                "Unknown check in enable: PortValues, did you mean PortValue?"
+            )),
+            validate_config(&config)
+        );
+
+        let mut config = Config::default();
+        config
+            .severity
+            .insert(String::from("DuplicatedKeys"), Severity::Error);
+        assert_eq!(
+            Err(String::from(
+                "Unknown check in severity: DuplicatedKeys, did you mean DuplicatedKey?"
+            )),
+            validate_config(&config)
+        );
+
+        let mut config = Config::default();
+        config.priority.push(String::from("Whatever"));
+        assert_eq!(
+            Err(String::from("Unknown check in priority: Whatever")),
+            validate_config(&config)
+        );
+    }
+
+    #[test]
+    fn edit_distance_test() {
+        assert_eq!(0, edit_distance("abc", "abc"));
+        assert_eq!(1, edit_distance("abc", "abd"));
+        assert_eq!(1, edit_distance("abc", "ab"));
+        assert_eq!(3, edit_distance("", "abc"));
+        assert_eq!(3, edit_distance("kitten", "sitting"));
+    }
+
+    #[test]
+    fn explain_check() {
+        let explanation = explain("LowercaseKey").expect("LowercaseKey explanation");
+
+        assert!(explanation.starts_with("LowercaseKey\n\n"));
+        assert!(explanation.contains("Wrong:\nFOo_BAR=FOOBAR"));
+        assert!(explanation.ends_with("Fix: Can be fixed automatically with the --fix argument"));
+    }
+
+    #[test]
+    fn explain_unfixable_check() {
+        let explanation = explain("UnorderedKey").expect("UnorderedKey explanation");
+
+        assert!(explanation.ends_with("Fix: Can't be fixed automatically"));
+    }
+
+    #[test]
+    fn explain_unknown_check() {
+        assert_eq!(None, explain("UnknownCheck"));
+    }
+
+    #[test]
+    fn run_check_test() {
+        let line = line_entry(1, 1, "foo=bar");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "LowercaseKey",
+            String::from("The foo key should be in uppercase"),
+        )];
+
+        assert_eq!(Ok(expected), run_check("LowercaseKey", &line));
+        assert_eq!(Ok(Vec::new()), run_check("DuplicatedKey", &line));
+        assert_eq!(
+            Ok(Vec::new()),
+            run_check("LowercaseKey", &line_entry(1, 1, "# foo=bar"))
+        );
+    }
+
+    #[test]
+    fn run_optional_check_test() {
+        let line = line_entry(1, 1, "DB_PORT=99999");
+
+        assert_eq!(1, run_check("PortValue", &line).expect("PortValue").len());
+    }
+
+    #[test]
+    fn run_unknown_check_test() {
+        let line = line_entry(1, 1, "FOO=bar");
+
+        assert_eq!(
+            Err(String::from(
+                "Unknown check: LowercaseKeys, did you mean LowercaseKey?"
+            )),
+            run_check("LowercaseKeys", &line)
+        );
     }
 }
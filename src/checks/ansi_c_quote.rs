@@ -0,0 +1,123 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct AnsiCQuoteChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl AnsiCQuoteChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{1}", key)
+    }
+}
+
+impl Default for AnsiCQuoteChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "AnsiCQuote",
+            template: "The {1} key has a value with the `$'...'` quoting of bash, dotenv loaders read it as is",
+        }
+    }
+}
+
+impl<'a> Check<'a> for AnsiCQuoteChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        // `$'` is literal inside quotes, in bash as well as in dotenv loaders
+        let (key, value) = match (parsed.key, parsed.value, parsed.quote) {
+            (Some(key), Some(value), None) => (key, value),
+            _ => return warnings,
+        };
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => &value[..index],
+            None => value,
+        };
+
+        if value.contains("$'") {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "`$'...'` is the ANSI-C quoting of bash: a shell which sources the file reads `$'line1\\nline2'` as two lines, while dotenv loaders keep the `$`, the quotes and the backslash in the value. Double quotes with `\\n` are read as a newline by most loaders, and a file sourced by a shell only can keep the check disabled.",
+            wrong: "FOO=$'line1\\nline2'",
+            correct: "FOO=\"line1\\nline2\"",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn ansi_c_quote_test() {
+        let mut checker = AnsiCQuoteChecker::default();
+        let line = line_entry("FOO=$'line1\\nline2' # two lines");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "AnsiCQuote",
+            String::from(
+                "The FOO key has a value with the `$'...'` quoting of bash, dotenv loaders read it as is",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert_eq!(1, checker.run(&line_entry("SEPARATOR=a$'\\t'b")).len());
+    }
+
+    #[test]
+    fn single_quotes_test() {
+        let mut checker = AnsiCQuoteChecker::default();
+
+        for str in [
+            "FOO='line1\\nline2'",
+            "FOO=\"price: $'5'\"",
+            "FOO='$'",
+            "FOO=$BAR",
+            "FOO=bar # it's $'x'",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+}
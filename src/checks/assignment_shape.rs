@@ -0,0 +1,163 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::AssignmentShapeConfig;
+
+pub(crate) struct AssignmentShapeChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    min_key_length: usize,
+    max_empty_key_length: usize,
+}
+
+impl AssignmentShapeChecker<'_> {
+    pub(crate) fn new(config: &AssignmentShapeConfig) -> Self {
+        Self {
+            min_key_length: config.min_key_length,
+            max_empty_key_length: config.max_empty_key_length,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str, anomaly: &str) -> String {
+        self.template.replace("{1}", key).replace("{2}", anomaly)
+    }
+
+    /// Returns the anomaly of a key and its value, if the shape of the line is suspicious
+    fn find_anomaly(&self, key: &str, value: &str) -> Option<String> {
+        let length = key.chars().count();
+        let characters = |n: usize| if n == 1 { "character" } else { "characters" };
+
+        if length < self.min_key_length {
+            Some(format!("a key of {} {}", length, characters(length)))
+        } else if value.is_empty() && length > self.max_empty_key_length {
+            Some(format!("an empty value and a key of {} characters", length))
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for AssignmentShapeChecker<'_> {
+    fn default() -> Self {
+        let config = AssignmentShapeConfig::default();
+        Self {
+            name: "AssignmentShape",
+            template: "The {1} key has a suspicious shape: {2}, the line may be generated wrong",
+            min_key_length: config.min_key_length,
+            max_empty_key_length: config.max_empty_key_length,
+        }
+    }
+}
+
+impl<'a> Check<'a> for AssignmentShapeChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value, parsed.has_delimiter) {
+            (Some(key), Some(value), true) => (key, value),
+            _ => return warnings,
+        };
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value);
+
+        if let Some(anomaly) = self.find_anomaly(key, value) {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(key, &anomaly),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "In generated files a single-character key or a very long key without a value usually means the generator split a line in the wrong place, e.g. a value with `=` or a whole line put into the key. The minimal key length and the maximal length of a key with an empty value can be set in the config file. The check is a heuristic, so it is optional.",
+            wrong: "A=SECRET_KEY=abc\nDATABASE_CONNECTION_STRING_FOR_THE_REPORTING_SERVICE=",
+            correct: "SECRET_KEY=abc\nREPORTING_DATABASE_URL=postgres://localhost/reports",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn short_key_test() {
+        let mut checker = AssignmentShapeChecker::default();
+        let line = line_entry("A=SECRET_KEY=abc");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "AssignmentShape",
+            String::from("The A key has a suspicious shape: a key of 1 character, the line may be generated wrong"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn long_key_with_empty_value_test() {
+        let mut checker = AssignmentShapeChecker::default();
+        let key = "A".repeat(41);
+        let line = line_entry(&format!("{}=\"\" # comment", key));
+        let expected = vec![Warning::new(
+            line.clone(),
+            "AssignmentShape",
+            format!("The {} key has a suspicious shape: an empty value and a key of 41 characters, the line may be generated wrong", key),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry(&format!("{}=1", key))).is_empty());
+        assert!(checker
+            .run(&line_entry(&format!("{}=", "A".repeat(40))))
+            .is_empty());
+    }
+
+    #[test]
+    fn configured_test() {
+        let mut checker = AssignmentShapeChecker::new(&AssignmentShapeConfig {
+            min_key_length: 3,
+            max_empty_key_length: 10,
+        });
+
+        assert_eq!(1, checker.run(&line_entry("DB=postgres")).len());
+        assert_eq!(1, checker.run(&line_entry("DATABASE_URL=")).len());
+        assert!(checker.run(&line_entry("HOST=")).is_empty());
+    }
+}
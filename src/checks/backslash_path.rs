@@ -0,0 +1,112 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct BackslashPathChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl BackslashPathChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for BackslashPathChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "BackslashPath",
+            template:
+                "The {} key has a value with backslashes, use forward slashes or single quotes",
+        }
+    }
+}
+
+impl<'a> Check<'a> for BackslashPathChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        // Backslashes in single quotes are kept as is by all loaders
+        if let (Some(key), Some(value)) = (parsed.key, parsed.value) {
+            if parsed.quote != Some(QuoteStyle::Single) && value.contains('\\') {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Loaders may treat backslashes in unquoted and double-quoted values as escape sequences, so a Windows path like C:\\new\\data can silently become C: followed by a newline. Forward slashes work on Windows too, single quotes keep backslashes as is.",
+            wrong: "DATA_DIR=C:\\Users\\me",
+            correct: "DATA_DIR='C:\\Users\\me'",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn backslash_test() {
+        let mut checker = BackslashPathChecker::default();
+
+        for str in &["DIR=C:\\Users\\me", "DIR=\"C:\\Users\\me\""] {
+            let line = line_entry(str);
+            let expected = vec![Warning::new(
+                line.clone(),
+                "BackslashPath",
+                String::from(
+                    "The DIR key has a value with backslashes, use forward slashes or single quotes",
+                ),
+            )];
+
+            assert_eq!(expected, checker.run(&line), "{}", str);
+        }
+    }
+
+    #[test]
+    fn without_backslash_test() {
+        let mut checker = BackslashPathChecker::default();
+
+        for str in &["DIR=C:/Users/me", "DIR='C:\\Users\\me'", "# DIR=C:\\Users"] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "{}", str);
+        }
+    }
+}
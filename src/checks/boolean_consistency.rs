@@ -0,0 +1,226 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::BooleanConsistencyConfig;
+use serde::{Deserialize, Serialize};
+
+/// Representation of boolean values
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BooleanStyle {
+    /// `true`, `false`, `yes`, `no`, `on`, `off`
+    Word,
+    /// `1`, `0`
+    Numeric,
+}
+
+pub(crate) struct BooleanConsistencyChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    style: Option<BooleanStyle>,
+    // The keys with boolean values, their lines and the styles of the values
+    booleans: Vec<(String, LineEntry, BooleanStyle)>,
+}
+
+impl BooleanConsistencyChecker<'_> {
+    pub(crate) fn new(config: &BooleanConsistencyConfig) -> Self {
+        Self {
+            style: config.style,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str, style: BooleanStyle) -> String {
+        let expected = match style {
+            BooleanStyle::Word => "`true` or `false`",
+            BooleanStyle::Numeric => "`1` or `0`",
+        };
+
+        self.template.replace("{1}", key).replace("{2}", expected)
+    }
+}
+
+/// Returns the style of a boolean value, or `None` if the value is not a boolean
+fn boolean_style(value: &str) -> Option<BooleanStyle> {
+    match value.to_lowercase().as_str() {
+        "true" | "false" | "yes" | "no" | "on" | "off" => Some(BooleanStyle::Word),
+        "1" | "0" => Some(BooleanStyle::Numeric),
+        _ => None,
+    }
+}
+
+impl Default for BooleanConsistencyChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "BooleanConsistency",
+            template:
+                "The {1} key should have a boolean value like {2}, as the other boolean values",
+            style: None,
+            booleans: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for BooleanConsistencyChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let parsed = line.parse();
+        if let (Some(key), Some(value)) = (parsed.key, parsed.value) {
+            let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+                Some(index) => value[..index].trim(),
+                None => value.trim(),
+            };
+            let value = unquote(value).unwrap_or(value);
+
+            if let Some(style) = boolean_style(value) {
+                self.booleans.push((String::from(key), line.clone(), style));
+            }
+        }
+
+        if !line.is_last_line() {
+            return Vec::new();
+        }
+
+        let words = self
+            .booleans
+            .iter()
+            .filter(|(_, _, style)| *style == BooleanStyle::Word)
+            .count();
+        let numbers = self.booleans.len() - words;
+        let expected = match self.style {
+            Some(style) => style,
+            None if words > numbers => BooleanStyle::Word,
+            None if numbers > words => BooleanStyle::Numeric,
+            None => return Vec::new(),
+        };
+
+        self.booleans
+            .iter()
+            .filter(|(_, _, style)| *style != expected)
+            .map(|(key, line, _)| {
+                Warning::new(line.clone(), self.name(), self.message(key, expected))
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Loaders and applications parse boolean values differently, and a file which mixes `true` with `1` is easy to get wrong when a new flag is added. The expected representation is inferred from the boolean values of the file (`1` and `0` are counted as numeric booleans), or it can be set in the config file (`word` or `numeric`).",
+            wrong: "ENABLE_CACHE=true\nENABLE_LOGS=true\nENABLE_METRICS=1",
+            correct: "ENABLE_CACHE=true\nENABLE_LOGS=true\nENABLE_METRICS=true",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut BooleanConsistencyChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn boolean_style_test() {
+        let asserts = vec![
+            ("true", Some(BooleanStyle::Word)),
+            ("FALSE", Some(BooleanStyle::Word)),
+            ("yes", Some(BooleanStyle::Word)),
+            ("off", Some(BooleanStyle::Word)),
+            ("1", Some(BooleanStyle::Numeric)),
+            ("0", Some(BooleanStyle::Numeric)),
+            ("10", None),
+            ("enabled", None),
+            ("", None),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, boolean_style(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn mixed_booleans_test() {
+        let mut checker = BooleanConsistencyChecker::default();
+        let lines = [
+            "ENABLE_CACHE=true",
+            "ENABLE_LOGS=\"false\" # for now",
+            "ENABLE_METRICS=1",
+            "HOST=localhost",
+            "\n",
+        ];
+        let expected = vec![Warning::new(
+            line_entry(3, 5, "ENABLE_METRICS=1"),
+            "BooleanConsistency",
+            String::from("The ENABLE_METRICS key should have a boolean value like `true` or `false`, as the other boolean values"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn mixed_numeric_booleans_test() {
+        let mut checker = BooleanConsistencyChecker::default();
+        let lines = ["A=0", "B=on", "C=1"];
+        let expected = vec![Warning::new(
+            line_entry(2, 3, "B=on"),
+            "BooleanConsistency",
+            String::from(
+                "The B key should have a boolean value like `1` or `0`, as the other boolean values",
+            ),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn tie_test() {
+        let mut checker = BooleanConsistencyChecker::default();
+        let lines = ["ENABLE_CACHE=true", "ENABLE_LOGS=0"];
+
+        assert!(run_checker(&mut checker, &lines).is_empty());
+    }
+
+    #[test]
+    fn style_test() {
+        let config = BooleanConsistencyConfig {
+            style: Some(BooleanStyle::Numeric),
+        };
+        let mut checker = BooleanConsistencyChecker::new(&config);
+        let lines = ["ENABLE_CACHE=true", "HOST=localhost"];
+
+        assert_eq!(1, run_checker(&mut checker, &lines).len());
+    }
+}
@@ -0,0 +1,259 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use std::collections::HashMap;
+
+pub(crate) struct CircularSubstitutionChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    // Keys in the order of their definition, with the line and the referenced keys
+    entries: Vec<(String, LineEntry, Vec<String>)>,
+}
+
+impl CircularSubstitutionChecker<'_> {
+    fn message(&self, cycle: &[usize]) -> String {
+        let keys: Vec<&str> = cycle
+            .iter()
+            .chain(cycle.first())
+            .map(|&i| self.entries[i].0.as_str())
+            .collect();
+
+        self.template.replace("{}", &keys.join(" -> "))
+    }
+
+    /// Finds all cycles in the substitution graph. Every cycle is reported once,
+    /// starting with the key that is defined first.
+    fn find_cycles(&self) -> Vec<Vec<usize>> {
+        let indexes: HashMap<&str, usize> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, (key, _, _))| (key.as_str(), i))
+            .collect();
+
+        let graph: Vec<Vec<usize>> = self
+            .entries
+            .iter()
+            .map(|(_, _, refs)| {
+                let mut edges: Vec<usize> = refs
+                    .iter()
+                    .filter_map(|r| indexes.get(r.as_str()).copied())
+                    .collect();
+                edges.sort_unstable();
+                edges.dedup();
+                edges
+            })
+            .collect();
+
+        let mut cycles = Vec::new();
+        for start in 0..graph.len() {
+            let mut path = vec![start];
+            Self::walk(&graph, start, &mut path, &mut cycles);
+        }
+
+        cycles
+    }
+
+    fn walk(
+        graph: &[Vec<usize>],
+        start: usize,
+        path: &mut Vec<usize>,
+        cycles: &mut Vec<Vec<usize>>,
+    ) {
+        let current = *path.last().expect("path is not empty");
+
+        for &next in &graph[current] {
+            if next == start {
+                cycles.push(path.clone());
+            } else if next > start && !path.contains(&next) {
+                path.push(next);
+                Self::walk(graph, start, path, cycles);
+                path.pop();
+            }
+        }
+    }
+}
+
+impl Default for CircularSubstitutionChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "CircularSubstitution",
+            template: "Circular substitution detected: {}",
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for CircularSubstitutionChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        if let (Some(key), Some(value)) = (line.get_key(), line.get_value()) {
+            // Substitutions are not expanded in single-quoted values
+            let refs: Vec<String> = if line.parse().quote == Some(QuoteStyle::Single) {
+                Vec::new()
+            } else {
+                get_substitution_keys(&value)
+                    .into_iter()
+                    .map(String::from)
+                    .collect()
+            };
+
+            // The last definition of a key wins. Keys without substitutions can't be a part
+            // of a cycle, so they are not kept
+            self.entries.retain(|(k, _, _)| k != &key);
+            if !refs.is_empty() {
+                self.entries.push((key, line.clone(), refs));
+            }
+        }
+
+        if !line.is_last_line() {
+            return Vec::new();
+        }
+
+        self.find_cycles()
+            .iter()
+            .map(|cycle| {
+                let line = self.entries[cycle[0]].1.clone();
+                Warning::new(line, self.name(), self.message(cycle))
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Keys that reference each other through substitutions form a loop that can never be resolved. Depending on the loader, the expansion either never terminates or silently produces an empty value. Every key in the loop must eventually resolve to a literal value.",
+            wrong: "A=${B}\nB=${A}",
+            correct: "A=foo\nB=${A}",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_asserts(lines: Vec<LineEntry>) -> Vec<Warning> {
+        let mut checker = CircularSubstitutionChecker::default();
+        lines.iter().flat_map(|line| checker.run(line)).collect()
+    }
+
+    #[test]
+    fn no_substitution_test() {
+        let lines = vec![line_entry(1, 2, "A=B"), line_entry(2, 2, "B=A")];
+
+        assert!(run_asserts(lines).is_empty());
+    }
+
+    #[test]
+    fn acyclic_substitution_test() {
+        let lines = vec![
+            line_entry(1, 3, "A=foo"),
+            line_entry(2, 3, "B=${A}"),
+            line_entry(3, 3, "C=${A}${B}"),
+        ];
+
+        assert!(run_asserts(lines).is_empty());
+    }
+
+    #[test]
+    fn single_quoted_substitution_test() {
+        let lines = vec![
+            line_entry(1, 3, "A='${B}'"),
+            line_entry(2, 3, "B=${A}"),
+            line_entry(3, 3, "\n"),
+        ];
+
+        assert!(run_asserts(lines).is_empty());
+    }
+
+    #[test]
+    fn self_reference_test() {
+        let lines = vec![line_entry(1, 2, "A=${A}"), line_entry(2, 2, "\n")];
+        let expected = vec![Warning::new(
+            lines[0].clone(),
+            "CircularSubstitution",
+            String::from("Circular substitution detected: A -> A"),
+        )];
+
+        assert_eq!(expected, run_asserts(lines));
+    }
+
+    #[test]
+    fn two_keys_cycle_test() {
+        let lines = vec![
+            line_entry(1, 3, "A=${B}"),
+            line_entry(2, 3, "B=$A"),
+            line_entry(3, 3, "\n"),
+        ];
+        let expected = vec![Warning::new(
+            lines[0].clone(),
+            "CircularSubstitution",
+            String::from("Circular substitution detected: A -> B -> A"),
+        )];
+
+        assert_eq!(expected, run_asserts(lines));
+    }
+
+    #[test]
+    fn several_cycles_test() {
+        let lines = vec![
+            line_entry(1, 5, "A=${B}"),
+            line_entry(2, 5, "B=${C}"),
+            line_entry(3, 5, "C=${A}"),
+            line_entry(4, 5, "D=${D}${A}"),
+            line_entry(5, 5, "# comment"),
+        ];
+        let expected = vec![
+            Warning::new(
+                lines[0].clone(),
+                "CircularSubstitution",
+                String::from("Circular substitution detected: A -> B -> C -> A"),
+            ),
+            Warning::new(
+                lines[3].clone(),
+                "CircularSubstitution",
+                String::from("Circular substitution detected: D -> D"),
+            ),
+        ];
+
+        assert_eq!(expected, run_asserts(lines));
+    }
+
+    #[test]
+    fn redefined_key_test() {
+        let lines = vec![
+            line_entry(1, 3, "A=${B}"),
+            line_entry(2, 3, "B=${A}"),
+            line_entry(3, 3, "A=foo"),
+        ];
+
+        assert!(run_asserts(lines).is_empty());
+    }
+}
@@ -0,0 +1,180 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::CommentSpacingConfig;
+
+/// What precedes a line, used to find the comments which start a group of keys
+#[derive(Clone, Copy, PartialEq)]
+enum Previous {
+    Nothing,
+    BlankLine,
+    Comment,
+    Key,
+}
+
+pub(crate) struct CommentSpacingChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    is_required: bool,
+    previous: Previous,
+    spaced_groups: usize,
+    unspaced_comments: Vec<LineEntry>,
+}
+
+impl CommentSpacingChecker<'_> {
+    pub(crate) fn new(config: &CommentSpacingConfig) -> Self {
+        Self {
+            is_required: config.required,
+            ..Self::default()
+        }
+    }
+
+    /// A blank line is expected when it is required, or when most of the groups have it
+    fn is_expected(&self) -> bool {
+        self.is_required || self.spaced_groups > self.unspaced_comments.len()
+    }
+}
+
+impl Default for CommentSpacingChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "CommentSpacing",
+            template: "The comment should be preceded by a blank line",
+            is_required: false,
+            previous: Previous::Nothing,
+            spaced_groups: 0,
+            unspaced_comments: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for CommentSpacingChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let current = if line.is_empty() {
+            Previous::BlankLine
+        } else if line.is_comment() {
+            Previous::Comment
+        } else {
+            Previous::Key
+        };
+
+        if current == Previous::Comment {
+            match self.previous {
+                Previous::BlankLine => self.spaced_groups += 1,
+                Previous::Key => self.unspaced_comments.push(line.clone()),
+                _ => {}
+            }
+        }
+        self.previous = current;
+
+        if !line.is_last_line() || !self.is_expected() {
+            return Vec::new();
+        }
+
+        self.unspaced_comments
+            .iter()
+            .map(|line| Warning::new(line.clone(), self.name(), self.template.to_string()))
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "When groups of keys headed by comments are separated by blank lines, a comment right after a key looks like it belongs to the previous group. The convention is inferred from the file, or the blank line can be required in the config file.",
+            wrong: "# Database\nDB_HOST=localhost\n\n# Cache\nREDIS_HOST=localhost\n# Queue\nQUEUE_HOST=localhost",
+            correct: "# Database\nDB_HOST=localhost\n\n# Cache\nREDIS_HOST=localhost\n\n# Queue\nQUEUE_HOST=localhost",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut CommentSpacingChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn missing_blank_line_test() {
+        let mut checker = CommentSpacingChecker::default();
+        let lines = [
+            "# Database",
+            "DB=db",
+            "",
+            "# Cache",
+            "# Redis",
+            "REDIS=redis",
+            "",
+            "# Mail",
+            "MAIL=mail",
+            "# Queue",
+            "QUEUE=queue",
+            "\n",
+        ];
+        let expected = vec![Warning::new(
+            line_entry(10, 12, "# Queue"),
+            "CommentSpacing",
+            String::from("The comment should be preceded by a blank line"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn inconsistent_convention_test() {
+        let mut checker = CommentSpacingChecker::default();
+        let lines = ["DB=db", "", "# Cache", "REDIS=redis", "# Queue", "\n"];
+
+        assert!(run_checker(&mut checker, &lines).is_empty());
+    }
+
+    #[test]
+    fn required_blank_line_test() {
+        let mut checker = CommentSpacingChecker::new(&CommentSpacingConfig { required: true });
+        let lines = ["# Database", "DB=db", "# Cache", "REDIS=redis", "\n"];
+        let expected = vec![Warning::new(
+            line_entry(3, 5, "# Cache"),
+            "CommentSpacing",
+            String::from("The comment should be preceded by a blank line"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::CommentStyleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Which character starts comments
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentStyle {
+    /// `# comment`
+    Hash,
+    /// `; comment`
+    Semicolon,
+}
+
+impl CommentStyle {
+    fn prefix(self) -> char {
+        match self {
+            CommentStyle::Hash => '#',
+            CommentStyle::Semicolon => ';',
+        }
+    }
+
+    fn of(line: &LineEntry) -> Option<Self> {
+        match line.trimmed_string().chars().next() {
+            Some('#') => Some(CommentStyle::Hash),
+            Some(';') => Some(CommentStyle::Semicolon),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) struct CommentStyleChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    style: Option<CommentStyle>,
+    comments: Vec<(CommentStyle, LineEntry)>,
+}
+
+impl CommentStyleChecker<'_> {
+    pub(crate) fn new(config: &CommentStyleConfig) -> Self {
+        Self {
+            style: config.style,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, style: CommentStyle) -> String {
+        self.template.replace("{}", &style.prefix().to_string())
+    }
+
+    /// Returns the expected or the dominant style of the file, `#` wins a tie
+    fn expected_style(&self) -> CommentStyle {
+        if let Some(style) = self.style {
+            return style;
+        }
+
+        let semicolons = self
+            .comments
+            .iter()
+            .filter(|(style, _)| *style == CommentStyle::Semicolon)
+            .count();
+
+        if semicolons * 2 > self.comments.len() {
+            CommentStyle::Semicolon
+        } else {
+            CommentStyle::Hash
+        }
+    }
+}
+
+impl Default for CommentStyleChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "CommentStyle",
+            template: "The comment should start with {}",
+            style: None,
+            comments: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for CommentStyleChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        if let Some(style) = CommentStyle::of(line) {
+            self.comments.push((style, line.clone()));
+        }
+
+        if !line.is_last_line() {
+            return Vec::new();
+        }
+
+        let expected = self.expected_style();
+        self.comments
+            .iter()
+            .filter(|(style, _)| *style != expected)
+            .map(|(_, line)| Warning::new(line.clone(), self.name(), self.message(expected)))
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Some dotenv dialects allow comments starting with a semicolon, but most loaders only support #. Mixing both styles in one file is confusing, and a semicolon comment can be parsed as a broken entry. The style is inferred from the file, or it can be set in the config file.",
+            wrong: "# Database\n; Cache",
+            correct: "# Database\n# Cache",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut CommentStyleChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn semicolon_comment_test() {
+        let mut checker = CommentStyleChecker::default();
+        let lines = ["# Database", "DB=db", "; Cache ;)", "\n"];
+        let expected = vec![Warning::new(
+            line_entry(3, 4, "; Cache ;)"),
+            "CommentStyle",
+            String::from("The comment should start with #"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn semicolon_file_test() {
+        let mut checker = CommentStyleChecker::default();
+        let lines = ["; Database", "; Host", "# Cache", "\n"];
+        let expected = vec![Warning::new(
+            line_entry(3, 4, "# Cache"),
+            "CommentStyle",
+            String::from("The comment should start with ;"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn tie_test() {
+        let mut checker = CommentStyleChecker::default();
+        let lines = ["; Database", "# Cache", "\n"];
+        let expected = vec![Warning::new(
+            line_entry(1, 3, "; Database"),
+            "CommentStyle",
+            String::from("The comment should start with #"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn configured_style_test() {
+        let mut checker = CommentStyleChecker::new(&CommentStyleConfig {
+            style: Some(CommentStyle::Hash),
+        });
+        let lines = ["; Database", "; Cache", "\n"];
+
+        assert_eq!(2, run_checker(&mut checker, &lines).len());
+    }
+}
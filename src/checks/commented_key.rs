@@ -0,0 +1,169 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::CommentedKeyConfig;
+
+pub(crate) struct CommentedKeyChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    threshold: usize,
+    // The commented out keys and their lines
+    commented_keys: Vec<(String, LineEntry)>,
+}
+
+impl<'a> CommentedKeyChecker<'a> {
+    pub(crate) fn new(config: &CommentedKeyConfig) -> Self {
+        Self {
+            threshold: config.threshold,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+/// Returns the key of a comment with the `# KEY=value` shape. Documentation comments
+/// usually have spaces around the equal sign or words before it, so they are not matched
+pub(crate) fn commented_key(line: &str) -> Option<&str> {
+    let parsed = parse_line(line.trim().strip_prefix('#')?);
+    let key = parsed.key.filter(|_| parsed.has_delimiter)?;
+
+    let mut chars = key.chars();
+    let is_key = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_key {
+        Some(key)
+    } else {
+        None
+    }
+}
+
+impl Default for CommentedKeyChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "CommentedKey",
+            template: "The {} key is commented out, consider removing it",
+            threshold: CommentedKeyConfig::default().threshold,
+            commented_keys: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for CommentedKeyChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        if let Some(key) = commented_key(&line.raw_string) {
+            self.commented_keys.push((String::from(key), line.clone()));
+        }
+
+        if !line.is_last_line() || self.commented_keys.len() <= self.threshold {
+            return Vec::new();
+        }
+
+        self.commented_keys
+            .iter()
+            .map(|(key, line)| Warning::new(line.clone(), self.name(), self.message(key)))
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Commented out keys are often leftovers of old settings. They accumulate over time and make it hard to tell which settings are still relevant. Only comments with the `# KEY=value` shape are counted, and the number of allowed commented out keys can be set in the config file.",
+            wrong: "# OLD_HOST=localhost\nHOST=example.com",
+            correct: "HOST=example.com",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut CommentedKeyChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn commented_key_test() {
+        let asserts = vec![
+            ("# OLD_KEY=value", Some("OLD_KEY")),
+            ("#FOO=", Some("FOO")),
+            ("# export FOO=bar", Some("FOO")),
+            ("# Set FOO = 1 to enable it", None),
+            ("# See https://example.com/?a=b", None),
+            ("# 1FOO=bar", None),
+            ("# just a comment", None),
+            ("FOO=bar", None),
+        ];
+
+        for (line, expected) in asserts {
+            assert_eq!(expected, commented_key(line), "Line: {}", line);
+        }
+    }
+
+    #[test]
+    fn commented_keys_test() {
+        let mut checker = CommentedKeyChecker::default();
+        let lines = ["# The host of the app", "# OLD_HOST=localhost", "HOST=app"];
+        let expected = vec![Warning::new(
+            line_entry(2, 3, "# OLD_HOST=localhost"),
+            "CommentedKey",
+            String::from("The OLD_HOST key is commented out, consider removing it"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn threshold_test() {
+        let config = CommentedKeyConfig { threshold: 2 };
+        let mut checker = CommentedKeyChecker::new(&config);
+        let lines = ["# A=1", "# B=2", "C=3"];
+        assert!(run_checker(&mut checker, &lines).is_empty());
+
+        let mut checker = CommentedKeyChecker::new(&config);
+        let lines = ["# A=1", "# B=2", "# C=3"];
+        assert_eq!(3, run_checker(&mut checker, &lines).len());
+    }
+}
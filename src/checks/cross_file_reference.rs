@@ -0,0 +1,148 @@
+use crate::checks::{Explanation, MultiFileCheck};
+use crate::common::*;
+use std::collections::HashSet;
+
+pub(crate) struct CrossFileReferenceChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl CrossFileReferenceChecker<'_> {
+    fn message(&self, key: &str, reference: &str, file: &FileEntry) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", reference)
+            .replace("{3}", &file.to_string())
+    }
+}
+
+impl Default for CrossFileReferenceChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "CrossFileReference",
+            template: "The {1} key references {2}, which is defined in {3}, but not in its file",
+        }
+    }
+}
+
+impl<'a> MultiFileCheck<'a> for CrossFileReferenceChecker<'a> {
+    fn run(&mut self, files: &[Vec<LineEntry>]) -> Vec<Warning> {
+        let keys: Vec<HashSet<String>> = files
+            .iter()
+            .map(|lines| lines.iter().filter_map(|line| line.get_key()).collect())
+            .collect();
+
+        let mut warnings = Vec::new();
+        for (i, lines) in files.iter().enumerate() {
+            for line in lines {
+                let (key, value) = match (line.get_key(), line.get_value()) {
+                    (Some(key), Some(value)) => (key, value),
+                    _ => continue,
+                };
+
+                for reference in get_substitution_keys(&value) {
+                    if keys[i].contains(reference) {
+                        continue;
+                    }
+
+                    // The first of the other files which defines the key is reported
+                    let defined_in = files
+                        .iter()
+                        .zip(&keys)
+                        .find(|(_, file_keys)| file_keys.contains(reference));
+                    if let Some((other_lines, _)) = defined_in {
+                        warnings.push(Warning::new(
+                            line.clone(),
+                            self.name(),
+                            self.message(&key, reference, &other_lines[0].file),
+                        ));
+                    }
+                }
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Most loaders expand substitutions within a single file, so a reference to a key which is defined only in another file (e.g. `.env.local` referencing `.env`) works only when both files are loaded in the right order. The check is optional, since some setups load the files together on purpose.",
+            wrong: "# .env\nHOST=localhost\n# .env.local\nURL=http://${HOST}",
+            correct: "# .env.local\nHOST=localhost\nURL=http://${HOST}",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(file_name: &str, number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(file_name),
+                file_name: file_name.to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn reference_to_other_file_test() {
+        let mut checker = CrossFileReferenceChecker::default();
+        let files = vec![
+            vec![line_entry(".env", 1, 1, "HOST=localhost")],
+            vec![
+                line_entry(".env.local", 1, 2, "PORT=80"),
+                line_entry(
+                    ".env.local",
+                    2,
+                    2,
+                    "URL=http://${HOST}:$PORT/${PATH_PREFIX}",
+                ),
+            ],
+        ];
+        let expected = vec![Warning::new(
+            files[1][1].clone(),
+            "CrossFileReference",
+            String::from(
+                "The URL key references HOST, which is defined in .env, but not in its file",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&files));
+    }
+
+    #[test]
+    fn reference_in_same_file_test() {
+        let mut checker = CrossFileReferenceChecker::default();
+        let files = vec![
+            vec![line_entry(".env", 1, 1, "HOST=localhost")],
+            vec![
+                line_entry(".env.local", 1, 2, "HOST=127.0.0.1"),
+                line_entry(".env.local", 2, 2, "URL=http://${HOST}"),
+            ],
+        ];
+
+        assert!(checker.run(&files).is_empty());
+    }
+}
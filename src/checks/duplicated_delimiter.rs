@@ -0,0 +1,108 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct DuplicatedDelimiterChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl DuplicatedDelimiterChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for DuplicatedDelimiterChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "DuplicatedDelimiter",
+            template: "The {} key has a duplicated delimiter",
+        }
+    }
+}
+
+impl<'a> Check<'a> for DuplicatedDelimiterChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        // Padding of base64 values is at the end, so only `=` right after the delimiter is reported
+        if let (Some(key), Some(value)) = (parsed.key, parsed.value) {
+            if value.starts_with('=') {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A doubled equal sign makes the value start with =, which is usually a typo. Values which really start with = should be quoted.",
+            wrong: "FOO==BAR",
+            correct: "FOO=BAR",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn duplicated_delimiter_test() {
+        let mut checker = DuplicatedDelimiterChecker::default();
+        let line = line_entry("FOO==BAR");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "DuplicatedDelimiter",
+            String::from("The FOO key has a duplicated delimiter"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn single_delimiter_test() {
+        let mut checker = DuplicatedDelimiterChecker::default();
+
+        for str in &[
+            "FOO=BAR",
+            "TOKEN=dGVzdA==",
+            "FOO=\"=BAR\"",
+            "FOO=",
+            "# FOO==BAR",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "{}", str);
+        }
+    }
+}
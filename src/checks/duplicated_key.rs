@@ -1,44 +1,135 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
-use std::collections::HashSet;
+use crate::config::DuplicatedKeyConfig;
+use std::collections::HashMap;
+
+// The first value of a key and whether a different value was defined after it
+struct Definition {
+    value: String,
+    is_conflicting: bool,
+}
 
 pub(crate) struct DuplicatedKeyChecker<'a> {
     name: &'a str,
     template: &'a str,
-    keys: HashSet<String>,
+    compare_values: bool,
+    keys: HashMap<String, Definition>,
 }
 
 impl DuplicatedKeyChecker<'_> {
-    fn message(&self, key: &str) -> String {
-        self.template.replace("{}", &key)
+    pub(crate) fn new(config: &DuplicatedKeyConfig) -> Self {
+        Self {
+            compare_values: config.compare_values,
+            ..Self::default()
+        }
+    }
+
+    // The kind is empty when the values are not compared
+    fn message(&self, key: &str, kind: &str) -> String {
+        self.template
+            .replace("{}", key)
+            .replace("{1}", key)
+            .replace("{2}", kind)
     }
 }
 
+/// Returns the value of a line without quotes and an inline comment, so the definitions of a key
+/// which are loaded the same are compared as equal
+pub(crate) fn normalized_value(line: &LineEntry) -> String {
+    let parsed = line.parse();
+    let value = parsed.value.unwrap_or_default();
+
+    let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+        Some(index) => value[..index].trim(),
+        None => value.trim(),
+    };
+
+    unquote(value).unwrap_or(value).to_string()
+}
+
 impl Default for DuplicatedKeyChecker<'_> {
     fn default() -> Self {
         Self {
-            keys: HashSet::new(),
+            keys: HashMap::new(),
             name: "DuplicatedKey",
-            template: "The {} key is duplicated",
+            template: "The {1} key is duplicated{2}",
+            compare_values: DuplicatedKeyConfig::default().compare_values,
         }
     }
 }
 
-impl Check for DuplicatedKeyChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
-        let key = line.get_key()?;
+impl<'a> Check<'a> for DuplicatedKeyChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        let key = match line.get_key() {
+            Some(key) => key,
+            None => return warnings,
+        };
+        let value = normalized_value(line);
+
+        let definition = match self.keys.get_mut(&key) {
+            Some(definition) => definition,
+            None => {
+                let definition = Definition {
+                    value,
+                    is_conflicting: false,
+                };
+                self.keys.insert(key, definition);
+                return warnings;
+            }
+        };
+        definition.is_conflicting |= definition.value != value;
+        let is_conflicting = definition.is_conflicting;
 
-        if self.keys.contains(&key) {
-            return Some(Warning::new(line.clone(), self.name(), self.message(&key)));
+        if !self.compare_values {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(&key, ""),
+            ));
+            return warnings;
         }
 
-        self.keys.insert(key);
-        None
+        // A redundant definition can be removed, while a conflicting one changes the value
+        // depending on the loader, so it is an error
+        if is_conflicting {
+            let message = self.message(&key, " with a different value");
+            let mut warning = Warning::new(line.clone(), self.name(), message);
+            warning.severity = Severity::Error;
+            warnings.push(warning);
+        } else {
+            let message = self.message(&key, " with the same value");
+            warnings.push(Warning::new(line.clone(), self.name(), message));
+        }
+
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A key that is defined more than once is ambiguous: loaders disagree on whether the first or the last definition wins, so the effective value depends on the tool reading the file. A duplicate with the same value is only redundant and is fixed by removing the earlier definition, while a duplicate with a different value is reported as an error. Both can be reported the same way with `compare_values: false` in the config file. Each key should be defined exactly once.",
+            wrong: "FOO=BAR\nFOO=BAZ",
+            correct: "FOO=BAR\nBAR=BAZ",
+        }
+    }
+
+    // The kind of the duplicate was added later, the templates with only the key still work
+    fn optional_placeholders(&self) -> &[&str] {
+        &["{2}"]
+    }
 }
 
 #[cfg(test)]
@@ -46,7 +137,7 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
-    fn run_duplicated_tests(asserts: Vec<(LineEntry, Option<Warning>)>) {
+    fn run_duplicated_tests(asserts: Vec<(LineEntry, Vec<Warning>)>) {
         let mut checker = DuplicatedKeyChecker::default();
 
         for assert in asserts {
@@ -68,7 +159,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -80,7 +171,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 2,
                         file: FileEntry {
@@ -91,8 +182,8 @@ mod tests {
                         raw_string: String::from("FOO=BAR"),
                     },
                     "DuplicatedKey",
-                    String::from("The FOO key is duplicated"),
-                )),
+                    String::from("The FOO key is duplicated with the same value"),
+                )],
             ),
         ];
 
@@ -112,7 +203,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -124,7 +215,7 @@ mod tests {
                     },
                     raw_string: String::from("BAR=FOO"),
                 },
-                None,
+                vec![],
             ),
         ];
 
@@ -143,7 +234,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -155,7 +246,7 @@ mod tests {
                     },
                     raw_string: String::from("Foo=FOO"),
                 },
-                None,
+                vec![],
             ),
         ];
 
@@ -175,7 +266,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -187,7 +278,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 2,
                         file: FileEntry {
@@ -198,8 +289,8 @@ mod tests {
                         raw_string: String::from("FOO=BAR"),
                     },
                     "DuplicatedKey",
-                    String::from("The FOO key is duplicated"),
-                )),
+                    String::from("The FOO key is duplicated with the same value"),
+                )],
             ),
             (
                 LineEntry {
@@ -211,7 +302,7 @@ mod tests {
                     },
                     raw_string: String::from("BAR=FOO"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -223,7 +314,7 @@ mod tests {
                     },
                     raw_string: String::from("BAR=FOO"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 4,
                         file: FileEntry {
@@ -234,8 +325,8 @@ mod tests {
                         raw_string: String::from("BAR=FOO"),
                     },
                     "DuplicatedKey",
-                    String::from("The BAR key is duplicated"),
-                )),
+                    String::from("The BAR key is duplicated with the same value"),
+                )],
             ),
         ];
 
@@ -255,7 +346,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -267,7 +358,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 2,
                         file: FileEntry {
@@ -278,8 +369,8 @@ mod tests {
                         raw_string: String::from("FOO=BAR"),
                     },
                     "DuplicatedKey",
-                    String::from("The FOO key is duplicated"),
-                )),
+                    String::from("The FOO key is duplicated with the same value"),
+                )],
             ),
             (
                 LineEntry {
@@ -291,10 +382,96 @@ mod tests {
                     },
                     raw_string: String::from("BAR=FOO"),
                 },
-                None,
+                vec![],
             ),
         ];
 
         run_duplicated_tests(asserts);
     }
+
+    fn line_entry(number: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 3,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn with_different_values_test() {
+        let mut checker = DuplicatedKeyChecker::default();
+        let lines = [
+            line_entry(1, "FOO=bar"),
+            line_entry(2, "FOO=baz"),
+            line_entry(3, "FOO=bar"),
+        ];
+        let mut expected = Warning::new(
+            lines[1].clone(),
+            "DuplicatedKey",
+            String::from("The FOO key is duplicated with a different value"),
+        );
+        expected.severity = Severity::Error;
+
+        assert!(checker.run(&lines[0]).is_empty());
+        assert_eq!(vec![expected], checker.run(&lines[1]));
+        // The later definitions are conflicting too, even with the first value
+        let warnings = checker.run(&lines[2]);
+        assert_eq!(1, warnings.len());
+        assert!(warnings[0].is_error());
+    }
+
+    #[test]
+    fn with_quoted_same_value_test() {
+        let mut checker = DuplicatedKeyChecker::default();
+
+        assert!(checker.run(&line_entry(1, "FOO=bar")).is_empty());
+        let warnings = checker.run(&line_entry(2, "FOO='bar' # the same"));
+        assert_eq!(
+            "The FOO key is duplicated with the same value",
+            warnings[0].message()
+        );
+        assert!(!warnings[0].is_error());
+    }
+
+    #[test]
+    fn without_compare_values_test() {
+        let config = DuplicatedKeyConfig {
+            compare_values: false,
+        };
+        let mut checker = DuplicatedKeyChecker::new(&config);
+        let line = line_entry(2, "FOO=baz");
+
+        assert!(checker.run(&line_entry(1, "FOO=bar")).is_empty());
+        assert_eq!(
+            vec![Warning::new(
+                line.clone(),
+                "DuplicatedKey",
+                String::from("The FOO key is duplicated"),
+            )],
+            checker.run(&line)
+        );
+    }
+
+    #[test]
+    fn custom_template_test() {
+        let mut checker = DuplicatedKeyChecker::default();
+        checker.set_template("Duplicate{2}: {1}, see CONF-123");
+
+        assert!(checker.run(&line_entry(1, "FOO=bar")).is_empty());
+        assert_eq!(
+            "Duplicate with a different value: FOO, see CONF-123",
+            checker.run(&line_entry(2, "FOO=baz"))[0].message()
+        );
+
+        // The templates with only the key leave out the kind
+        checker.set_template("The {} key is defined twice");
+        assert_eq!(
+            "The FOO key is defined twice",
+            checker.run(&line_entry(3, "FOO=bar"))[0].message()
+        );
+    }
 }
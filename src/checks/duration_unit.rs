@@ -0,0 +1,219 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::DurationUnitConfig;
+
+pub(crate) struct DurationUnitChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    suffixes: Vec<String>,
+    require_unit: bool,
+}
+
+impl DurationUnitChecker<'_> {
+    pub(crate) fn new(config: &DurationUnitConfig) -> Self {
+        Self {
+            name: "DurationUnit",
+            template: "The {1} key has a duration {2}",
+            suffixes: config.suffixes.clone(),
+            require_unit: config.require_unit,
+        }
+    }
+
+    fn message(&self, key: &str) -> String {
+        let problem = if self.require_unit {
+            "without a unit, e.g. 30s or 500ms"
+        } else {
+            "with a unit, use a plain number"
+        };
+
+        self.template.replace("{1}", key).replace("{2}", problem)
+    }
+
+    fn is_duration_key(&self, key: &str) -> bool {
+        let key = key.to_uppercase();
+        self.suffixes
+            .iter()
+            .any(|suffix| key.ends_with(suffix.as_str()))
+    }
+}
+
+/// Checks if a value is a plain number, like `30` or `1.5`
+fn is_plain_number(value: &str) -> bool {
+    let mut parts = value.splitn(2, '.');
+    let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+
+    parts.next().is_some_and(is_digits) && parts.next().is_none_or(is_digits)
+}
+
+/// Checks if a value is a number with a unit, like `30s`, `500ms` or `1h30m`
+fn has_unit(value: &str) -> bool {
+    value.starts_with(|c: char| c.is_ascii_digit())
+        && value.chars().any(|c| c.is_ascii_alphabetic())
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '.')
+}
+
+impl Default for DurationUnitChecker<'_> {
+    fn default() -> Self {
+        Self::new(&DurationUnitConfig::default())
+    }
+}
+
+impl<'a> Check<'a> for DurationUnitChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+        if !self.is_duration_key(key) {
+            return warnings;
+        }
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value);
+
+        // Zero is the same in any unit
+        let is_reported = if self.require_unit {
+            is_plain_number(value) && !value.trim_start_matches(['0', '.']).is_empty()
+        } else {
+            has_unit(value)
+        };
+
+        if is_reported {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A timeout or an interval without a unit is ambiguous: libraries read `30` as seconds, milliseconds or even minutes, and a value copied between services can be off by a factor of a thousand. The suffixes of the duration keys can be set in the config file, and `require_unit: false` reports the values with a unit instead, for the teams which keep the unit in the key name.",
+            wrong: "REQUEST_TIMEOUT=30",
+            correct: "REQUEST_TIMEOUT=30s",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn is_plain_number_test() {
+        let asserts = vec![
+            ("30", true),
+            ("1.5", true),
+            ("30s", false),
+            ("1.", false),
+            (".5", false),
+            ("", false),
+            ("-1", false),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, is_plain_number(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn has_unit_test() {
+        let asserts = vec![
+            ("30s", true),
+            ("500ms", true),
+            ("1h30m", true),
+            ("1.5h", true),
+            ("30", false),
+            ("never", false),
+            ("30 s", false),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, has_unit(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn without_unit_test() {
+        let mut checker = DurationUnitChecker::default();
+        let line = line_entry("REQUEST_TIMEOUT=\"30\" # seconds");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "DurationUnit",
+            String::from(
+                "The REQUEST_TIMEOUT key has a duration without a unit, e.g. 30s or 500ms",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert_eq!(1, checker.run(&line_entry("CACHE_TTL=3600")).len());
+        assert_eq!(1, checker.run(&line_entry("POLL_INTERVAL=1.5")).len());
+    }
+
+    #[test]
+    fn not_reported_test() {
+        let mut checker = DurationUnitChecker::default();
+
+        for str in [
+            "REQUEST_TIMEOUT=30s",
+            "REQUEST_TIMEOUT=0",
+            "REQUEST_TIMEOUT_MS=500",
+            "RETRIES=3",
+            "CACHE_TTL=",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+
+    #[test]
+    fn unit_not_required_test() {
+        let mut checker = DurationUnitChecker::new(&DurationUnitConfig {
+            suffixes: vec![String::from("_DELAY")],
+            require_unit: false,
+        });
+        let line = line_entry("RETRY_DELAY=5s");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "DurationUnit",
+            String::from("The RETRY_DELAY key has a duration with a unit, use a plain number"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry("RETRY_DELAY=5")).is_empty());
+        assert!(checker.run(&line_entry("REQUEST_TIMEOUT=5s")).is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct EmptyFileChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    keys: usize,
+    first_line: Option<LineEntry>,
+}
+
+impl EmptyFileChecker<'_> {
+    fn message(&self) -> String {
+        String::from(self.template)
+    }
+}
+
+impl Default for EmptyFileChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "EmptyFile",
+            template: "The file has no keys, only comments and blank lines",
+            keys: 0,
+            first_line: None,
+        }
+    }
+}
+
+impl<'a> Check<'a> for EmptyFileChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        if self.first_line.is_none() {
+            self.first_line = Some(line.clone());
+        }
+        if !line.is_empty_or_comment() {
+            self.keys += 1;
+        }
+
+        // The warning is shown at the first line, since it is about the whole file
+        match &self.first_line {
+            Some(first_line) if line.is_last_line() && self.keys == 0 => {
+                vec![Warning::new(
+                    first_line.clone(),
+                    self.name(),
+                    self.message(),
+                )]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "An env file with only comments and blank lines is often the wrong file or a config which was never filled in. Empty example stubs are legitimate, so the check is optional.",
+            wrong: "# The settings of the app",
+            correct: "# The settings of the app\nHOST=localhost",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut EmptyFileChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn file_without_keys_test() {
+        let mut checker = EmptyFileChecker::default();
+        let lines = ["# The settings of the app", "", "\n"];
+        let expected = vec![Warning::new(
+            line_entry(1, 3, "# The settings of the app"),
+            "EmptyFile",
+            String::from("The file has no keys, only comments and blank lines"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn file_with_keys_test() {
+        let mut checker = EmptyFileChecker::default();
+        let lines = ["# The settings of the app", "HOST=localhost", "\n"];
+
+        assert!(run_checker(&mut checker, &lines).is_empty());
+    }
+}
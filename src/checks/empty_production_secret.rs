@@ -0,0 +1,158 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::{EmptyProductionSecretConfig, FilePatterns};
+
+pub(crate) struct EmptyProductionSecretChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    files: FilePatterns,
+    suffixes: Vec<String>,
+}
+
+impl EmptyProductionSecretChecker<'_> {
+    pub(crate) fn new(config: &EmptyProductionSecretConfig) -> Self {
+        Self {
+            name: "EmptyProductionSecret",
+            template:
+                "The {} key looks like a secret, but it has an empty value in a production file",
+            files: FilePatterns::new(&config.files),
+            suffixes: config.suffixes.clone(),
+        }
+    }
+
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for EmptyProductionSecretChecker<'_> {
+    fn default() -> Self {
+        Self::new(&EmptyProductionSecretConfig::default())
+    }
+}
+
+impl<'a> Check<'a> for EmptyProductionSecretChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        if !self.files.matches_name(&line.file) {
+            return warnings;
+        }
+
+        let parsed = line.parse();
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value);
+
+        let is_secret = self
+            .suffixes
+            .iter()
+            .any(|suffix| key.to_uppercase().ends_with(suffix.as_str()));
+        if is_secret && value.trim().is_empty() {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Empty values are normal in example and development files, but a secret with an empty value in a production file is almost always a deploy mistake, and the application may start without a password at all. The patterns of the production file names and the suffixes of the secret keys can be set in the config file.",
+            wrong: "# .env.production\nDATABASE_PASSWORD=",
+            correct: "# .env.production\nDATABASE_PASSWORD=s3cr3t",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(file_name: &str, str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(file_name),
+                file_name: file_name.to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn empty_secret_test() {
+        let mut checker = EmptyProductionSecretChecker::default();
+        let line = line_entry(".env.production", "DATABASE_PASSWORD=\"\" # set by deploy");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "EmptyProductionSecret",
+            String::from(
+                "The DATABASE_PASSWORD key looks like a secret, but it has an empty value in a production file",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert_eq!(
+            1,
+            checker.run(&line_entry(".env.prod", "API_SECRET=")).len()
+        );
+    }
+
+    #[test]
+    fn not_reported_test() {
+        let mut checker = EmptyProductionSecretChecker::default();
+        let lines = [
+            line_entry(".env.production", "DATABASE_PASSWORD=s3cr3t"),
+            line_entry(".env.production", "DATABASE_HOST="),
+            line_entry(".env.example", "DATABASE_PASSWORD="),
+            line_entry(".env", "DATABASE_PASSWORD="),
+        ];
+
+        for line in lines {
+            assert!(checker.run(&line).is_empty(), "Line: {}", line.raw_string);
+        }
+    }
+
+    #[test]
+    fn config_test() {
+        let config = EmptyProductionSecretConfig {
+            files: vec![String::from(".env.live")],
+            suffixes: vec![String::from("_CREDENTIALS")],
+        };
+        let mut checker = EmptyProductionSecretChecker::new(&config);
+
+        assert_eq!(
+            1,
+            checker
+                .run(&line_entry(".env.live", "AWS_CREDENTIALS="))
+                .len()
+        );
+        assert!(checker
+            .run(&line_entry(".env.production", "AWS_CREDENTIALS="))
+            .is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::EmptyValueStyleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Representation of empty values
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmptyValueStyle {
+    /// `KEY=""` or `KEY=''`
+    Quoted,
+    /// `KEY=`
+    Bare,
+}
+
+pub(crate) struct EmptyValueStyleChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    style: Option<EmptyValueStyle>,
+    // The keys with empty values, their lines and the styles of the values
+    empty_values: Vec<(String, LineEntry, EmptyValueStyle)>,
+}
+
+impl EmptyValueStyleChecker<'_> {
+    pub(crate) fn new(config: &EmptyValueStyleConfig) -> Self {
+        Self {
+            style: config.style,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str, style: EmptyValueStyle) -> String {
+        let expected = match style {
+            EmptyValueStyle::Quoted => "in quotes",
+            EmptyValueStyle::Bare => "without quotes",
+        };
+
+        self.template.replace("{1}", key).replace("{2}", expected)
+    }
+}
+
+/// Returns the style of an empty value (without its comment), or `None` if the value is not empty
+fn empty_value_style(value: &str) -> Option<EmptyValueStyle> {
+    match value {
+        "" => Some(EmptyValueStyle::Bare),
+        "\"\"" | "''" => Some(EmptyValueStyle::Quoted),
+        _ => None,
+    }
+}
+
+impl Default for EmptyValueStyleChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "EmptyValueStyle",
+            template: "The {1} key should have an empty value {2}, as the other empty values",
+            style: None,
+            empty_values: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for EmptyValueStyleChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let parsed = line.parse();
+        if let (Some(key), Some(value), true) = (parsed.key, parsed.value, parsed.has_delimiter) {
+            let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+                Some(index) => value[..index].trim(),
+                None => value.trim(),
+            };
+
+            if let Some(style) = empty_value_style(value) {
+                self.empty_values
+                    .push((String::from(key), line.clone(), style));
+            }
+        }
+
+        if !line.is_last_line() {
+            return Vec::new();
+        }
+
+        let quoted = self
+            .empty_values
+            .iter()
+            .filter(|(_, _, style)| *style == EmptyValueStyle::Quoted)
+            .count();
+        let bare = self.empty_values.len() - quoted;
+        let expected = match self.style {
+            Some(style) => style,
+            None if quoted > bare => EmptyValueStyle::Quoted,
+            None if bare > quoted => EmptyValueStyle::Bare,
+            None => return Vec::new(),
+        };
+
+        self.empty_values
+            .iter()
+            .filter(|(_, _, style)| *style != expected)
+            .map(|(key, line, _)| {
+                Warning::new(line.clone(), self.name(), self.message(key, expected))
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "`KEY=` and `KEY=\"\"` are the same empty value, and a file which mixes them is harder to read and to search. The expected style is inferred from the empty values of the file, or it can be set in the config file (`quoted` or `bare`).",
+            wrong: "API_KEY=\nSECRET_KEY=\"\"\nTOKEN=",
+            correct: "API_KEY=\nSECRET_KEY=\nTOKEN=",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut EmptyValueStyleChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn empty_value_style_test() {
+        let asserts = vec![
+            ("", Some(EmptyValueStyle::Bare)),
+            ("\"\"", Some(EmptyValueStyle::Quoted)),
+            ("''", Some(EmptyValueStyle::Quoted)),
+            ("\" \"", None),
+            ("bar", None),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, empty_value_style(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn mixed_empty_values_test() {
+        let mut checker = EmptyValueStyleChecker::default();
+        let lines = [
+            "API_KEY=",
+            "SECRET_KEY=\"\" # later",
+            "TOKEN=",
+            "HOST=localhost",
+        ];
+        let expected = vec![Warning::new(
+            line_entry(2, 4, "SECRET_KEY=\"\" # later"),
+            "EmptyValueStyle",
+            String::from(
+                "The SECRET_KEY key should have an empty value without quotes, as the other empty values",
+            ),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn tie_test() {
+        let mut checker = EmptyValueStyleChecker::default();
+
+        assert!(run_checker(&mut checker, &["A=", "B=''", "C"]).is_empty());
+    }
+
+    #[test]
+    fn configured_style_test() {
+        let config = EmptyValueStyleConfig {
+            style: Some(EmptyValueStyle::Quoted),
+        };
+        let mut checker = EmptyValueStyleChecker::new(&config);
+        let expected = vec![Warning::new(
+            line_entry(1, 1, "A="),
+            "EmptyValueStyle",
+            String::from(
+                "The A key should have an empty value in quotes, as the other empty values",
+            ),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &["A="]));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
 
 pub(crate) struct EndingBlankLineChecker<'a> {
@@ -21,19 +21,37 @@ impl EndingBlankLineChecker<'_> {
     }
 }
 
-impl Check for EndingBlankLineChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
+impl<'a> Check<'a> for EndingBlankLineChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
         if line.is_last_line() && !line.raw_string.ends_with(LF) {
-            Some(Warning::new(line.clone(), self.name(), self.message()))
-        } else {
-            None
+            warnings.push(Warning::new(line.clone(), self.name(), self.message()));
         }
+
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
 
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A file that does not end with a newline is not a valid POSIX text file. Some tools ignore the last line of such a file, and concatenating files produces broken lines.",
+            wrong: "FOO=BAR",
+            correct: "FOO=BAR\n",
+        }
+    }
+
     fn skip_comments(&self) -> bool {
         false
     }
@@ -58,7 +76,7 @@ mod tests {
             raw_string: String::from("\n"),
         };
 
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -74,7 +92,7 @@ mod tests {
             raw_string: String::from("\r\n"),
         };
 
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -89,11 +107,11 @@ mod tests {
             },
             raw_string: String::from("a"),
         };
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "EndingBlankLine",
             String::from("No blank line at the end of the file"),
-        ));
+        )];
 
         assert_eq!(expected, checker.run(&line));
     }
@@ -0,0 +1,131 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct EscapedClosingQuoteChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl EscapedClosingQuoteChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+/// Checks if a double-quoted value ends with a quote which is escaped by an odd number
+/// of backslashes (e.g. `"bar\"`), so the value isn't closed
+fn has_escaped_closing_quote(value: &str) -> bool {
+    let content = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(content) => content,
+        None => return false,
+    };
+
+    let backslashes = content.len() - content.trim_end_matches('\\').len();
+    backslashes % 2 == 1 && find_closing_quote(&value[1..], QuoteStyle::Double).is_none()
+}
+
+impl Default for EscapedClosingQuoteChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "EscapedClosingQuote",
+            template: "The {} key has a backslash before the closing quote, it escapes the quote and the value isn't closed",
+        }
+    }
+}
+
+impl<'a> Check<'a> for EscapedClosingQuoteChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+
+        if has_escaped_closing_quote(value.trim()) {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "In double-quoted values a backslash escapes the next character, so a value ending with `\\\"` is not closed: the quote is a part of the value, and loaders read the next lines into it or fail to parse the file. A value ending with a backslash needs the backslash to be escaped too.",
+            wrong: "DIR=\"C:\\temp\\\"",
+            correct: "DIR=\"C:\\temp\\\\\"",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn has_escaped_closing_quote_test() {
+        let asserts = vec![
+            ("\"bar\\\"", true),
+            ("\"bar\\\\\"", false),
+            ("\"bar\\\\\\\"", true),
+            ("\"bar\"", false),
+            ("\"\\\"\"", false),
+            ("'bar\\'", false),
+            ("bar\\\"", false),
+            ("\"", false),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(
+                expected,
+                has_escaped_closing_quote(value),
+                "Value: {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn escaped_closing_quote_test() {
+        let mut checker = EscapedClosingQuoteChecker::default();
+        let line = line_entry("DIR=\"C:\\temp\\\"");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "EscapedClosingQuote",
+            String::from("The DIR key has a backslash before the closing quote, it escapes the quote and the value isn't closed"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry("DIR=\"C:\\temp\\\\\"")).is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::ExportStyleConfig;
+use serde::{Deserialize, Serialize};
+
+/// A policy for the `export` prefix of the keys
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportStyle {
+    /// Keys can be defined with or without `export`
+    #[default]
+    Allow,
+    /// `FOO=BAR`
+    Forbid,
+    /// `export FOO=BAR`
+    Require,
+}
+
+pub(crate) struct ExportStyleChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    style: ExportStyle,
+}
+
+impl ExportStyleChecker<'_> {
+    pub(crate) fn new(config: &ExportStyleConfig) -> Self {
+        Self {
+            style: config.style,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str) -> String {
+        let expected = match self.style {
+            ExportStyle::Require => "with",
+            _ => "without",
+        };
+
+        self.template.replace("{1}", key).replace("{2}", expected)
+    }
+}
+
+impl Default for ExportStyleChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "ExportStyle",
+            template: "The {1} key should be defined {2} export",
+            style: ExportStyle::default(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for ExportStyleChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        let key = match line.get_key() {
+            Some(key) => key,
+            None => return warnings,
+        };
+
+        let is_violation = match self.style {
+            ExportStyle::Allow => false,
+            ExportStyle::Forbid => line.is_exported(),
+            ExportStyle::Require => !line.is_exported(),
+        };
+
+        if is_violation {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(&key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Some loaders don't support the `export` prefix, while files which are sourced by a shell need it to pass the variables to subprocesses. The policy is set in the config file with `checks.ExportStyle.style`: `allow` (the default) doesn't report anything, `forbid` reports the keys with `export` and `require` reports the keys without it.",
+            wrong: "# With the forbid style\nexport FOO=BAR",
+            correct: "FOO=BAR",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn allow_style_test() {
+        let mut checker = ExportStyleChecker::default();
+
+        assert!(checker.run(&line_entry("export FOO=BAR")).is_empty());
+        assert!(checker.run(&line_entry("FOO=BAR")).is_empty());
+    }
+
+    #[test]
+    fn forbid_style_test() {
+        let mut checker = ExportStyleChecker::new(&ExportStyleConfig {
+            style: ExportStyle::Forbid,
+        });
+        let line = line_entry("export FOO=BAR");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "ExportStyle",
+            String::from("The FOO key should be defined without export"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry("FOO=BAR")).is_empty());
+    }
+
+    #[test]
+    fn require_style_test() {
+        let mut checker = ExportStyleChecker::new(&ExportStyleConfig {
+            style: ExportStyle::Require,
+        });
+        let line = line_entry("FOO=BAR");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "ExportStyle",
+            String::from("The FOO key should be defined with export"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry("export FOO=BAR")).is_empty());
+        assert!(checker.run(&line_entry("# FOO=BAR")).is_empty());
+    }
+}
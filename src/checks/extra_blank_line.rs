@@ -1,4 +1,4 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
 
 pub(crate) struct ExtraBlankLineChecker<'a> {
@@ -23,27 +23,43 @@ impl Default for ExtraBlankLineChecker<'_> {
     }
 }
 
-impl Check for ExtraBlankLineChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
+impl<'a> Check<'a> for ExtraBlankLineChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
         if !line.is_empty() {
-            return None;
+            return warnings;
         }
 
-        let is_extra = self
-            .last_blank_number
-            .map_or(false, |n| n + 1 == line.number);
+        let is_extra = self.last_blank_number == Some(line.number - 1);
         self.last_blank_number = Some(line.number);
 
         if is_extra {
-            return Some(Warning::new(line.clone(), self.name(), self.message()));
+            warnings.push(Warning::new(line.clone(), self.name(), self.message()));
         }
 
-        None
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Several blank lines in a row add noise and make groups of keys harder to read. A single blank line is enough to separate groups.",
+            wrong: "A=B\n\n\nFOO=BAR",
+            correct: "A=B\n\nFOO=BAR",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -65,8 +81,10 @@ mod tests {
                 },
                 raw_string: String::from(content),
             };
-            let expected =
-                message.map(|msg| Warning::new(line.clone(), "ExtraBlankLine", String::from(msg)));
+            let expected: Vec<Warning> = message
+                .iter()
+                .map(|msg| Warning::new(line.clone(), "ExtraBlankLine", String::from(*msg)))
+                .collect();
 
             assert_eq!(checker.run(&line), expected);
         }
@@ -0,0 +1,154 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::ForbiddenKeyConfig;
+
+pub(crate) struct ForbiddenKeyChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    // The forbidden keys with the messages explaining what to use instead
+    keys: Vec<(String, String)>,
+}
+
+impl ForbiddenKeyChecker<'_> {
+    pub(crate) fn new(config: &ForbiddenKeyConfig) -> Self {
+        Self {
+            name: "ForbiddenKey",
+            template: "The {1} key is forbidden, {2}",
+            keys: config
+                .keys
+                .iter()
+                .map(|k| {
+                    let message = k
+                        .message
+                        .clone()
+                        .unwrap_or_else(|| String::from("remove or rename it"));
+                    (k.key.clone(), message)
+                })
+                .collect(),
+        }
+    }
+
+    fn message(&self, key: &str, message: &str) -> String {
+        self.template.replace("{1}", key).replace("{2}", message)
+    }
+}
+
+impl Default for ForbiddenKeyChecker<'_> {
+    fn default() -> Self {
+        Self::new(&ForbiddenKeyConfig::default())
+    }
+}
+
+impl<'a> Check<'a> for ForbiddenKeyChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        let key = match line.get_key() {
+            Some(key) => key,
+            None => return warnings,
+        };
+
+        if let Some((_, message)) = self.keys.iter().find(|(k, _)| *k == key) {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(&key, message),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Some keys shouldn't be used anymore, e.g. legacy keys which were replaced by other ones, or keys which a framework reads in an unexpected way. The forbidden keys and the messages about them are set in the config file, without them the check does nothing.",
+            wrong: "LEGACY_API_URL=https://api.example.com",
+            correct: "API_URL=https://api.example.com",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ForbiddenKeyItem;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn checker() -> ForbiddenKeyChecker<'static> {
+        ForbiddenKeyChecker::new(&ForbiddenKeyConfig {
+            keys: vec![
+                ForbiddenKeyItem {
+                    key: String::from("LEGACY_API_URL"),
+                    message: Some(String::from("use API_URL instead")),
+                },
+                ForbiddenKeyItem {
+                    key: String::from("DEBUG"),
+                    message: None,
+                },
+            ],
+        })
+    }
+
+    #[test]
+    fn forbidden_key_test() {
+        let mut checker = checker();
+        let line = line_entry("export LEGACY_API_URL=https://api.example.com");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "ForbiddenKey",
+            String::from("The LEGACY_API_URL key is forbidden, use API_URL instead"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn without_message_test() {
+        let mut checker = checker();
+        let line = line_entry("DEBUG=true");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "ForbiddenKey",
+            String::from("The DEBUG key is forbidden, remove or rename it"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn allowed_key_test() {
+        let mut checker = checker();
+
+        assert!(checker
+            .run(&line_entry("API_URL=https://api.example.com"))
+            .is_empty());
+        assert!(checker.run(&line_entry("DEBUG_SQL=true")).is_empty());
+        assert!(ForbiddenKeyChecker::default()
+            .run(&line_entry("DEBUG=true"))
+            .is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+use crate::checks::{Explanation, MultiFileCheck};
+use crate::common::*;
+
+pub(crate) struct InconsistentEndingNewlineChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl InconsistentEndingNewlineChecker<'_> {
+    fn message(&self, has_newline: bool) -> String {
+        let state = if has_newline { "ends" } else { "doesn't end" };
+
+        self.template.replace("{}", state)
+    }
+}
+
+impl Default for InconsistentEndingNewlineChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "InconsistentEndingNewline",
+            template: "The file {} with a newline, unlike most of the checked files",
+        }
+    }
+}
+
+impl<'a> MultiFileCheck<'a> for InconsistentEndingNewlineChecker<'a> {
+    fn run(&mut self, files: &[Vec<LineEntry>]) -> Vec<Warning> {
+        // The last line of each non-empty file
+        let last_lines: Vec<&LineEntry> = files.iter().filter_map(|lines| lines.last()).collect();
+        let with_newline = last_lines
+            .iter()
+            .filter(|line| line.raw_string.ends_with(LF))
+            .count();
+        let without_newline = last_lines.len() - with_newline;
+
+        if with_newline == 0 || without_newline == 0 {
+            return Vec::new();
+        }
+
+        // The files of the minority are reported, on a tie the files without a newline are
+        let reported = with_newline < without_newline;
+        last_lines
+            .into_iter()
+            .filter(|line| line.raw_string.ends_with(LF) == reported)
+            .map(|line| Warning::new(line.clone(), self.name(), self.message(reported)))
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "When some of the files end with a newline and others don't, the files are edited with different settings and every edit can add or remove the newline in a diff. Unlike EndingBlankLine, the check doesn't require the newline, it only reports the files which differ from the most of the checked files. The check is optional.",
+            wrong: "# .env\nFOO=BAR\\n\n# .env.test\nFOO=BAZ",
+            correct: "# .env\nFOO=BAR\\n\n# .env.test\nFOO=BAZ\\n",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(file_name: &str, number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(file_name),
+                file_name: file_name.to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn minority_without_newline_test() {
+        let mut checker = InconsistentEndingNewlineChecker::default();
+        let files = vec![
+            vec![
+                line_entry(".env", 1, 2, "FOO=BAR"),
+                line_entry(".env", 2, 2, "\n"),
+            ],
+            vec![line_entry(".env.test", 1, 1, "FOO=BAZ")],
+            vec![
+                line_entry(".env.local", 1, 2, "FOO=QUX"),
+                line_entry(".env.local", 2, 2, "\n"),
+            ],
+        ];
+        let expected = vec![Warning::new(
+            files[1][0].clone(),
+            "InconsistentEndingNewline",
+            String::from("The file doesn't end with a newline, unlike most of the checked files"),
+        )];
+
+        assert_eq!(expected, checker.run(&files));
+    }
+
+    #[test]
+    fn minority_with_newline_test() {
+        let mut checker = InconsistentEndingNewlineChecker::default();
+        let files = vec![
+            vec![
+                line_entry(".env", 1, 2, "FOO=BAR"),
+                line_entry(".env", 2, 2, "\n"),
+            ],
+            vec![line_entry(".env.test", 1, 1, "FOO=BAZ")],
+            vec![line_entry(".env.local", 1, 1, "FOO=QUX")],
+        ];
+        let expected = vec![Warning::new(
+            files[0][1].clone(),
+            "InconsistentEndingNewline",
+            String::from("The file ends with a newline, unlike most of the checked files"),
+        )];
+
+        assert_eq!(expected, checker.run(&files));
+    }
+
+    #[test]
+    fn consistent_files_test() {
+        let mut checker = InconsistentEndingNewlineChecker::default();
+        let files = vec![
+            vec![line_entry(".env", 1, 1, "FOO=BAR")],
+            vec![line_entry(".env.test", 1, 1, "FOO=BAZ")],
+        ];
+
+        assert!(checker.run(&files).is_empty());
+    }
+}
@@ -0,0 +1,143 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use std::collections::HashMap;
+
+pub(crate) struct InconsistentExportChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    // The first line of each key, with and without `export`
+    keys: HashMap<(String, bool), usize>,
+}
+
+impl InconsistentExportChecker<'_> {
+    fn message(&self, key: &str, exported_line: usize, line: usize) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", &exported_line.to_string())
+            .replace("{3}", &line.to_string())
+    }
+}
+
+impl Default for InconsistentExportChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "InconsistentExport",
+            template: "The {1} key is defined with export at line {2} and without it at line {3}",
+            keys: HashMap::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for InconsistentExportChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let Some(key) = line.get_key() {
+            let is_exported = line.is_exported();
+
+            if let Some(&other_line) = self.keys.get(&(key.clone(), !is_exported)) {
+                let (exported_line, unexported_line) = if is_exported {
+                    (line.number, other_line)
+                } else {
+                    (other_line, line.number)
+                };
+
+                warnings.push(Warning::new(
+                    line.clone(),
+                    self.name(),
+                    self.message(&key, exported_line, unexported_line),
+                ));
+            }
+
+            self.keys.entry((key, is_exported)).or_insert(line.number);
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A key defined both with and without export is confusing: it's not clear which definition takes precedence and whether the variable is passed to subprocesses when the file is sourced by a shell.",
+            wrong: "export FOO=1\nFOO=2",
+            correct: "export FOO=2",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_lines(lines: &[LineEntry]) -> Vec<Warning> {
+        let mut checker = InconsistentExportChecker::default();
+        lines.iter().flat_map(|line| checker.run(line)).collect()
+    }
+
+    #[test]
+    fn exported_and_unexported_key_test() {
+        let lines = vec![
+            line_entry(1, 3, "export FOO=1"),
+            line_entry(2, 3, "BAR=1"),
+            line_entry(3, 3, "FOO=2"),
+        ];
+        let expected = vec![Warning::new(
+            lines[2].clone(),
+            "InconsistentExport",
+            String::from("The FOO key is defined with export at line 1 and without it at line 3"),
+        )];
+
+        assert_eq!(expected, run_lines(&lines));
+    }
+
+    #[test]
+    fn unexported_and_exported_key_test() {
+        let lines = vec![line_entry(1, 2, "FOO=1"), line_entry(2, 2, "export FOO=2")];
+        let expected = vec![Warning::new(
+            lines[1].clone(),
+            "InconsistentExport",
+            String::from("The FOO key is defined with export at line 2 and without it at line 1"),
+        )];
+
+        assert_eq!(expected, run_lines(&lines));
+    }
+
+    #[test]
+    fn consistent_export_test() {
+        let lines = vec![
+            line_entry(1, 3, "export FOO=1"),
+            line_entry(2, 3, "export FOO=2"),
+            line_entry(3, 3, "BAR=1"),
+        ];
+
+        assert!(run_lines(&lines).is_empty());
+    }
+}
@@ -0,0 +1,247 @@
+use crate::checks::{Explanation, MultiFileCheck};
+use crate::common::*;
+use std::collections::HashMap;
+use std::fmt;
+
+pub(crate) struct InconsistentValueTypeChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ValueType {
+    Bool,
+    Int,
+    Url,
+    String,
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ValueType::Bool => "bool",
+            ValueType::Int => "int",
+            ValueType::Url => "url",
+            ValueType::String => "string",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl ValueType {
+    /// Infers a rough type of a value:
+    /// * `bool` - `true`, `false`, `yes`, `no`, `on`, `off` (case-insensitive)
+    /// * `int` - digits with an optional sign
+    /// * `url` - `scheme://...`
+    /// * `string` - anything else
+    ///
+    /// Empty values have no type.
+    fn infer(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let value = unquote(value).unwrap_or(value);
+
+        if value.is_empty() {
+            return None;
+        }
+
+        let lowercase = value.to_lowercase();
+        let digits = lowercase.trim_start_matches(['-', '+']);
+
+        let value_type =
+            if ["true", "false", "yes", "no", "on", "off"].contains(&lowercase.as_str()) {
+                ValueType::Bool
+            } else if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                ValueType::Int
+            } else if is_url(&lowercase) {
+                ValueType::Url
+            } else {
+                ValueType::String
+            };
+
+        Some(value_type)
+    }
+}
+
+fn is_url(value: &str) -> bool {
+    match value.find("://") {
+        Some(index) => {
+            index > 0
+                && value[..index]
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+        }
+        None => false,
+    }
+}
+
+impl InconsistentValueTypeChecker<'_> {
+    fn message(
+        &self,
+        key: &str,
+        value_type: ValueType,
+        other: ValueType,
+        file: &FileEntry,
+    ) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", &value_type.to_string())
+            .replace("{3}", &other.to_string())
+            .replace("{4}", &file.to_string())
+    }
+}
+
+impl Default for InconsistentValueTypeChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "InconsistentValueType",
+            template: "The {1} key has the {2} type, but the {3} type in {4}",
+        }
+    }
+}
+
+impl<'a> MultiFileCheck<'a> for InconsistentValueTypeChecker<'a> {
+    fn run(&mut self, files: &[Vec<LineEntry>]) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        // The type of the first definition of a key and the file where it was found
+        let mut first_types: HashMap<String, (ValueType, FileEntry)> = HashMap::new();
+
+        for lines in files {
+            for line in lines {
+                let (key, value) = match (line.get_key(), line.get_value()) {
+                    (Some(key), Some(value)) => (key, value),
+                    _ => continue,
+                };
+                let value_type = match ValueType::infer(&value) {
+                    Some(value_type) => value_type,
+                    None => continue,
+                };
+
+                match first_types.get(&key) {
+                    Some((first_type, file)) => {
+                        if *first_type != value_type && *file != line.file {
+                            warnings.push(Warning::new(
+                                line.clone(),
+                                self.name(),
+                                self.message(&key, value_type, *first_type, file),
+                            ));
+                        }
+                    }
+                    None => {
+                        first_types.insert(key, (value_type, line.file.clone()));
+                    }
+                }
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "The same key should have the same kind of value in every environment. When one file uses `true` and another uses `1`, the application has to handle both forms, and a value that works in one environment may break in another. The type is roughly inferred as bool, int, url or string.",
+            wrong: "# .env.dev\nDEBUG=true\n# .env.prod\nDEBUG=0",
+            correct: "# .env.dev\nDEBUG=true\n# .env.prod\nDEBUG=false",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(file_name: &str, number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(file_name),
+                file_name: file_name.to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn infer_test() {
+        let asserts = vec![
+            ("true", Some(ValueType::Bool)),
+            ("\"OFF\"", Some(ValueType::Bool)),
+            ("1", Some(ValueType::Int)),
+            ("-42", Some(ValueType::Int)),
+            ("https://example.com", Some(ValueType::Url)),
+            ("postgres://user@host/db", Some(ValueType::Url)),
+            ("foo", Some(ValueType::String)),
+            ("1.5", Some(ValueType::String)),
+            ("://foo", Some(ValueType::String)),
+            ("", None),
+            ("''", None),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, ValueType::infer(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn consistent_types_test() {
+        let mut checker = InconsistentValueTypeChecker::default();
+        let files = vec![
+            vec![
+                line_entry(".env.dev", 1, 2, "DEBUG=true"),
+                line_entry(".env.dev", 2, 2, "PORT=80"),
+            ],
+            vec![
+                line_entry(".env.prod", 1, 3, "DEBUG=false"),
+                line_entry(".env.prod", 2, 3, "PORT="),
+                line_entry(".env.prod", 3, 3, "URL=http://localhost"),
+            ],
+        ];
+
+        assert!(checker.run(&files).is_empty());
+    }
+
+    #[test]
+    fn inconsistent_types_test() {
+        let mut checker = InconsistentValueTypeChecker::default();
+        let files = vec![
+            vec![line_entry(".env.dev", 1, 1, "DEBUG=true")],
+            vec![line_entry(".env.prod", 1, 1, "DEBUG=1")],
+            vec![line_entry(".env.test", 1, 1, "DEBUG=yes")],
+        ];
+        let expected = vec![Warning::new(
+            files[1][0].clone(),
+            "InconsistentValueType",
+            String::from("The DEBUG key has the int type, but the bool type in .env.dev"),
+        )];
+
+        assert_eq!(expected, checker.run(&files));
+    }
+
+    #[test]
+    fn same_file_is_ignored_test() {
+        let mut checker = InconsistentValueTypeChecker::default();
+        let files = vec![vec![
+            line_entry(".env", 1, 2, "DEBUG=true"),
+            line_entry(".env", 2, 2, "DEBUG=1"),
+        ]];
+
+        assert!(checker.run(&files).is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
 
 pub(crate) struct IncorrectDelimiterChecker<'a> {
@@ -7,11 +7,27 @@ pub(crate) struct IncorrectDelimiterChecker<'a> {
 }
 
 impl IncorrectDelimiterChecker<'_> {
-    fn message(&self, key: &str) -> String {
-        self.template.replace("{}", &key)
+    fn message(&self, key: &str, spans: &[Span]) -> String {
+        let message = self.template.replace("{}", key);
+        if spans.len() < 2 {
+            return message;
+        }
+
+        let columns: Vec<String> = spans.iter().map(|s| s.column.to_string()).collect();
+        format!(
+            "{} ({} incorrect delimiters at columns {})",
+            message,
+            spans.len(),
+            columns.join(", ")
+        )
     }
 }
 
+fn is_incorrect_delimiter(c: char) -> bool {
+    // invisible characters are reported by the InvisibleCharacter check
+    !c.is_alphanumeric() && c != '_' && !is_invisible_char(c)
+}
+
 impl Default for IncorrectDelimiterChecker<'_> {
     fn default() -> Self {
         Self {
@@ -21,28 +37,72 @@ impl Default for IncorrectDelimiterChecker<'_> {
     }
 }
 
-impl Check for IncorrectDelimiterChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
-        let key = line.get_key()?;
+impl<'a> Check<'a> for IncorrectDelimiterChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let key = match line.get_key() {
+            Some(key) => key,
+            None => return warnings,
+        };
 
         // delimiters occur /between/ characters, not as the initial character, so we should
         // remove all invalid leading characters before checking for incorrect delimiters
         let cleaned_key = remove_invalid_leading_chars(&key);
 
-        if cleaned_key
-            .trim()
+        let start = key.chars().count() - cleaned_key.chars().count();
+        let end = start + cleaned_key.trim_end().chars().count();
+
+        // the key is the last occurrence of it before the equal sign (after an `export ` prefix)
+        let offset = line
+            .raw_string
+            .find('=')
+            .and_then(|index| line.raw_string[..index].rfind(key.as_str()))
+            .map_or(0, |index| line.raw_string[..index].chars().count());
+
+        let spans: Vec<Span> = key
             .chars()
-            .any(|c| !c.is_alphanumeric() && c != '_')
-        {
-            return Some(Warning::new(line.clone(), self.name(), self.message(&key)));
+            .enumerate()
+            .take(end)
+            .skip(start)
+            .filter(|(_, c)| is_incorrect_delimiter(*c))
+            .map(|(index, _)| Span {
+                column: offset + index + 1,
+                length: 1,
+            })
+            .collect();
+
+        if !spans.is_empty() {
+            let mut warning = Warning::new(line.clone(), self.name(), self.message(&key, &spans));
+            warning.spans = spans;
+            warnings.push(warning);
         }
 
-        None
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Environment variable names may only contain letters, digits and underscores. Keys using other delimiters (such as `-` or `.`) can't be exported by a shell and are handled inconsistently by loaders. Words in a key should be separated with an underscore.",
+            wrong: "FOO-BAR=FOOBAR",
+            correct: "FOO_BAR=FOOBAR",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -62,7 +122,7 @@ mod tests {
             },
             raw_string: String::from("FOO_BAR=FOOBAR"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -77,7 +137,7 @@ mod tests {
             },
             raw_string: String::from("F1OO=BAR"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -93,7 +153,7 @@ mod tests {
             raw_string: String::from("*FOO=BAR"),
         };
         // expect None because this warning should be found by LeadingCharacterChecker
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -109,11 +169,15 @@ mod tests {
             raw_string: String::from("***F-OOBAR=BAZ"),
         };
 
-        let expected = Some(Warning::new(
+        let mut expected = vec![Warning::new(
             line.clone(),
             "IncorrectDelimiter",
             String::from("The ***F-OOBAR key has incorrect delimiter"),
-        ));
+        )];
+        expected[0].spans = vec![Span {
+            column: 5,
+            length: 1,
+        }];
 
         assert_eq!(expected, checker.run(&line));
     }
@@ -130,11 +194,15 @@ mod tests {
             },
             raw_string: String::from("FOO-BAR=FOOBAR"),
         };
-        let expected = Some(Warning::new(
+        let mut expected = vec![Warning::new(
             line.clone(),
             "IncorrectDelimiter",
             String::from("The FOO-BAR key has incorrect delimiter"),
-        ));
+        )];
+        expected[0].spans = vec![Span {
+            column: 4,
+            length: 1,
+        }];
         assert_eq!(expected, checker.run(&line));
     }
 
@@ -150,11 +218,15 @@ mod tests {
             },
             raw_string: String::from("FOO BAR=FOOBAR"),
         };
-        let expected = Some(Warning::new(
+        let mut expected = vec![Warning::new(
             line.clone(),
             "IncorrectDelimiter",
             String::from("The FOO BAR key has incorrect delimiter"),
-        ));
+        )];
+        expected[0].spans = vec![Span {
+            column: 4,
+            length: 1,
+        }];
         assert_eq!(expected, checker.run(&line));
     }
 
@@ -171,7 +243,7 @@ mod tests {
             raw_string: String::from("FOO-BAR"),
         };
         // there's no key, so KeyWithoutValueChecker should catch this error
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -187,7 +259,7 @@ mod tests {
             raw_string: String::from("FOO_BAR =FOOBAR"),
         };
         // has a trailing space, so SpaceCharacterChecker should catch this error
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -202,7 +274,7 @@ mod tests {
             },
             raw_string: String::from(""),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -217,6 +289,76 @@ mod tests {
             },
             raw_string: String::from("F=BAR"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn invisible_character_run() {
+        let mut checker = IncorrectDelimiterChecker::default();
+        let line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from("FOO\u{200B}=BAR"),
+        };
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn multiple_delimiters_run() {
+        let mut checker = IncorrectDelimiterChecker::default();
+        let line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from("FOO-BAR.BAZ=FOOBAR"),
+        };
+        let warnings = checker.run(&line);
+
+        assert_eq!(1, warnings.len());
+        assert_eq!(
+            "The FOO-BAR.BAZ key has incorrect delimiter (2 incorrect delimiters at columns 4, 8)",
+            warnings[0].message()
+        );
+        assert_eq!(
+            vec![
+                Span {
+                    column: 4,
+                    length: 1
+                },
+                Span {
+                    column: 8,
+                    length: 1
+                }
+            ],
+            warnings[0].spans
+        );
+    }
+
+    #[test]
+    fn exported_key_spans_run() {
+        let mut checker = IncorrectDelimiterChecker::default();
+        let line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from("export port-a-b=1"),
+        };
+        let columns: Vec<usize> = checker.run(&line)[0]
+            .spans
+            .iter()
+            .map(|s| s.column)
+            .collect();
+
+        assert_eq!(vec![12, 14], columns);
     }
 }
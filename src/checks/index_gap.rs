@@ -0,0 +1,213 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::IndexGapConfig;
+
+pub(crate) struct IndexGapChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    min_family_size: usize,
+    // The keys without the numeric suffix, the line of the first key and the indexes of each family
+    families: Vec<(String, LineEntry, Vec<usize>)>,
+}
+
+impl<'a> IndexGapChecker<'a> {
+    pub(crate) fn new(config: &IndexGapConfig) -> Self {
+        Self {
+            min_family_size: config.min_family_size,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, prefix: &str, missing: &[usize]) -> String {
+        let keys: Vec<String> = missing
+            .iter()
+            .map(|index| format!("{}{}", prefix, index))
+            .collect();
+
+        self.template
+            .replace("{1}", prefix)
+            .replace("{2}", &keys.join(", "))
+    }
+
+    fn add_key(&mut self, line: &LineEntry, key: &str) {
+        let prefix = key.trim_end_matches(|c: char| c.is_ascii_digit());
+        let index = match key[prefix.len()..].parse::<usize>() {
+            Ok(index) if !prefix.is_empty() => index,
+            _ => return,
+        };
+
+        match self.families.iter_mut().find(|(p, _, _)| p == prefix) {
+            Some((_, _, indexes)) => indexes.push(index),
+            None => self
+                .families
+                .push((String::from(prefix), line.clone(), vec![index])),
+        }
+    }
+}
+
+/// Returns the indexes missing between the smallest and the largest index of a family. They
+/// are not returned when more indexes are missing than present, e.g. for `PORT_8080` and
+/// `PORT_9000`, since such numbers are not a sequence
+fn find_missing_indexes(indexes: &[usize]) -> Vec<usize> {
+    let mut indexes = indexes.to_vec();
+    indexes.sort_unstable();
+    indexes.dedup();
+
+    let (first, last) = match (indexes.first(), indexes.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return Vec::new(),
+    };
+
+    if last - first + 1 - indexes.len() >= indexes.len() {
+        return Vec::new();
+    }
+
+    (first..last)
+        .filter(|index| indexes.binary_search(index).is_err())
+        .collect()
+}
+
+impl Default for IndexGapChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "IndexGap",
+            template: "The {1}* keys have a gap in their indexes, the missing keys: {2}",
+            min_family_size: IndexGapConfig::default().min_family_size,
+            families: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for IndexGapChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        if !line.is_comment() {
+            if let Some(key) = line.get_key() {
+                self.add_key(line, &key);
+            }
+        }
+
+        if !line.is_last_line() {
+            return Vec::new();
+        }
+
+        self.families
+            .iter()
+            .filter(|(_, _, indexes)| indexes.len() >= self.min_family_size)
+            .filter_map(|(prefix, first_line, indexes)| {
+                let missing = find_missing_indexes(indexes);
+                if missing.is_empty() {
+                    None
+                } else {
+                    let message = self.message(prefix, &missing);
+                    Some(Warning::new(first_line.clone(), self.name(), message))
+                }
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Keys with numeric indexes like `ITEM_0`, `ITEM_1`, `ITEM_2` are often read in a loop which stops at the first missing index, so the keys after a gap are silently ignored. A gap is usually a key which was removed or renamed by mistake. The minimum number of keys of a family can be set in the config file.",
+            wrong: "ITEM_0=a\nITEM_1=b\nITEM_3=d",
+            correct: "ITEM_0=a\nITEM_1=b\nITEM_2=c\nITEM_3=d",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut IndexGapChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn find_missing_indexes_test() {
+        let asserts = vec![
+            (vec![0, 1, 3], vec![2]),
+            (vec![5, 1, 2], vec![3, 4]),
+            (vec![1, 2, 3], vec![]),
+            (vec![1, 1, 2], vec![]),
+            (vec![8080, 8081, 9000], vec![]),
+        ];
+
+        for (indexes, expected) in asserts {
+            assert_eq!(
+                expected,
+                find_missing_indexes(&indexes),
+                "Indexes: {:?}",
+                indexes
+            );
+        }
+    }
+
+    #[test]
+    fn gapped_sequence_test() {
+        let mut checker = IndexGapChecker::default();
+        let lines = ["ITEM_0=a", "ITEM_1=b", "# ITEM_2=c", "ITEM_3=d"];
+        let expected = vec![Warning::new(
+            line_entry(1, 4, "ITEM_0=a"),
+            "IndexGap",
+            String::from("The ITEM_* keys have a gap in their indexes, the missing keys: ITEM_2"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn contiguous_sequence_test() {
+        let mut checker = IndexGapChecker::default();
+        let lines = ["ITEM_0=a", "ITEM_1=b", "ITEM_2=c", "OAUTH2=d", "HOST1=e"];
+
+        assert!(run_checker(&mut checker, &lines).is_empty());
+    }
+
+    #[test]
+    fn min_family_size_test() {
+        let mut checker = IndexGapChecker::default();
+        assert!(run_checker(&mut checker, &["HOST1=a", "HOST3=b"]).is_empty());
+
+        let config = IndexGapConfig { min_family_size: 2 };
+        let mut checker = IndexGapChecker::new(&config);
+        assert_eq!(1, run_checker(&mut checker, &["HOST1=a", "HOST3=b"]).len());
+    }
+}
@@ -0,0 +1,243 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::IntegerRangeConfig;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// An integer type which the values are parsed into
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IntegerWidth {
+    I32,
+    U32,
+    #[default]
+    I64,
+    U64,
+}
+
+impl fmt::Display for IntegerWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            IntegerWidth::I32 => "i32",
+            IntegerWidth::U32 => "u32",
+            IntegerWidth::I64 => "i64",
+            IntegerWidth::U64 => "u64",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl IntegerWidth {
+    fn contains(self, number: i128) -> bool {
+        match self {
+            IntegerWidth::I32 => i32::try_from(number).is_ok(),
+            IntegerWidth::U32 => u32::try_from(number).is_ok(),
+            IntegerWidth::I64 => i64::try_from(number).is_ok(),
+            IntegerWidth::U64 => u64::try_from(number).is_ok(),
+        }
+    }
+}
+
+pub(crate) struct IntegerRangeChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    width: IntegerWidth,
+    suffixes: Vec<String>,
+}
+
+impl IntegerRangeChecker<'_> {
+    pub(crate) fn new(config: &IntegerRangeConfig) -> Self {
+        Self {
+            width: config.width,
+            suffixes: config.suffixes.clone(),
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", &self.width.to_string())
+    }
+}
+
+/// Checks if a value is an integer with an optional sign, e.g. `42` or `-7`
+fn is_integer(value: &str) -> bool {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Checks if an integer value fits in the type. Integers which don't fit even in `i128`
+/// don't fit in any of the types
+fn is_in_range(value: &str, width: IntegerWidth) -> bool {
+    value
+        .parse::<i128>()
+        .is_ok_and(|number| width.contains(number))
+}
+
+impl Default for IntegerRangeChecker<'_> {
+    fn default() -> Self {
+        let config = IntegerRangeConfig::default();
+        Self {
+            name: "IntegerRange",
+            template: "The {1} key has an integer which doesn't fit in {2}",
+            width: config.width,
+            suffixes: config.suffixes,
+        }
+    }
+}
+
+impl<'a> Check<'a> for IntegerRangeChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+        if !self.suffixes.is_empty() && !self.suffixes.iter().any(|s| key.ends_with(s.as_str())) {
+            return warnings;
+        }
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value);
+
+        if is_integer(value) && !is_in_range(value, self.width) {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "An integer which doesn't fit in the type of the setting fails to parse or wraps around, usually only when the application starts. The type (`i32`, `u32`, `i64` or `u64`) and the suffixes of the checked keys can be set in the config file, all keys are checked by default.",
+            wrong: "RETRIES=99999999999999999999",
+            correct: "RETRIES=5",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn is_integer_test() {
+        let asserts = vec![
+            ("42", true),
+            ("-7", true),
+            ("+7", true),
+            ("99999999999999999999999999999999999999999", true),
+            ("-", false),
+            ("1.5", false),
+            ("1e3", false),
+            ("", false),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, is_integer(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn is_in_range_test() {
+        let asserts = vec![
+            ("9223372036854775807", IntegerWidth::I64, true),
+            ("9223372036854775808", IntegerWidth::I64, false),
+            ("-9223372036854775808", IntegerWidth::I64, true),
+            ("-9223372036854775809", IntegerWidth::I64, false),
+            ("18446744073709551615", IntegerWidth::U64, true),
+            ("18446744073709551616", IntegerWidth::U64, false),
+            ("-1", IntegerWidth::U64, false),
+            ("2147483647", IntegerWidth::I32, true),
+            ("2147483648", IntegerWidth::I32, false),
+            ("4294967295", IntegerWidth::U32, true),
+            ("4294967296", IntegerWidth::U32, false),
+            (
+                "99999999999999999999999999999999999999999",
+                IntegerWidth::U64,
+                false,
+            ),
+        ];
+
+        for (value, width, expected) in asserts {
+            assert_eq!(
+                expected,
+                is_in_range(value, width),
+                "Value: {}, width: {}",
+                value,
+                width
+            );
+        }
+    }
+
+    #[test]
+    fn integer_range_test() {
+        let mut checker = IntegerRangeChecker::default();
+        let line = line_entry("RETRIES=\"99999999999999999999\" # forever");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "IntegerRange",
+            String::from("The RETRIES key has an integer which doesn't fit in i64"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry("RETRIES=5")).is_empty());
+        assert!(checker
+            .run(&line_entry("VERSION=1.99999999999999999999"))
+            .is_empty());
+    }
+
+    #[test]
+    fn configured_test() {
+        let mut checker = IntegerRangeChecker::new(&IntegerRangeConfig {
+            width: IntegerWidth::I32,
+            suffixes: vec![String::from("_TIMEOUT")],
+        });
+        let line = line_entry("DB_TIMEOUT=3000000000");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "IntegerRange",
+            String::from("The DB_TIMEOUT key has an integer which doesn't fit in i32"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry("PHONE=3000000000")).is_empty());
+    }
+}
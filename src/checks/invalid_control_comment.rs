@@ -0,0 +1,131 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct InvalidControlCommentChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl InvalidControlCommentChecker<'_> {
+    fn message(&self) -> String {
+        String::from(self.template)
+    }
+}
+
+/// Checks if a line looks like a control comment but isn't parsed as one: a comment with
+/// a misspelled directive, or a directive after a key (in its value or in an inline comment)
+fn is_invalid_control_comment(line: &str) -> bool {
+    if Comment::parse(line).is_some() {
+        return false;
+    }
+
+    let line = line.trim().to_lowercase();
+    match line.strip_prefix('#') {
+        Some(text) => text
+            .trim_start_matches(|c: char| c == '#' || c.is_whitespace())
+            .starts_with("dotenv-linter:"),
+        None => line.contains("dotenv-linter:off") || line.contains("dotenv-linter:on"),
+    }
+}
+
+impl Default for InvalidControlCommentChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "InvalidControlComment",
+            template: "The control comment has no effect, use `# dotenv-linter:off` or `# dotenv-linter:on` on a separate comment line",
+        }
+    }
+}
+
+impl<'a> Check<'a> for InvalidControlCommentChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        if is_invalid_control_comment(&line.raw_string) {
+            vec![Warning::new(line.clone(), self.name(), self.message())]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Control comments only work on a separate comment line which starts with `dotenv-linter:off` or `dotenv-linter:on`. A directive after a key or with a typo is silently ignored, so the checks it was meant to turn off are still run.",
+            wrong: "FOO=bar # dotenv-linter:off LowercaseKey\n# dotenv-linter: off",
+            correct: "# dotenv-linter:off LowercaseKey\nFOO=bar",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn is_invalid_control_comment_test() {
+        let asserts = vec![
+            ("# dotenv-linter:off", false),
+            ("#dotenv-linter:on LowercaseKey", false),
+            ("# dotenv-linter: off", true),
+            ("# dotenv-linter:disable UnorderedKey", true),
+            ("## dotenv-linter:off", true),
+            ("# Dotenv-Linter:off", true),
+            ("FOO=bar # dotenv-linter:off", true),
+            ("FOO=dotenv-linter:on", true),
+            ("# Checked by dotenv-linter: https://example.com", false),
+            ("FOO=bar", false),
+        ];
+
+        for (line, expected) in asserts {
+            assert_eq!(expected, is_invalid_control_comment(line), "Line: {}", line);
+        }
+    }
+
+    #[test]
+    fn directive_after_key_test() {
+        let mut checker = InvalidControlCommentChecker::default();
+        let line = line_entry("FOO=bar # dotenv-linter:off LowercaseKey");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "InvalidControlComment",
+            String::from("The control comment has no effect, use `# dotenv-linter:off` or `# dotenv-linter:on` on a separate comment line"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn control_comment_test() {
+        let mut checker = InvalidControlCommentChecker::default();
+        let line = line_entry("# dotenv-linter:off LowercaseKey");
+
+        assert!(checker.run(&line).is_empty());
+    }
+}
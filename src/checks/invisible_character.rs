@@ -0,0 +1,138 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct InvisibleCharacterChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl InvisibleCharacterChecker<'_> {
+    fn message(&self, c: char, position: usize) -> String {
+        self.template
+            .replace("{1}", &format!("U+{:04X}", c as u32))
+            .replace("{2}", &position.to_string())
+    }
+}
+
+impl Default for InvisibleCharacterChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "InvisibleCharacter",
+            template: "Invisible character {1} detected at position {2}",
+        }
+    }
+}
+
+impl<'a> Check<'a> for InvisibleCharacterChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        line.raw_string
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| is_invisible_char(*c))
+            .map(|(i, c)| Warning::new(line.clone(), self.name(), self.message(c, i + 1)))
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Zero-width spaces, non-breaking spaces and other invisible characters are usually pasted together with a copied value. They can't be seen in an editor, but they make the key or the value differ from what it looks like.",
+            wrong: "FOO<U+200B>=BAR",
+            correct: "FOO=BAR",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn visible_characters_test() {
+        let mut checker = InvisibleCharacterChecker::default();
+        let line = line_entry(1, 1, "FOO=\"bar baz\"\t");
+
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn zero_width_space_in_key_test() {
+        let mut checker = InvisibleCharacterChecker::default();
+        let line = line_entry(1, 1, "FOO\u{200B}=BAR");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "InvisibleCharacter",
+            String::from("Invisible character U+200B detected at position 4"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn several_characters_test() {
+        let mut checker = InvisibleCharacterChecker::default();
+        let line = line_entry(1, 1, "\u{FEFF}FOO=b\u{00A0}r");
+        let expected = vec![
+            Warning::new(
+                line.clone(),
+                "InvisibleCharacter",
+                String::from("Invisible character U+FEFF detected at position 1"),
+            ),
+            Warning::new(
+                line.clone(),
+                "InvisibleCharacter",
+                String::from("Invisible character U+00A0 detected at position 7"),
+            ),
+        ];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn control_characters_in_value_test() {
+        let mut checker = InvisibleCharacterChecker::default();
+        let line = line_entry(1, 1, "FOO=b\0a\u{1B}r");
+        let expected = vec![
+            Warning::new(
+                line.clone(),
+                "InvisibleCharacter",
+                String::from("Invisible character U+0000 detected at position 6"),
+            ),
+            Warning::new(
+                line.clone(),
+                "InvisibleCharacter",
+                String::from("Invisible character U+001B detected at position 8"),
+            ),
+        ];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+}
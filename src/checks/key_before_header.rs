@@ -0,0 +1,160 @@
+use crate::checks::commented_key::commented_key;
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::KeyBeforeHeaderConfig;
+
+pub(crate) struct KeyBeforeHeaderChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    ignore_commented_keys: bool,
+    has_keys: bool,
+    in_comment_block: bool,
+}
+
+impl<'a> KeyBeforeHeaderChecker<'a> {
+    pub(crate) fn new(config: &KeyBeforeHeaderConfig) -> Self {
+        Self {
+            ignore_commented_keys: config.ignore_commented_keys,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self) -> String {
+        String::from(self.template)
+    }
+}
+
+impl Default for KeyBeforeHeaderChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "KeyBeforeHeader",
+            template: "The comment should be moved to the header of the file, before the first key",
+            ignore_commented_keys: KeyBeforeHeaderConfig::default().ignore_commented_keys,
+            has_keys: false,
+            in_comment_block: false,
+        }
+    }
+}
+
+impl<'a> Check<'a> for KeyBeforeHeaderChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        if !line.is_comment() {
+            self.in_comment_block = false;
+            self.has_keys = self.has_keys || !line.is_empty();
+            return Vec::new();
+        }
+
+        if self.ignore_commented_keys && commented_key(&line.raw_string).is_some() {
+            return Vec::new();
+        }
+
+        // Only the first line of a comment block is reported
+        let is_block_start = !self.in_comment_block;
+        self.in_comment_block = true;
+
+        if self.has_keys && is_block_start {
+            vec![Warning::new(line.clone(), self.name(), self.message())]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Some teams keep all the documentation of a file at its top, so it can be read at once before the keys. Comments between the keys break this rule. Commented out keys (`# KEY=value`) are not documentation and are ignored by default.",
+            wrong: "# The host of the app\nHOST=localhost\n# The port of the app\nPORT=80",
+            correct: "# The host and the port of the app\nHOST=localhost\nPORT=80",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut KeyBeforeHeaderChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn header_comments_test() {
+        let mut checker = KeyBeforeHeaderChecker::default();
+        let lines = ["# The header", "# of the file", "", "A=1", "B=2"];
+
+        assert!(run_checker(&mut checker, &lines).is_empty());
+    }
+
+    #[test]
+    fn comment_block_after_keys_test() {
+        let mut checker = KeyBeforeHeaderChecker::default();
+        let lines = [
+            "# The header",
+            "A=1",
+            "",
+            "# The B key",
+            "# is documented here",
+            "B=2",
+        ];
+        let expected = vec![Warning::new(
+            line_entry(4, 6, "# The B key"),
+            "KeyBeforeHeader",
+            String::from(
+                "The comment should be moved to the header of the file, before the first key",
+            ),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn commented_keys_test() {
+        let lines = ["A=1", "# OLD_B=2", "B=2"];
+
+        let mut checker = KeyBeforeHeaderChecker::default();
+        assert!(run_checker(&mut checker, &lines).is_empty());
+
+        let config = KeyBeforeHeaderConfig {
+            ignore_commented_keys: false,
+        };
+        let mut checker = KeyBeforeHeaderChecker::new(&config);
+        assert_eq!(1, run_checker(&mut checker, &lines).len());
+    }
+}
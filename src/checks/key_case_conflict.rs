@@ -0,0 +1,141 @@
+use crate::checks::{Explanation, MultiFileCheck};
+use crate::common::*;
+use std::collections::HashMap;
+
+pub(crate) struct KeyCaseConflictChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl KeyCaseConflictChecker<'_> {
+    fn message(&self, key: &str, other_key: &str, file: &FileEntry) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", other_key)
+            .replace("{3}", &file.to_string())
+    }
+}
+
+impl Default for KeyCaseConflictChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "KeyCaseConflict",
+            template: "The {1} key differs only by case from the {2} key in {3}",
+        }
+    }
+}
+
+impl<'a> MultiFileCheck<'a> for KeyCaseConflictChecker<'a> {
+    fn run(&mut self, files: &[Vec<LineEntry>]) -> Vec<Warning> {
+        // The first definition of each key in lowercase: the key and the index of its file
+        let mut first_keys: HashMap<String, (String, usize)> = HashMap::new();
+        let mut warnings = Vec::new();
+
+        for (i, lines) in files.iter().enumerate() {
+            for line in lines {
+                let key = match line.get_key() {
+                    Some(key) => key,
+                    None => continue,
+                };
+
+                let (first_key, first_file) = first_keys
+                    .entry(key.to_lowercase())
+                    .or_insert_with(|| (key.clone(), i));
+
+                // Keys of the same file are reported by NormalizedCollision
+                if *first_key != key && *first_file != i {
+                    warnings.push(Warning::new(
+                        line.clone(),
+                        self.name(),
+                        self.message(&key, first_key, &files[*first_file][0].file),
+                    ));
+                }
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Environment variables are case-insensitive on Windows, so keys which differ only by case in different files (e.g. `Path` in `.env` and `PATH` in `.env.local`) override each other there, while on other platforms both are set. The check is optional and is useful for projects which run on several platforms.",
+            wrong: "# .env\nPath=/usr/bin\n# .env.local\nPATH=/usr/local/bin",
+            correct: "# .env\nPATH=/usr/bin\n# .env.local\nPATH=/usr/local/bin",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(file_name: &str, number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(file_name),
+                file_name: file_name.to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn case_conflict_test() {
+        let mut checker = KeyCaseConflictChecker::default();
+        let files = vec![
+            vec![
+                line_entry(".env", 1, 2, "Path=/usr/bin"),
+                line_entry(".env", 2, 2, "HOST=localhost"),
+            ],
+            vec![
+                line_entry(".env.local", 1, 2, "PATH=/usr/local/bin"),
+                line_entry(".env.local", 2, 2, "HOST=127.0.0.1"),
+            ],
+            vec![line_entry(".env.test", 1, 1, "path=/tmp")],
+        ];
+        let expected = vec![
+            Warning::new(
+                files[1][0].clone(),
+                "KeyCaseConflict",
+                String::from("The PATH key differs only by case from the Path key in .env"),
+            ),
+            Warning::new(
+                files[2][0].clone(),
+                "KeyCaseConflict",
+                String::from("The path key differs only by case from the Path key in .env"),
+            ),
+        ];
+
+        assert_eq!(expected, checker.run(&files));
+    }
+
+    #[test]
+    fn same_file_test() {
+        let mut checker = KeyCaseConflictChecker::default();
+        let files = vec![vec![
+            line_entry(".env", 1, 2, "Path=/usr/bin"),
+            line_entry(".env", 2, 2, "PATH=/usr/local/bin"),
+        ]];
+
+        assert!(checker.run(&files).is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct KeyWhitespaceChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl KeyWhitespaceChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for KeyWhitespaceChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "KeyWhitespace",
+            template: "The {} key has extra whitespace after the export prefix",
+        }
+    }
+}
+
+impl<'a> Check<'a> for KeyWhitespaceChecker<'a> {
+    // Whitespace at the start of a line is reported by LeadingCharacter, and whitespace
+    // before the equal sign by SpaceCharacter, so only the whitespace after `export `
+    // is left for this check
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        let rest = match line.raw_string.trim_start().strip_prefix(EXPORT_PREFIX) {
+            Some(rest) => rest,
+            None => return warnings,
+        };
+
+        if rest.starts_with(char::is_whitespace) {
+            if let Some(key) = line.get_key() {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(&key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Some parsers read only a single space between the `export` prefix and the key, and the extra whitespace becomes a part of the key. Whitespace at the start of a line and before the equal sign is reported by the LeadingCharacter and SpaceCharacter checks.",
+            wrong: "export  FOO=BAR",
+            correct: "export FOO=BAR",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn key_whitespace_test() {
+        let mut checker = KeyWhitespaceChecker::default();
+        let line = line_entry("export  FOO=BAR");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "KeyWhitespace",
+            String::from("The FOO key has extra whitespace after the export prefix"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn without_whitespace_test() {
+        let mut checker = KeyWhitespaceChecker::default();
+        let asserts = vec![
+            "export FOO=BAR",
+            "FOO=BAR",
+            " FOO=BAR",
+            "FOO =BAR",
+            "# export  FOO=BAR",
+        ];
+
+        for str in asserts {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+}
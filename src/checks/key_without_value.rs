@@ -1,4 +1,4 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
 
 pub(crate) struct KeyWithoutValueChecker<'a> {
@@ -17,26 +17,48 @@ impl Default for KeyWithoutValueChecker<'_> {
 
 impl KeyWithoutValueChecker<'_> {
     fn message(&self, key: &str) -> String {
-        self.template.replace("{}", &key)
+        self.template.replace("{}", key)
     }
 }
 
-impl Check for KeyWithoutValueChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
-        if !(line.is_empty() || line.raw_string.contains('=')) {
-            Some(Warning::new(
+impl<'a> Check<'a> for KeyWithoutValueChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if !(line.is_empty() || line.parse().has_delimiter) {
+            warnings.push(Warning::new(
                 line.clone(),
                 self.name(),
                 self.message(&line.raw_string),
-            ))
-        } else {
-            None
+            ));
         }
+
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A line with a key but without an equal sign is not an assignment. Loaders either skip it or fail to parse the file. An empty value should be written explicitly with an equal sign.",
+            wrong: "FOO",
+            correct: "FOO=\nFOO=BAR",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -56,7 +78,7 @@ mod tests {
             },
             raw_string: String::from("FOO=BAR"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -71,7 +93,7 @@ mod tests {
             },
             raw_string: String::from(""),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -86,7 +108,7 @@ mod tests {
             },
             raw_string: String::from("FOO="),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -101,11 +123,11 @@ mod tests {
             },
             raw_string: String::from("FOO"),
         };
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "KeyWithoutValue",
             String::from("The FOO key should be with a value or have an equal sign"),
-        ));
+        )];
         assert_eq!(expected, checker.run(&line));
     }
 }
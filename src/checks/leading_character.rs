@@ -1,4 +1,4 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
 
 pub(crate) struct LeadingCharacterChecker<'a> {
@@ -21,22 +21,44 @@ impl LeadingCharacterChecker<'_> {
     }
 }
 
-impl Check for LeadingCharacterChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
-        if line.is_empty()
+impl<'a> Check<'a> for LeadingCharacterChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if !(line.is_empty()
             || line
                 .raw_string
-                .starts_with(|c: char| c.is_alphabetic() || c == '_')
+                .starts_with(|c: char| c.is_alphabetic() || c == '_'))
         {
-            None
-        } else {
-            Some(Warning::new(line.clone(), self.name(), self.message()))
+            warnings.push(Warning::new(line.clone(), self.name(), self.message()));
         }
+
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Environment variable names must start with a letter or an underscore. A key starting with a digit, a symbol or whitespace can't be exported by a shell and is often a sign of a typo.",
+            wrong: " FOO=BAR\n.FOO=BAR\n1FOO=BAR",
+            correct: "FOO=BAR\n_FOO=BAR",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -58,7 +80,7 @@ mod tests {
             },
             raw_string: String::from("FOO=BAR"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -73,7 +95,7 @@ mod tests {
             },
             raw_string: String::from(""),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -88,7 +110,7 @@ mod tests {
             },
             raw_string: String::from("_FOO=BAR"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -104,11 +126,11 @@ mod tests {
             raw_string: String::from(".FOO=BAR"),
         };
         assert_eq!(
-            Some(Warning::new(
+            vec![Warning::new(
                 line.clone(),
                 "LeadingCharacter",
                 MESSAGE.to_string()
-            )),
+            )],
             checker.run(&line)
         );
     }
@@ -126,11 +148,11 @@ mod tests {
             raw_string: String::from("*FOO=BAR"),
         };
         assert_eq!(
-            Some(Warning::new(
+            vec![Warning::new(
                 line.clone(),
                 "LeadingCharacter",
                 MESSAGE.to_string()
-            )),
+            )],
             checker.run(&line)
         );
     }
@@ -148,11 +170,11 @@ mod tests {
             raw_string: String::from("1FOO=BAR"),
         };
         assert_eq!(
-            Some(Warning::new(
+            vec![Warning::new(
                 line.clone(),
                 "LeadingCharacter",
                 MESSAGE.to_string()
-            )),
+            )],
             checker.run(&line)
         );
     }
@@ -169,11 +191,11 @@ mod tests {
             },
             raw_string: String::from(" FOO=BAR"),
         };
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "LeadingCharacter",
             MESSAGE.to_string(),
-        ));
+        )];
         assert_eq!(expected, checker.run(&line));
     }
 
@@ -189,11 +211,11 @@ mod tests {
             },
             raw_string: String::from("  FOO=BAR"),
         };
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "LeadingCharacter",
             MESSAGE.to_string(),
-        ));
+        )];
         assert_eq!(expected, checker.run(&line));
     }
 
@@ -209,11 +231,11 @@ mod tests {
             },
             raw_string: String::from("\tFOO=BAR"),
         };
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "LeadingCharacter",
             MESSAGE.to_string(),
-        ));
+        )];
         assert_eq!(expected, checker.run(&line));
     }
 }
@@ -0,0 +1,127 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct LineContinuationChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl LineContinuationChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for LineContinuationChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "LineContinuation",
+            template:
+                "The {} key has a value ending with a backslash, line continuation is not supported",
+        }
+    }
+}
+
+impl<'a> Check<'a> for LineContinuationChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let (Some(key), Some(value)) = (line.get_key(), line.get_value()) {
+            let value = value.trim_end();
+            // An even number of backslashes is a sequence of escaped backslashes
+            let backslashes = value.chars().rev().take_while(|&c| c == '\\').count();
+
+            if unquote(value).is_none() && backslashes % 2 == 1 {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(&key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A trailing backslash looks like a line continuation, but dotenv files don't support them: the backslash becomes part of the value and the next line is parsed as a separate entry. Multi-line values should be written on one line, e.g. using \\n inside double quotes.",
+            wrong: "FOO=bar\\\nbaz",
+            correct: "FOO=\"bar\\nbaz\"",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const MESSAGE: &str =
+        "The FOO key has a value ending with a backslash, line continuation is not supported";
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn value_without_backslash_test() {
+        let mut checker = LineContinuationChecker::default();
+        let line = line_entry(1, 1, "FOO=bar");
+
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn trailing_backslash_test() {
+        let mut checker = LineContinuationChecker::default();
+        let line = line_entry(1, 1, "FOO=bar\\");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "LineContinuation",
+            MESSAGE.to_string(),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn escaped_backslash_test() {
+        let mut checker = LineContinuationChecker::default();
+        let line = line_entry(1, 1, "FOO=bar\\\\");
+
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn quoted_backslash_test() {
+        let mut checker = LineContinuationChecker::default();
+        let line = line_entry(1, 1, "FOO=\"bar\\\"");
+
+        assert!(checker.run(&line).is_empty());
+    }
+}
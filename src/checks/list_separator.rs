@@ -0,0 +1,185 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::ListSeparatorConfig;
+use serde::{Deserialize, Serialize};
+
+/// A character which separates the items of a list value
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListSeparator {
+    /// `a,b,c`
+    Comma,
+    /// `a;b;c`
+    Semicolon,
+}
+
+impl ListSeparator {
+    fn as_char(self) -> char {
+        match self {
+            ListSeparator::Comma => ',',
+            ListSeparator::Semicolon => ';',
+        }
+    }
+}
+
+pub(crate) struct ListSeparatorChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    separator: Option<ListSeparator>,
+}
+
+impl ListSeparatorChecker<'_> {
+    pub(crate) fn new(config: &ListSeparatorConfig) -> Self {
+        Self {
+            separator: config.separator,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str, separator: ListSeparator) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", &separator.as_char().to_string())
+    }
+
+    /// Returns the separator which should be used in the value, if the value uses another one.
+    /// Without the expected separator only the values with both separators are reported,
+    /// and the more frequent separator of the value is expected
+    fn expected_separator(&self, value: &str) -> Option<ListSeparator> {
+        let commas = value.matches(',').count();
+        let semicolons = value.matches(';').count();
+
+        match self.separator {
+            Some(ListSeparator::Comma) if semicolons > 0 => Some(ListSeparator::Comma),
+            Some(ListSeparator::Semicolon) if commas > 0 => Some(ListSeparator::Semicolon),
+            Some(_) => None,
+            None if commas == 0 || semicolons == 0 => None,
+            None if semicolons > commas => Some(ListSeparator::Semicolon),
+            None => Some(ListSeparator::Comma),
+        }
+    }
+}
+
+impl Default for ListSeparatorChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "ListSeparator",
+            template: "The {1} key has a list value with inconsistent separators, use `{2}` to separate the items",
+            separator: None,
+        }
+    }
+}
+
+impl<'a> Check<'a> for ListSeparatorChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value);
+
+        if let Some(separator) = self.expected_separator(value) {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(key, separator),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "An application splits a list value with a single separator, so a value like `a,b;c` has the `b;c` item instead of two items. Without a configured separator only the values with both commas and semicolons are reported. The expected separator (`comma` or `semicolon`) can be set in the config file to report the other one as well.",
+            wrong: "HOSTS=a,b;c",
+            correct: "HOSTS=a,b,c",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn mixed_separators_test() {
+        let mut checker = ListSeparatorChecker::default();
+        let line = line_entry("HOSTS=\"a,b;c\" # hosts");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "ListSeparator",
+            String::from(
+                "The HOSTS key has a list value with inconsistent separators, use `,` to separate the items",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn consistent_separators_test() {
+        let mut checker = ListSeparatorChecker::default();
+        let asserts = vec!["HOSTS=a,b,c", "HOSTS=a;b;c", "HOST=a", "HOSTS="];
+
+        for str in asserts {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+
+    #[test]
+    fn expected_separator_test() {
+        let config = ListSeparatorConfig {
+            separator: Some(ListSeparator::Semicolon),
+        };
+        let mut checker = ListSeparatorChecker::new(&config);
+        let line = line_entry("HOSTS=a,b");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "ListSeparator",
+            String::from(
+                "The HOSTS key has a list value with inconsistent separators, use `;` to separate the items",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry("HOSTS=a;b")).is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
 
 pub(crate) struct LowercaseKeyChecker<'a> {
@@ -10,29 +10,79 @@ impl Default for LowercaseKeyChecker<'_> {
     fn default() -> Self {
         Self {
             name: "LowercaseKey",
-            template: "The {} key should be in uppercase",
+            template: "The {1} key should be in uppercase{2}",
         }
     }
 }
 
-impl Check for LowercaseKeyChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
-        let key = line.get_key()?;
-        if key.to_uppercase() == key {
-            None
-        } else {
-            Some(Warning::new(line.clone(), self.name(), self.message(&key)))
+impl<'a> Check<'a> for LowercaseKeyChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let Some(key) = line.get_key() {
+            if let Some(index) = key.find(char::is_lowercase) {
+                warnings.push(Warning::new(
+                    line.clone(),
+                    self.name(),
+                    self.message(&key, index),
+                ));
+            }
         }
+
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "By convention, environment variable names are written in uppercase. Mixing cases makes keys harder to find and leads to bugs on platforms where variable names are case-sensitive.",
+            wrong: "FOo_BAR=FOOBAR\nfoo_bar=FOOBAR",
+            correct: "FOO_BAR=FOOBAR",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+
+    // The position was added later, the templates with only the key still work
+    fn optional_placeholders(&self) -> &[&str] {
+        &["{2}"]
+    }
 }
 
 impl LowercaseKeyChecker<'_> {
-    fn message(&self, key: &str) -> String {
-        self.template.replace("{}", key)
+    /// Keys which are only partially lowercase (e.g. `log4j_LEVEL`) get the position
+    /// of the first lowercase character and the part of the key it belongs to in `{2}`
+    fn message(&self, key: &str, index: usize) -> String {
+        let position = if key.contains(char::is_uppercase) {
+            let start = key[..index].rfind('_').map_or(0, |i| i + 1);
+            let end = key[index..].find('_').map_or(key.len(), |i| index + i);
+
+            format!(
+                " (the first lowercase character is at position {}, in the {} part)",
+                key[..index].chars().count() + 1,
+                &key[start..end]
+            )
+        } else {
+            String::new()
+        };
+
+        self.template
+            .replace("{}", key)
+            .replace("{1}", key)
+            .replace("{2}", &position)
     }
 }
 
@@ -53,7 +103,7 @@ mod tests {
             },
             raw_string: String::from("FOO=BAR"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -68,11 +118,11 @@ mod tests {
             },
             raw_string: String::from("foo_bar=FOOBAR"),
         };
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "LowercaseKey",
             String::from("The foo_bar key should be in uppercase"),
-        ));
+        )];
         assert_eq!(expected, checker.run(&line));
     }
 
@@ -88,11 +138,69 @@ mod tests {
             },
             raw_string: String::from("FOo_BAR=FOOBAR"),
         };
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "LowercaseKey",
-            String::from("The FOo_BAR key should be in uppercase"),
-        ));
+            String::from("The FOo_BAR key should be in uppercase (the first lowercase character is at position 3, in the FOo part)"),
+        )];
         assert_eq!(expected, checker.run(&line));
     }
+
+    #[test]
+    fn failing_run_with_lowercase_part_and_digits() {
+        let mut checker = LowercaseKeyChecker::default();
+
+        for (str, message) in &[
+            ("log4j_LEVEL=info", "The log4j_LEVEL key should be in uppercase (the first lowercase character is at position 1, in the log4j part)"),
+            ("DB_2nd_HOST=db", "The DB_2nd_HOST key should be in uppercase (the first lowercase character is at position 5, in the 2nd part)"),
+            ("log4j=info", "The log4j key should be in uppercase"),
+        ] {
+            let line = LineEntry {
+                number: 1,
+                file: FileEntry {
+                    path: PathBuf::from(".env"),
+                    file_name: ".env".to_string(),
+                    total_lines: 1,
+                },
+                raw_string: String::from(*str),
+            };
+            let expected = vec![Warning::new(line.clone(), "LowercaseKey", String::from(*message))];
+            assert_eq!(expected, checker.run(&line));
+        }
+    }
+
+    #[test]
+    fn working_run_with_digits() {
+        let mut checker = LowercaseKeyChecker::default();
+        let line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from("LOG4J_2_LEVEL=info"),
+        };
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn custom_template_test() {
+        let mut checker = LowercaseKeyChecker::default();
+        checker.set_template("Uppercase {1}{2}, see CONF-123");
+        let line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from("FOo=BAR"),
+        };
+
+        assert_eq!(
+            "Uppercase FOo (the first lowercase character is at position 3, in the FOo part), see CONF-123",
+            checker.run(&line)[0].message()
+        );
+    }
 }
@@ -0,0 +1,176 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::MissingPathConfig;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+pub(crate) struct MissingPathChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    suffixes: Vec<String>,
+}
+
+impl MissingPathChecker<'_> {
+    pub(crate) fn new(config: &MissingPathConfig) -> Self {
+        Self {
+            suffixes: config.suffixes.clone(),
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str, path: &str) -> String {
+        self.template.replace("{1}", key).replace("{2}", path)
+    }
+}
+
+impl Default for MissingPathChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "MissingPath",
+            template: "The {1} key points to a missing path: {2}",
+            suffixes: MissingPathConfig::default().suffixes,
+        }
+    }
+}
+
+impl<'a> Check<'a> for MissingPathChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        let (key, value) = match (line.get_key(), line.get_value()) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+        let value = value.trim();
+
+        if !self.suffixes.iter().any(|s| key.ends_with(s.as_str()))
+            || unquote(value).is_some()
+            || !looks_like_path(value)
+        {
+            return warnings;
+        }
+
+        // A relative path can be relative to the env file or to the current directory
+        let file_dir = line.file.path.parent().unwrap_or_else(|| Path::new(""));
+        if !exists(&file_dir.join(value)) && !exists(Path::new(value)) {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(&key, value),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A key ending with _PATH or _FILE (the suffixes can be set in the config file) usually points to a local file, and a path that doesn't exist is often a typo or a leftover. The path is resolved relative to the env file or to the current directory. This check touches the filesystem, so it should be enabled explicitly.",
+            wrong: "CERT_FILE=./cert.pem # the file doesn't exist",
+            correct: "CERT_FILE=./certs/cert.pem",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+/// Skips empty values, substitutions, URLs, home-relative paths and lists of paths
+fn looks_like_path(value: &str) -> bool {
+    // `C:\data` is a path, while `/usr/lib:/lib` is a list
+    let has_list_separator = value.char_indices().any(|(i, c)| c == ':' && i != 1);
+
+    !value.is_empty()
+        && !value.contains('$')
+        && !value.starts_with('~')
+        && !value.contains("://")
+        && !has_list_separator
+}
+
+/// A path that can't be accessed because of permissions is considered existing
+fn exists(path: &Path) -> bool {
+    match fs::metadata(path) {
+        Ok(_) => true,
+        Err(e) => e.kind() == ErrorKind::PermissionDenied,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn missing_path_test() {
+        let mut checker = MissingPathChecker::default();
+        let line = line_entry("CERT_FILE=./missing/cert.pem");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "MissingPath",
+            String::from("The CERT_FILE key points to a missing path: ./missing/cert.pem"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn existing_path_test() {
+        let mut checker = MissingPathChecker::default();
+
+        assert!(checker.run(&line_entry("CARGO_FILE=Cargo.toml")).is_empty());
+        assert!(checker.run(&line_entry("SRC_PATH=src")).is_empty());
+    }
+
+    #[test]
+    fn skipped_values_test() {
+        let mut checker = MissingPathChecker::default();
+
+        for str in &[
+            "CERT=./missing/cert.pem",
+            "CERT_FILE=\"./missing/cert.pem\"",
+            "CERT_FILE=",
+            "CERT_FILE=${CERTS_DIR}/cert.pem",
+            "CERT_FILE=~/missing/cert.pem",
+            "CERT_FILE=https://example.com/cert.pem",
+            "LIBRARY_PATH=/missing/lib:/lib",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "{}", str);
+        }
+    }
+
+    #[test]
+    fn configured_suffixes_test() {
+        let mut checker = MissingPathChecker::new(&MissingPathConfig {
+            suffixes: vec![String::from("_DIR")],
+        });
+
+        assert_eq!(1, checker.run(&line_entry("DATA_DIR=./missing")).len());
+        assert!(checker.run(&line_entry("CERT_FILE=./missing")).is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct MultilineValueChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    // The quote which opened a multi-line value on one of the previous lines
+    open_quote: Option<QuoteStyle>,
+}
+
+impl MultilineValueChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for MultilineValueChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "MultilineValue",
+            template: "The {} key has a multi-line value, it is not supported by all loaders",
+            open_quote: None,
+        }
+    }
+}
+
+impl<'a> Check<'a> for MultilineValueChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        // The continuation lines of a value are neither keys nor comments
+        if let Some(quote) = self.open_quote {
+            if find_closing_quote(&line.raw_string, quote).is_some() {
+                self.open_quote = None;
+            }
+            return warnings;
+        }
+
+        let parsed = line.parse();
+        if let (Some(key), Some(value), Some(quote)) = (parsed.key, parsed.value, parsed.quote) {
+            if find_closing_quote(&value.trim_start()[1..], quote).is_none() {
+                self.open_quote = Some(quote);
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Some loaders support quoted values spanning several lines, others read only the first line and parse the rest as separate entries. Multi-line values can be written on one line with \\n inside double quotes.",
+            wrong: "KEY=\"-----BEGIN KEY-----\n...\n-----END KEY-----\"",
+            correct: "KEY=\"-----BEGIN KEY-----\\n...\\n-----END KEY-----\"",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(lines: &[&str]) -> Vec<Warning> {
+        let mut checker = MultilineValueChecker::default();
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn multiline_value_test() {
+        let lines = [
+            "KEY=\"-----BEGIN KEY-----",
+            "# not a comment",
+            "FOO=\"not a key",
+            "-----END KEY-----\"",
+            "BAR='multi",
+            "line'",
+        ];
+        let expected = vec![
+            Warning::new(
+                line_entry(1, 6, lines[0]),
+                "MultilineValue",
+                String::from(
+                    "The KEY key has a multi-line value, it is not supported by all loaders",
+                ),
+            ),
+            Warning::new(
+                line_entry(5, 6, lines[4]),
+                "MultilineValue",
+                String::from(
+                    "The BAR key has a multi-line value, it is not supported by all loaders",
+                ),
+            ),
+        ];
+
+        assert_eq!(expected, run_checker(&lines));
+    }
+
+    #[test]
+    fn single_line_values_test() {
+        let lines = [
+            "# KEY=\"comment",
+            "FOO=\"bar\"",
+            "BAR='baz' # comment",
+            "BAZ=\"escaped \\\" quote\"",
+            "QUX='\\'",
+            "QUUX=bar\"",
+        ];
+
+        assert!(run_checker(&lines).is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use std::collections::{HashMap, HashSet};
+
+pub(crate) struct NormalizedCollisionChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    // The normalized identifiers and the first keys normalized to them
+    identifiers: HashMap<String, String>,
+    keys: HashSet<String>,
+}
+
+impl NormalizedCollisionChecker<'_> {
+    fn message(&self, key: &str, first_key: &str, identifier: &str) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", first_key)
+            .replace("{3}", identifier)
+    }
+}
+
+/// Normalizes a key the way frameworks map keys to environment variables:
+/// letters are uppercased, dots and dashes are replaced with underscores
+fn normalize(key: &str) -> String {
+    key.chars()
+        .map(|c| match c {
+            '.' | '-' => '_',
+            c => c.to_ascii_uppercase(),
+        })
+        .collect()
+}
+
+impl Default for NormalizedCollisionChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "NormalizedCollision",
+            template:
+                "The {1} key collides with the {2} key, both of them are {3} after normalization",
+            identifiers: HashMap::new(),
+            keys: HashSet::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for NormalizedCollisionChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        let key = match line.get_key() {
+            Some(key) => key,
+            None => return warnings,
+        };
+
+        // Exact duplicates are reported by DuplicatedKey
+        if !self.keys.insert(key.clone()) {
+            return warnings;
+        }
+
+        let identifier = normalize(&key);
+        match self.identifiers.get(&identifier) {
+            Some(first_key) => warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(&key, first_key, &identifier),
+            )),
+            None => {
+                self.identifiers.insert(identifier, key);
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Some frameworks map keys like `app.name`, `app-name` and `APP_NAME` to the same variable: letters are uppercased, dots and dashes are replaced with underscores. Such keys are different for DuplicatedKey, but only one of the values is used. Each key which collides with a previous key is reported.",
+            wrong: "APP_NAME=api\napp.name=web",
+            correct: "APP_NAME=api",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn normalize_test() {
+        let asserts = vec![
+            ("app.name", "APP_NAME"),
+            ("app-name", "APP_NAME"),
+            ("APP_NAME", "APP_NAME"),
+            ("Db.Host-1", "DB_HOST_1"),
+        ];
+
+        for (key, expected) in asserts {
+            assert_eq!(expected, normalize(key), "Key: {}", key);
+        }
+    }
+
+    #[test]
+    fn colliding_keys_test() {
+        let mut checker = NormalizedCollisionChecker::default();
+        let lines = [
+            line_entry(1, 4, "APP_NAME=api"),
+            line_entry(2, 4, "app.name=web"),
+            line_entry(3, 4, "app-name=worker"),
+            line_entry(4, 4, "APP_NAME=api"),
+        ];
+        let warning = |line: &LineEntry, key: &str| {
+            Warning::new(
+                line.clone(),
+                "NormalizedCollision",
+                format!(
+                    "The {} key collides with the APP_NAME key, both of them are APP_NAME after normalization",
+                    key
+                ),
+            )
+        };
+        let expected = vec![
+            warning(&lines[1], "app.name"),
+            warning(&lines[2], "app-name"),
+        ];
+
+        let warnings: Vec<Warning> = lines.iter().flat_map(|line| checker.run(line)).collect();
+        assert_eq!(expected, warnings);
+    }
+
+    #[test]
+    fn different_keys_test() {
+        let mut checker = NormalizedCollisionChecker::default();
+
+        for str in ["APP_NAME=api", "APP_NAMES=web", "APP__NAME=worker"] {
+            assert!(
+                checker.run(&line_entry(1, 3, str)).is_empty(),
+                "Line: {}",
+                str
+            );
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::NumberedKeyConfig;
+
+pub(crate) struct NumberedKeyChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    min_group_size: usize,
+    // The keys without the numeric suffix, the line of the first key and the keys of each group
+    groups: Vec<(String, LineEntry, Vec<String>)>,
+}
+
+impl<'a> NumberedKeyChecker<'a> {
+    pub(crate) fn new(config: &NumberedKeyConfig) -> Self {
+        Self {
+            min_group_size: config.min_group_size,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, keys: &[String]) -> String {
+        self.template.replace("{}", &keys.join(", "))
+    }
+
+    fn add_key(&mut self, line: &LineEntry, key: String) {
+        let prefix = key.trim_end_matches(|c: char| c.is_ascii_digit());
+        if prefix.is_empty() || prefix.len() == key.len() {
+            return;
+        }
+
+        match self.groups.iter_mut().find(|(p, _, _)| p == prefix) {
+            Some((_, _, keys)) => {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+            None => self
+                .groups
+                .push((String::from(prefix), line.clone(), vec![key])),
+        }
+    }
+}
+
+impl Default for NumberedKeyChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "NumberedKey",
+            template: "The {} keys differ only by a number, consider using a single key with a list of values",
+            min_group_size: NumberedKeyConfig::default().min_group_size,
+            groups: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for NumberedKeyChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        if !line.is_comment() {
+            if let Some(key) = line.get_key() {
+                self.add_key(line, key);
+            }
+        }
+
+        if !line.is_last_line() {
+            return Vec::new();
+        }
+
+        self.groups
+            .iter()
+            .filter(|(_, _, keys)| keys.len() >= self.min_group_size)
+            .map(|(_, first_line, keys)| {
+                Warning::new(first_line.clone(), self.name(), self.message(keys))
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Keys which differ only by a numeric suffix usually describe a list of values. Every new item needs a new key and a change in the code reading them, while a single key with comma-separated values does not. Numbered keys are sometimes legitimate, so the warning only suggests a review.",
+            wrong: "HOST1=a.example.com\nHOST2=b.example.com",
+            correct: "HOSTS=a.example.com,b.example.com",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut NumberedKeyChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn numbered_keys_test() {
+        let mut checker = NumberedKeyChecker::default();
+        let lines = [
+            "DATABASE_URL=postgres://localhost",
+            "HOST1=a",
+            "HOST_1=b",
+            "HOST2=c",
+            "HOST10=d",
+            "# HOST3=e",
+        ];
+        let expected = vec![Warning::new(
+            line_entry(2, 6, "HOST1=a"),
+            "NumberedKey",
+            String::from("The HOST1, HOST2, HOST10 keys differ only by a number, consider using a single key with a list of values"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn without_numbered_keys_test() {
+        let mut checker = NumberedKeyChecker::default();
+        let lines = ["HOST=a", "OAUTH2_ID=b", "PORT1=80", "123=c", "PORT1=81"];
+
+        assert!(run_checker(&mut checker, &lines).is_empty());
+    }
+
+    #[test]
+    fn min_group_size_test() {
+        let config = NumberedKeyConfig { min_group_size: 3 };
+        let mut checker = NumberedKeyChecker::new(&config);
+        let lines = ["HOST1=a", "HOST2=b", "PORT1=1", "PORT2=2", "PORT3=3"];
+        let expected = vec![Warning::new(
+            line_entry(3, 5, "PORT1=1"),
+            "NumberedKey",
+            String::from("The PORT1, PORT2, PORT3 keys differ only by a number, consider using a single key with a list of values"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::NumericKeyBooleanValueConfig;
+
+const BOOLEAN_WORDS: &[&str] = &["true", "false", "yes", "no", "on", "off"];
+
+pub(crate) struct NumericKeyBooleanValueChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    suffixes: Vec<String>,
+}
+
+impl NumericKeyBooleanValueChecker<'_> {
+    pub(crate) fn new(config: &NumericKeyBooleanValueConfig) -> Self {
+        Self {
+            suffixes: config.suffixes.clone(),
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for NumericKeyBooleanValueChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "NumericKeyBooleanValue",
+            template: "The {} key should have a number, but it has a boolean value",
+            suffixes: NumericKeyBooleanValueConfig::default().suffixes,
+        }
+    }
+}
+
+impl<'a> Check<'a> for NumericKeyBooleanValueChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+        if !self.suffixes.iter().any(|s| key.ends_with(s.as_str())) {
+            return warnings;
+        }
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value).to_lowercase();
+
+        if BOOLEAN_WORDS.contains(&value.as_str()) {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Keys like `MAX_RETRIES` or `POOL_SIZE` are read as numbers, so a boolean value is a mistake which is noticed only when the application fails to parse it. Usually the value should be a number, or the key should be renamed. The suffixes of the keys can be set in the config file.",
+            wrong: "MAX_RETRIES=true",
+            correct: "MAX_RETRIES=3",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn boolean_value_test() {
+        let mut checker = NumericKeyBooleanValueChecker::default();
+
+        for (str, key) in &[
+            ("MAX_RETRIES=true", "MAX_RETRIES"),
+            ("POOL_SIZE=\"Off\"", "POOL_SIZE"),
+            ("WORKER_COUNT=no # disabled", "WORKER_COUNT"),
+            ("RATE_LIMIT=YES", "RATE_LIMIT"),
+        ] {
+            let line = line_entry(str);
+            let expected = vec![Warning::new(
+                line.clone(),
+                "NumericKeyBooleanValue",
+                format!(
+                    "The {} key should have a number, but it has a boolean value",
+                    key
+                ),
+            )];
+
+            assert_eq!(expected, checker.run(&line), "{}", str);
+        }
+    }
+
+    #[test]
+    fn exempt_test() {
+        let mut checker = NumericKeyBooleanValueChecker::default();
+
+        for str in &[
+            "MAX_RETRIES=3",
+            "POOL_SIZE=",
+            "RATE_LIMIT=${LIMIT}",
+            "WORKER_COUNT=truely",
+            "DEBUG=true",
+            "RETRIES_ENABLED=true",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "{}", str);
+        }
+    }
+
+    #[test]
+    fn configured_test() {
+        let mut checker = NumericKeyBooleanValueChecker::new(&NumericKeyBooleanValueConfig {
+            suffixes: vec![String::from("_TIMEOUT")],
+        });
+        let line = line_entry("HTTP_TIMEOUT=off");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "NumericKeyBooleanValue",
+            String::from("The HTTP_TIMEOUT key should have a number, but it has a boolean value"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry("MAX_RETRIES=true")).is_empty());
+    }
+}
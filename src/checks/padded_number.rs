@@ -0,0 +1,136 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct PaddedNumberChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl PaddedNumberChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+/// Checks if a string is an integer or a decimal number with an optional sign
+fn is_number(value: &str) -> bool {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    let mut parts = digits.splitn(2, '.');
+    let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+
+    parts.next().is_some_and(is_digits) && parts.next().is_none_or(is_digits)
+}
+
+impl Default for PaddedNumberChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "PaddedNumber",
+            template: "The {} key has a number with spaces inside quotes, the spaces are a part of the value",
+        }
+    }
+}
+
+impl<'a> Check<'a> for PaddedNumberChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value, quote) = match (parsed.key, parsed.value, parsed.quote) {
+            (Some(key), Some(value), Some(quote)) => (key, value, quote),
+            _ => return warnings,
+        };
+
+        let value = &value.trim_start()[1..];
+        let content = match find_closing_quote(value, quote) {
+            Some(end) => &value[..end],
+            None => return warnings,
+        };
+
+        if content.trim() != content && is_number(content.trim()) {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Spaces inside quotes are a part of the value, so `\" 8080 \"` is a string which fails to parse as a number in most applications. The fix removes the quotes with the spaces, or only the spaces if a comment follows the value.",
+            wrong: "PORT=\" 8080 \"",
+            correct: "PORT=8080",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn is_number_test() {
+        let asserts = vec![
+            ("8080", true),
+            ("-1", true),
+            ("+0.5", true),
+            ("3.14", true),
+            ("1.", false),
+            (".5", false),
+            ("1.2.3", false),
+            ("inf", false),
+            ("", false),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, is_number(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn padded_number_test() {
+        let mut checker = PaddedNumberChecker::default();
+        let line = line_entry("PORT=\" 8080 \" # default");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "PaddedNumber",
+            String::from(
+                "The PORT key has a number with spaces inside quotes, the spaces are a part of the value",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn without_spaces_test() {
+        let mut checker = PaddedNumberChecker::default();
+
+        for str in ["PORT=\"8080\"", "PORT=8080", "NAME=' app '", "PORT=\"  \""] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::PairListConfig;
+
+pub(crate) struct PairListChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    suffixes: Vec<String>,
+}
+
+impl PairListChecker<'_> {
+    pub(crate) fn new(config: &PairListConfig) -> Self {
+        Self {
+            name: "PairList",
+            template: "The {1} key has the malformed `{2}` pair, the pairs should be `key=value` separated by `;`",
+            suffixes: config.suffixes.clone(),
+        }
+    }
+
+    fn message(&self, key: &str, pair: &str) -> String {
+        self.template.replace("{1}", key).replace("{2}", pair)
+    }
+
+    fn is_pair_list_key(&self, key: &str) -> bool {
+        let key = key.to_uppercase();
+        self.suffixes
+            .iter()
+            .any(|suffix| key.ends_with(suffix.as_str()))
+    }
+}
+
+/// Returns the first `;`-separated pair of a value which is not `key=value`, e.g. a dangling
+/// `key=` or `=value`. Empty pairs (a trailing `;`) are allowed, and the value of a pair can
+/// have `=` in it
+fn find_malformed_pair(value: &str) -> Option<&str> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .find(|pair| {
+            let parsed = parse_line(pair);
+            match (parsed.key, parsed.value) {
+                (Some(key), Some(value)) => key.trim().is_empty() || value.trim().is_empty(),
+                _ => true,
+            }
+        })
+}
+
+impl Default for PairListChecker<'_> {
+    fn default() -> Self {
+        Self::new(&PairListConfig::default())
+    }
+}
+
+impl<'a> Check<'a> for PairListChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+        if !self.is_pair_list_key(key) {
+            return warnings;
+        }
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value);
+
+        // A value without `=` is not a pair list, and a substitution can have the pairs
+        if !value.contains('=') || value.starts_with('$') {
+            return warnings;
+        }
+
+        if let Some(pair) = find_malformed_pair(value) {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(key, pair),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Options of drivers and clients are often passed as `key=value` pairs separated by `;`, e.g. in JDBC connection strings. A pair without a key or a value is usually an unfinished edit, and the driver either ignores it or fails to connect. The suffixes of the keys with pair lists can be set in the config file.",
+            wrong: "DB_OPTIONS=ssl=true;timeout=;=30",
+            correct: "DB_OPTIONS=ssl=true;timeout=30",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn find_malformed_pair_test() {
+        let asserts = vec![
+            ("ssl=true;timeout=30", None),
+            ("ssl=true;timeout=30;", None),
+            ("filter=a=b", None),
+            ("ssl=true;timeout=", Some("timeout=")),
+            ("=30;ssl=true", Some("=30")),
+            ("ssl=true; verbose", Some("verbose")),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, find_malformed_pair(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn malformed_pair_test() {
+        let mut checker = PairListChecker::default();
+        let line = line_entry("DB_OPTIONS=\"ssl=true;timeout=\" # seconds");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "PairList",
+            String::from("The DB_OPTIONS key has the malformed `timeout=` pair, the pairs should be `key=value` separated by `;`"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn well_formed_pairs_test() {
+        let mut checker = PairListChecker::default();
+
+        for str in [
+            "DB_OPTIONS=ssl=true;timeout=30",
+            "DB_OPTIONS=",
+            "DB_OPTIONS=verbose",
+            "DB_OPTIONS=${DEFAULT_OPTIONS}",
+            "DSN=ssl=true;timeout=",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+
+    #[test]
+    fn custom_suffixes_test() {
+        let config = PairListConfig {
+            suffixes: vec![String::from("_DSN")],
+        };
+        let mut checker = PairListChecker::new(&config);
+
+        assert_eq!(1, checker.run(&line_entry("DB_DSN=host=;port=1")).len());
+        assert!(checker.run(&line_entry("DB_OPTIONS=a=")).is_empty());
+    }
+}
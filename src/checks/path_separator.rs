@@ -0,0 +1,225 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::PathSeparatorConfig;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The platform which reads the paths of the file
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Platform {
+    #[default]
+    Unix,
+    Windows,
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Platform::Unix => "Unix",
+            Platform::Windows => "Windows",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Platform {
+    fn separator(self) -> char {
+        match self {
+            Platform::Unix => '/',
+            Platform::Windows => '\\',
+        }
+    }
+
+    fn wrong_separator(self) -> char {
+        match self {
+            Platform::Unix => '\\',
+            Platform::Windows => '/',
+        }
+    }
+}
+
+pub(crate) struct PathSeparatorChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    platform: Platform,
+    suffixes: Vec<String>,
+}
+
+impl PathSeparatorChecker<'_> {
+    pub(crate) fn new(config: &PathSeparatorConfig) -> Self {
+        Self {
+            name: "PathSeparator",
+            template: "The {1} key has a path with `{2}`, use `{3}` as the separator on {4}",
+            platform: config.platform,
+            suffixes: config.suffixes.clone(),
+        }
+    }
+
+    fn message(&self, key: &str) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", &self.platform.wrong_separator().to_string())
+            .replace("{3}", &self.platform.separator().to_string())
+            .replace("{4}", &self.platform.to_string())
+    }
+
+    fn is_path_key(&self, key: &str) -> bool {
+        let key = key.to_uppercase();
+        self.suffixes
+            .iter()
+            .any(|suffix| key.ends_with(suffix.as_str()))
+    }
+}
+
+/// Checks if a path has the separator of the other platform. URLs always use forward slashes,
+/// so they are not reported
+fn has_wrong_separator(value: &str, platform: Platform) -> bool {
+    !value.contains("://") && value.contains(platform.wrong_separator())
+}
+
+impl Default for PathSeparatorChecker<'_> {
+    fn default() -> Self {
+        Self::new(&PathSeparatorConfig::default())
+    }
+}
+
+impl<'a> Check<'a> for PathSeparatorChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+        if !self.is_path_key(key) {
+            return warnings;
+        }
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value);
+
+        if has_wrong_separator(value, self.platform) {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A file written for one platform often ends up on another one, e.g. an example file from a Windows machine in a Linux container. Paths with the separators of the other platform are not found, or are read as a single file name. The target platform and the suffixes of the path keys can be set in the config file.",
+            wrong: "DATA_DIR=data\\uploads",
+            correct: "DATA_DIR=data/uploads",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn has_wrong_separator_test() {
+        let asserts = vec![
+            ("data\\uploads", Platform::Unix, true),
+            ("data/uploads", Platform::Unix, false),
+            ("data/uploads", Platform::Windows, true),
+            ("C:\\data\\uploads", Platform::Windows, false),
+            ("file:///var/data", Platform::Windows, false),
+            ("uploads", Platform::Windows, false),
+        ];
+
+        for (value, platform, expected) in asserts {
+            assert_eq!(
+                expected,
+                has_wrong_separator(value, platform),
+                "Value: {}, platform: {}",
+                value,
+                platform
+            );
+        }
+    }
+
+    #[test]
+    fn unix_platform_test() {
+        let mut checker = PathSeparatorChecker::default();
+        let line = line_entry("DATA_DIR=\"data\\uploads\" # relative to the app");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "PathSeparator",
+            String::from("The DATA_DIR key has a path with `\\`, use `/` as the separator on Unix"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry("DATA_DIR=data/uploads")).is_empty());
+    }
+
+    #[test]
+    fn windows_platform_test() {
+        let config = PathSeparatorConfig {
+            platform: Platform::Windows,
+            ..Default::default()
+        };
+        let mut checker = PathSeparatorChecker::new(&config);
+        let line = line_entry("LOG_FILE=logs/app.log");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "PathSeparator",
+            String::from(
+                "The LOG_FILE key has a path with `/`, use `\\` as the separator on Windows",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker
+            .run(&line_entry("LOG_FILE=logs\\app.log"))
+            .is_empty());
+    }
+
+    #[test]
+    fn not_path_keys_test() {
+        let mut checker = PathSeparatorChecker::default();
+
+        for str in [
+            "PATTERN=a\\d+",
+            "DATA_DIR=",
+            "DATA_URL=https://example.com/a\\b",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+}
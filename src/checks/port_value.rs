@@ -0,0 +1,185 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::PortValueConfig;
+
+pub(crate) struct PortValueChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    suffixes: Vec<String>,
+    skip_quoted: bool,
+}
+
+impl PortValueChecker<'_> {
+    pub(crate) fn new(config: &PortValueConfig) -> Self {
+        Self {
+            suffixes: config.suffixes.clone(),
+            skip_quoted: config.skip_quoted,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str, issue: &str) -> String {
+        self.template.replace("{1}", key).replace("{2}", issue)
+    }
+}
+
+impl Default for PortValueChecker<'_> {
+    fn default() -> Self {
+        let config = PortValueConfig::default();
+        Self {
+            name: "PortValue",
+            template: "The {1} key has {2}, a port should be a number from 1 to 65535",
+            suffixes: config.suffixes,
+            skip_quoted: config.skip_quoted,
+        }
+    }
+}
+
+impl<'a> Check<'a> for PortValueChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+        if !self.suffixes.iter().any(|s| key.ends_with(s.as_str())) {
+            return warnings;
+        }
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = match unquote(value) {
+            Some(_) if self.skip_quoted => return warnings,
+            Some(content) => content,
+            None => value,
+        };
+
+        // Empty values and substitutions are checked only when they are loaded
+        if value.is_empty() || value.contains('$') {
+            return warnings;
+        }
+
+        let issue = if !value.chars().all(|c| c.is_ascii_digit()) {
+            Some("a non-numeric value")
+        } else if !matches!(value.parse::<u16>(), Ok(port) if port > 0) {
+            Some("an out of range value")
+        } else {
+            None
+        };
+
+        if let Some(issue) = issue {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(key, issue),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A value of a port key which is not a number from 1 to 65535 is usually a host or a URL put into the wrong key, and the application fails only when it connects. The suffixes of the keys can be set in the config file, quoted values are skipped by default.",
+            wrong: "DB_PORT=localhost",
+            correct: "DB_HOST=localhost\nDB_PORT=5432",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn invalid_port_test() {
+        let mut checker = PortValueChecker::default();
+
+        for (str, issue) in &[
+            ("DB_PORT=localhost", "a non-numeric value"),
+            ("DB_PORT=-1", "a non-numeric value"),
+            ("DB_PORT=0", "an out of range value"),
+            ("DB_PORT=65536 # db", "an out of range value"),
+        ] {
+            let line = line_entry(str);
+            let expected = vec![Warning::new(
+                line.clone(),
+                "PortValue",
+                format!(
+                    "The DB_PORT key has {}, a port should be a number from 1 to 65535",
+                    issue
+                ),
+            )];
+
+            assert_eq!(expected, checker.run(&line), "{}", str);
+        }
+    }
+
+    #[test]
+    fn valid_port_test() {
+        let mut checker = PortValueChecker::default();
+
+        for str in &[
+            "DB_PORT=5432",
+            "DB_PORT=65535 # db",
+            "DB_PORT=",
+            "DB_PORT=${PORT}",
+            "DB_PORT=\"localhost\"",
+            "DB_HOST=localhost",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "{}", str);
+        }
+    }
+
+    #[test]
+    fn configured_test() {
+        let mut checker = PortValueChecker::new(&PortValueConfig {
+            suffixes: vec![String::from("_PORT_NUMBER")],
+            skip_quoted: false,
+        });
+        let line = line_entry("DB_PORT_NUMBER='localhost'");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "PortValue",
+            String::from(
+                "The DB_PORT_NUMBER key has a non-numeric value, a port should be a number from 1 to 65535",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert!(checker.run(&line_entry("DB_PORT=localhost")).is_empty());
+    }
+}
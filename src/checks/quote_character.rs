@@ -1,4 +1,4 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
 
 pub(crate) struct QuoteCharacterChecker<'a> {
@@ -21,19 +21,42 @@ impl Default for QuoteCharacterChecker<'_> {
     }
 }
 
-impl Check for QuoteCharacterChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
-        let val = line.get_value()?;
-        if val.contains('\"') || val.contains('\'') {
-            Some(Warning::new(line.clone(), self.name(), self.message()))
-        } else {
-            None
+impl<'a> Check<'a> for QuoteCharacterChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let Some(val) = line.get_value() {
+            if val.contains('\"') || val.contains('\'') {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message()));
+            }
         }
+
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Loaders handle quotes differently: some strip them, some keep them as a part of the value. Values without quotes are interpreted the same way by every loader.",
+            wrong: "FOO=\"BAR\"\nFOO='BAR'",
+            correct: "FOO=BAR",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -41,7 +64,7 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
-    fn run_quote_char_tests(asserts: Vec<(LineEntry, Option<Warning>)>) {
+    fn run_quote_char_tests(asserts: Vec<(LineEntry, Vec<Warning>)>) {
         let mut checker = QuoteCharacterChecker::default();
 
         for assert in asserts {
@@ -63,7 +86,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -75,7 +98,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO='BAR'"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 2,
                         file: FileEntry {
@@ -87,7 +110,7 @@ mod tests {
                     },
                     "QuoteCharacter",
                     String::from("The value has quote characters (\', \")"),
-                )),
+                )],
             ),
             (
                 LineEntry {
@@ -99,7 +122,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO='B\"AR'"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 2,
                         file: FileEntry {
@@ -111,7 +134,7 @@ mod tests {
                     },
                     "QuoteCharacter",
                     String::from("The value has quote characters (\', \")"),
-                )),
+                )],
             ),
         ];
 
@@ -131,7 +154,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -143,7 +166,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=\"BAR\""),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 2,
                         file: FileEntry {
@@ -155,7 +178,7 @@ mod tests {
                     },
                     "QuoteCharacter",
                     String::from("The value has quote characters (\', \")"),
-                )),
+                )],
             ),
         ];
 
@@ -174,7 +197,7 @@ mod tests {
                 },
                 raw_string: String::from("FOO=BAR"),
             },
-            None,
+            vec![],
         )];
 
         run_quote_char_tests(asserts);
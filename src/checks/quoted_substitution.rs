@@ -0,0 +1,128 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct QuotedSubstitutionChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl QuotedSubstitutionChecker<'_> {
+    fn message(&self, key: &str, reference: &str) -> String {
+        self.template.replace("{1}", key).replace("{2}", reference)
+    }
+}
+
+impl Default for QuotedSubstitutionChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "QuotedSubstitution",
+            template: "The {1} key has the `${{2}}` reference in single quotes, it is not expanded, use double quotes to expand it",
+        }
+    }
+}
+
+impl<'a> Check<'a> for QuotedSubstitutionChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        // References are expanded in double quotes and in unquoted values
+        let (key, value) = match (parsed.key, parsed.value, parsed.quote) {
+            (Some(key), Some(value), Some(QuoteStyle::Single)) => (key, value),
+            _ => return warnings,
+        };
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value);
+
+        if let Some(reference) = get_substitution_keys(value).first() {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(key, reference),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A `${KEY}` or `$KEY` reference is expanded in double quotes, but single quotes keep the value as is: `FOO='${BAR}'` is the text `${BAR}`, not the value of BAR. The quotes are easy to mix up, and a literal `$` in single quotes is rare enough for the check to be enabled when values are written by hand.",
+            wrong: "URL='${HOST}/api'",
+            correct: "URL=\"${HOST}/api\"",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn single_quotes_test() {
+        let mut checker = QuotedSubstitutionChecker::default();
+        let line = line_entry("URL='${HOST}/api' # the API");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "QuotedSubstitution",
+            String::from(
+                "The URL key has the `${HOST}` reference in single quotes, it is not expanded, use double quotes to expand it",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+        assert_eq!(1, checker.run(&line_entry("PATH='$HOME/bin'")).len());
+    }
+
+    #[test]
+    fn double_quotes_test() {
+        let mut checker = QuotedSubstitutionChecker::default();
+
+        for str in [
+            "URL=\"${HOST}/api\"",
+            "URL=${HOST}/api",
+            "PRICE='$5'",
+            "FOO='bar' # see $BAR",
+            "FOO='bar'",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct RedundantKeyPrefixValueChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl RedundantKeyPrefixValueChecker<'_> {
+    fn message(&self, key: &str, value: &str) -> String {
+        self.template.replace("{1}", key).replace("{2}", value)
+    }
+}
+
+impl Default for RedundantKeyPrefixValueChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "RedundantKeyPrefixValue",
+            template: "The value of the {1} key starts with the key name: {2}",
+        }
+    }
+}
+
+impl<'a> Check<'a> for RedundantKeyPrefixValueChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let (Some(key), Some(value)) = (line.get_key(), line.get_value()) {
+            let (key, value) = (key.trim(), value.trim());
+
+            // The key should be followed by a separator, so FOO=FOOBAR or FOO=FOO are not reported
+            let is_prefixed =
+                value.starts_with(key) && value[key.len()..].starts_with(['_', '-', '.']);
+
+            if unquote(value).is_none() && !key.is_empty() && is_prefixed {
+                warnings.push(Warning::new(
+                    line.clone(),
+                    self.name(),
+                    self.message(key, value),
+                ));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A value that starts with its own key, like TIMEOUT=TIMEOUT_30, is usually a copy/paste mistake: the key was pasted into the value together with the intended value.",
+            wrong: "TIMEOUT=TIMEOUT_30",
+            correct: "TIMEOUT=30",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn value_with_key_prefix_test() {
+        let mut checker = RedundantKeyPrefixValueChecker::default();
+
+        for (str, key, value) in &[
+            ("TIMEOUT=TIMEOUT_30", "TIMEOUT", "TIMEOUT_30"),
+            (
+                "API_URL=API_URL-http://localhost",
+                "API_URL",
+                "API_URL-http://localhost",
+            ),
+        ] {
+            let line = line_entry(1, 1, str);
+            let expected = vec![Warning::new(
+                line.clone(),
+                "RedundantKeyPrefixValue",
+                format!(
+                    "The value of the {} key starts with the key name: {}",
+                    key, value
+                ),
+            )];
+
+            assert_eq!(expected, checker.run(&line));
+        }
+    }
+
+    #[test]
+    fn exempt_values_test() {
+        let mut checker = RedundantKeyPrefixValueChecker::default();
+
+        for str in &[
+            "TIMEOUT=30",
+            "NAME=NAMESPACE",
+            "SECRET=SECRET",
+            "TIMEOUT=\"TIMEOUT_30\"",
+            "TIMEOUT=${TIMEOUT_DEFAULT}",
+            "TIMEOUT=",
+        ] {
+            let line = line_entry(1, 1, str);
+            assert!(
+                checker.run(&line).is_empty(),
+                "Expected no warning for {}",
+                str
+            );
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct RedundantQuotesChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl RedundantQuotesChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for RedundantQuotesChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "RedundantQuotes",
+            template: "The {} key has a value with redundant quotes",
+        }
+    }
+}
+
+impl<'a> Check<'a> for RedundantQuotesChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let (Some(key), Some(value)) = (line.get_key(), line.get_value()) {
+            if let Some(inner) = unquote(&value) {
+                if !(inner.is_empty() || inner.chars().any(requires_quoting)) {
+                    warnings.push(Warning::new(line.clone(), self.name(), self.message(&key)));
+                }
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Quotes are only needed when a value contains whitespace or characters with a special meaning. Quoting simple values adds noise, so some teams prefer to keep quoting minimal.",
+            wrong: "FOO=\"BAR\"",
+            correct: "FOO=BAR\nFOO=\"BAR BAZ\"",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+fn requires_quoting(c: char) -> bool {
+    c.is_whitespace() || ['#', '$', '\\', '`', '\'', '"'].contains(&c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const MESSAGE: &str = "The FOO key has a value with redundant quotes";
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn unquoted_value_test() {
+        let mut checker = RedundantQuotesChecker::default();
+        let line = line_entry(1, 1, "FOO=bar");
+
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn double_quoted_simple_value_test() {
+        let mut checker = RedundantQuotesChecker::default();
+        let line = line_entry(1, 1, "FOO=\"bar\"");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "RedundantQuotes",
+            MESSAGE.to_string(),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn single_quoted_simple_value_test() {
+        let mut checker = RedundantQuotesChecker::default();
+        let line = line_entry(1, 1, "FOO='bar_1.2-3/baz'");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "RedundantQuotes",
+            MESSAGE.to_string(),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn quoted_value_with_space_test() {
+        let mut checker = RedundantQuotesChecker::default();
+        let line = line_entry(1, 1, "FOO=\"bar baz\"");
+
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn quoted_value_with_special_chars_test() {
+        let mut checker = RedundantQuotesChecker::default();
+
+        for value in &["\"#bar\"", "\"$BAR\"", "'b\"ar'", "\"b\\ar\"", "\"`bar`\""] {
+            let line = line_entry(1, 1, &format!("FOO={}", value));
+            assert!(
+                checker.run(&line).is_empty(),
+                "Expected no warning for {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn empty_quotes_test() {
+        let mut checker = RedundantQuotesChecker::default();
+        let line = line_entry(1, 1, "FOO=\"\"");
+
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn mismatched_quotes_test() {
+        let mut checker = RedundantQuotesChecker::default();
+        let line = line_entry(1, 1, "FOO=\"bar'");
+
+        assert!(checker.run(&line).is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::RepeatedCommentConfig;
+
+pub(crate) struct RepeatedCommentChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    max_count: usize,
+    // The trailing comments in the order of their first lines, with the lines they are on
+    comments: Vec<(String, Vec<LineEntry>)>,
+}
+
+impl RepeatedCommentChecker<'_> {
+    pub(crate) fn new(config: &RepeatedCommentConfig) -> Self {
+        Self {
+            name: "RepeatedComment",
+            template: "The `{1}` comment is on {2} lines ({3}), the values may be unfinished",
+            max_count: config.max_count,
+            comments: Vec::new(),
+        }
+    }
+
+    fn message(&self, comment: &str, lines: &[LineEntry]) -> String {
+        let numbers: Vec<String> = lines.iter().map(|l| l.number.to_string()).collect();
+
+        self.template
+            .replace("{1}", comment)
+            .replace("{2}", &lines.len().to_string())
+            .replace("{3}", &numbers.join(", "))
+    }
+}
+
+impl Default for RepeatedCommentChecker<'_> {
+    fn default() -> Self {
+        Self::new(&RepeatedCommentConfig::default())
+    }
+}
+
+impl<'a> Check<'a> for RepeatedCommentChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let parsed = line.parse();
+
+        // Comment lines are headers and notes, only the comments after keys are counted
+        if let (Some(_), Some(comment)) = (parsed.key, parsed.comment) {
+            let comment = comment.trim();
+            match self.comments.iter_mut().find(|(c, _)| c == comment) {
+                Some((_, lines)) => lines.push(line.clone()),
+                None => self
+                    .comments
+                    .push((String::from(comment), vec![line.clone()])),
+            }
+        }
+
+        if !line.is_last_line() {
+            return Vec::new();
+        }
+
+        // Every repeated comment is reported once, on its first line
+        self.comments
+            .iter()
+            .filter(|(_, lines)| lines.len() > self.max_count)
+            .map(|(comment, lines)| {
+                Warning::new(lines[0].clone(), self.name(), self.message(comment, lines))
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "The same trailing comment on many lines, like `# TODO fill this`, is usually left by a generator or a copied template, and the values next to it are often placeholders which were never filled in. The number of lines a comment can be on is set in the config file with `max_count`.",
+            wrong: "API_KEY=xxx # TODO fill this\nAPI_SECRET=xxx # TODO fill this\nAPI_URL=xxx # TODO fill this",
+            correct: "API_KEY=a1b2c3\nAPI_SECRET=s3cr3t\nAPI_URL=https://api.example.com",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entries(strs: &[&str]) -> Vec<LineEntry> {
+        strs.iter()
+            .enumerate()
+            .map(|(index, str)| LineEntry {
+                number: index + 1,
+                file: FileEntry {
+                    path: PathBuf::from(".env"),
+                    file_name: ".env".to_string(),
+                    total_lines: strs.len(),
+                },
+                raw_string: String::from(*str),
+            })
+            .collect()
+    }
+
+    fn run(checker: &mut RepeatedCommentChecker, lines: &[LineEntry]) -> Vec<Warning> {
+        lines.iter().flat_map(|line| checker.run(line)).collect()
+    }
+
+    #[test]
+    fn repeated_comment_test() {
+        let mut checker = RepeatedCommentChecker::default();
+        let lines = line_entries(&[
+            "API_KEY=xxx # TODO fill this",
+            "# TODO fill this",
+            "API_SECRET=\"xxx\" # TODO fill this",
+            "API_URL=xxx  # TODO fill this ",
+            "PORT=8080 # the public port",
+            "",
+        ]);
+        let expected = vec![Warning::new(
+            lines[0].clone(),
+            "RepeatedComment",
+            String::from(
+                "The `# TODO fill this` comment is on 3 lines (1, 3, 4), the values may be unfinished",
+            ),
+        )];
+
+        assert_eq!(expected, run(&mut checker, &lines));
+    }
+
+    #[test]
+    fn max_count_test() {
+        let lines = line_entries(&["A=1 # TODO", "B=2 # TODO", "C=3 # TODO"]);
+
+        let mut checker = RepeatedCommentChecker::new(&RepeatedCommentConfig { max_count: 3 });
+        assert!(run(&mut checker, &lines).is_empty());
+
+        let mut checker = RepeatedCommentChecker::new(&RepeatedCommentConfig { max_count: 1 });
+        assert_eq!(1, run(&mut checker, &lines).len());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::{FilePatterns, RequiredKeysConfig};
+use std::collections::HashSet;
+
+pub(crate) struct RequiredKeysChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    keys: Vec<&'a str>,
+    overrides: Vec<(FilePatterns, Vec<&'a str>)>,
+    defined_keys: HashSet<String>,
+}
+
+impl<'a> RequiredKeysChecker<'a> {
+    pub(crate) fn new(config: &'a RequiredKeysConfig) -> Self {
+        Self {
+            keys: config.keys.iter().map(String::as_str).collect(),
+            overrides: config
+                .overrides
+                .iter()
+                .map(|o| {
+                    let keys = o.keys.iter().map(String::as_str).collect();
+                    (FilePatterns::new(std::iter::once(&o.files)), keys)
+                })
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+
+    /// Returns the required keys of the file in the order of the config, without duplicates
+    fn required_keys(&self, file: &FileEntry) -> Vec<&'a str> {
+        let mut seen = HashSet::new();
+        let overrides = self
+            .overrides
+            .iter()
+            .filter(|(patterns, _)| patterns.matches_path(file))
+            .flat_map(|(_, keys)| keys.iter());
+
+        self.keys
+            .iter()
+            .chain(overrides)
+            .copied()
+            .filter(|key| seen.insert(*key))
+            .collect()
+    }
+}
+
+impl Default for RequiredKeysChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "RequiredKeys",
+            template: "The {} key is required, but it is missing",
+            keys: Vec::new(),
+            overrides: Vec::new(),
+            defined_keys: HashSet::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for RequiredKeysChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        if !line.is_comment() {
+            if let Some(key) = line.get_key() {
+                self.defined_keys.insert(key);
+            }
+        }
+
+        if !line.is_last_line() {
+            return Vec::new();
+        }
+
+        self.required_keys(&line.file)
+            .into_iter()
+            .filter(|key| !self.defined_keys.contains(*key))
+            .map(|key| Warning::new(line.clone(), self.name(), self.message(key)))
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A missing key is noticed only when the application fails at runtime. The required keys are listed in the config file (for all files, or for the files matching glob patterns), the check does nothing without them.",
+            wrong: "# keys: [DATABASE_URL, SECRET_KEY]\nDATABASE_URL=postgres://localhost/db",
+            correct: "# keys: [DATABASE_URL, SECRET_KEY]\nDATABASE_URL=postgres://localhost/db\nSECRET_KEY=secret",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RequiredKeysOverride;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env.production"),
+                file_name: ".env.production".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut RequiredKeysChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    fn config(keys: &[&str], overrides: &[(&str, &[&str])]) -> RequiredKeysConfig {
+        let strings = |keys: &[&str]| keys.iter().map(|k| String::from(*k)).collect();
+        RequiredKeysConfig {
+            keys: strings(keys),
+            overrides: overrides
+                .iter()
+                .map(|(files, keys)| RequiredKeysOverride {
+                    files: String::from(*files),
+                    keys: strings(keys),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn without_config_test() {
+        let mut checker = RequiredKeysChecker::default();
+
+        assert!(run_checker(&mut checker, &["FOO=BAR", ""]).is_empty());
+    }
+
+    #[test]
+    fn missing_keys_test() {
+        let config = config(&["APP_ENV", "FOO", "SECRET_KEY"], &[]);
+        let mut checker = RequiredKeysChecker::new(&config);
+        let lines = ["# SECRET_KEY=secret", "FOO=BAR", ""];
+        let expected = vec![
+            Warning::new(
+                line_entry(3, 3, ""),
+                "RequiredKeys",
+                String::from("The APP_ENV key is required, but it is missing"),
+            ),
+            Warning::new(
+                line_entry(3, 3, ""),
+                "RequiredKeys",
+                String::from("The SECRET_KEY key is required, but it is missing"),
+            ),
+        ];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn overrides_test() {
+        let config = config(
+            &["FOO"],
+            &[
+                ("*.production", &["FOO", "SECRET_KEY"]),
+                (".env.test", &["TEST_DB"]),
+            ],
+        );
+        let mut checker = RequiredKeysChecker::new(&config);
+        let lines = ["FOO=BAR", ""];
+        let expected = vec![Warning::new(
+            line_entry(2, 2, ""),
+            "RequiredKeys",
+            String::from("The SECRET_KEY key is required, but it is missing"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn all_keys_defined_test() {
+        let config = config(&["FOO"], &[("*", &["BAR"])]);
+        let mut checker = RequiredKeysChecker::new(&config);
+
+        assert!(run_checker(&mut checker, &["export BAR=1", "FOO=BAR", ""]).is_empty());
+    }
+}
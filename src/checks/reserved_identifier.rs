@@ -0,0 +1,225 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::ReservedIdentifierConfig;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A language of the code generated from the keys
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    Python,
+    JavaScript,
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Language::Python => "Python",
+            Language::JavaScript => "JavaScript",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Language {
+    fn reserved_words(self) -> &'static [&'static str] {
+        match self {
+            Language::Python => &[
+                "and", "as", "assert", "async", "await", "break", "class", "continue", "def",
+                "del", "elif", "else", "except", "false", "finally", "for", "from", "global", "if",
+                "import", "in", "is", "lambda", "none", "nonlocal", "not", "or", "pass", "raise",
+                "return", "true", "try", "while", "with", "yield",
+            ],
+            Language::JavaScript => &[
+                "await",
+                "break",
+                "case",
+                "catch",
+                "class",
+                "const",
+                "continue",
+                "debugger",
+                "default",
+                "delete",
+                "do",
+                "else",
+                "enum",
+                "export",
+                "extends",
+                "false",
+                "finally",
+                "for",
+                "function",
+                "if",
+                "import",
+                "in",
+                "instanceof",
+                "new",
+                "null",
+                "return",
+                "super",
+                "switch",
+                "this",
+                "throw",
+                "true",
+                "try",
+                "typeof",
+                "var",
+                "void",
+                "while",
+                "with",
+                "yield",
+            ],
+        }
+    }
+}
+
+pub(crate) struct ReservedIdentifierChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    language: Language,
+    // Additional reserved words from the config
+    words: &'a [String],
+}
+
+impl<'a> ReservedIdentifierChecker<'a> {
+    pub(crate) fn new(config: &'a ReservedIdentifierConfig) -> Self {
+        Self {
+            language: config.language,
+            words: &config.words,
+            ..Self::default()
+        }
+    }
+}
+
+impl ReservedIdentifierChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", &self.language.to_string())
+    }
+
+    fn is_reserved(&self, key: &str) -> bool {
+        let key = key.to_lowercase();
+
+        self.language.reserved_words().contains(&key.as_str())
+            || self.words.iter().any(|w| w.to_lowercase() == key)
+    }
+}
+
+impl Default for ReservedIdentifierChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "ReservedIdentifier",
+            template: "The {1} key is a reserved word in {2}",
+            language: Language::default(),
+            words: &[],
+        }
+    }
+}
+
+impl<'a> Check<'a> for ReservedIdentifierChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let Some(key) = line.get_key() {
+            if self.is_reserved(key.trim()) {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(&key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "When settings classes or objects are generated from the keys, a key matching a keyword of the target language (e.g. `class` or `import` in Python) produces invalid code. The language and additional reserved words can be set in the config file.",
+            wrong: "CLASS=admin",
+            correct: "USER_CLASS=admin",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn regular_key_test() {
+        let mut checker = ReservedIdentifierChecker::default();
+        let line = line_entry(1, 1, "CLASS_NAME=admin");
+
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn python_keyword_test() {
+        let mut checker = ReservedIdentifierChecker::default();
+        let line = line_entry(1, 1, "IMPORT=true");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "ReservedIdentifier",
+            String::from("The IMPORT key is a reserved word in Python"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn configured_language_and_words_test() {
+        let config = ReservedIdentifierConfig {
+            language: Language::JavaScript,
+            words: vec![String::from("settings")],
+        };
+        let mut checker = ReservedIdentifierChecker::new(&config);
+
+        assert!(checker.run(&line_entry(1, 3, "DEF=true")).is_empty());
+
+        let line = line_entry(2, 3, "TYPEOF=true");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "ReservedIdentifier",
+            String::from("The TYPEOF key is a reserved word in JavaScript"),
+        )];
+        assert_eq!(expected, checker.run(&line));
+
+        let line = line_entry(3, 3, "SETTINGS=true");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "ReservedIdentifier",
+            String::from("The SETTINGS key is a reserved word in JavaScript"),
+        )];
+        assert_eq!(expected, checker.run(&line));
+    }
+}
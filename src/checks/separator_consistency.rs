@@ -0,0 +1,257 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::SeparatorConsistencyConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const SEPARATOR: char = '_';
+
+/// How words in keys are separated
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeparatorStyle {
+    /// `SERVICE_NAME`
+    Underscore,
+    /// `SERVICENAME`
+    None,
+}
+
+pub(crate) struct SeparatorConsistencyChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    style: Option<SeparatorStyle>,
+    keys: Vec<(String, LineEntry)>,
+}
+
+impl SeparatorConsistencyChecker<'_> {
+    pub(crate) fn new(config: &SeparatorConsistencyConfig) -> Self {
+        Self {
+            style: config.style,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+
+    /// Returns the keys which don't match the expected or the dominant style of the file.
+    ///
+    /// A key without separators is only counted if it can be split into words
+    /// which are used in the separated keys (`SERVICENAME` for `SERVICE_NAME`),
+    /// therefore single-word keys like `PORT` are never reported.
+    fn find_outliers(&self) -> Vec<&(String, LineEntry)> {
+        let separated: Vec<&(String, LineEntry)> = self
+            .keys
+            .iter()
+            .filter(|(key, _)| key.split(SEPARATOR).filter(|w| !w.is_empty()).count() > 1)
+            .collect();
+
+        let words: HashSet<&str> = separated
+            .iter()
+            .flat_map(|(key, _)| key.split(SEPARATOR))
+            .filter(|w| w.len() > 1)
+            .collect();
+
+        let joined: Vec<&(String, LineEntry)> = self
+            .keys
+            .iter()
+            .filter(|(key, _)| !key.contains(SEPARATOR) && is_joined_words(key, &words))
+            .collect();
+
+        let style = match self.style {
+            Some(style) => style,
+            None if separated.len() > joined.len() => SeparatorStyle::Underscore,
+            None if joined.len() > separated.len() => SeparatorStyle::None,
+            None => return Vec::new(),
+        };
+
+        match style {
+            SeparatorStyle::Underscore => joined,
+            SeparatorStyle::None => separated,
+        }
+    }
+}
+
+/// Checks if the key consists of at least two of the given words
+fn is_joined_words(key: &str, words: &HashSet<&str>) -> bool {
+    // The minimal number of words needed to build the key prefix of the given length
+    let mut counts: Vec<Option<usize>> = vec![None; key.len() + 1];
+    counts[0] = Some(0);
+
+    for end in 1..=key.len() {
+        counts[end] = (0..end)
+            .filter(|&start| key.is_char_boundary(start) && key.is_char_boundary(end))
+            .filter(|&start| words.contains(&key[start..end]))
+            .filter_map(|start| counts[start].map(|count| count + 1))
+            .max();
+    }
+
+    counts[key.len()].filter(|&count| count > 1).is_some()
+}
+
+impl Default for SeparatorConsistencyChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "SeparatorConsistency",
+            template: "The {} key has an inconsistent separator style",
+            style: None,
+            keys: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for SeparatorConsistencyChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        if let Some(key) = line.get_key() {
+            self.keys.push((key, line.clone()));
+        }
+
+        if !line.is_last_line() {
+            return Vec::new();
+        }
+
+        self.find_outliers()
+            .into_iter()
+            .map(|(key, line)| Warning::new(line.clone(), self.name(), self.message(key)))
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "When most keys in a file separate words with an underscore, a key like SERVICENAME next to SERVICE_NAME is easy to misspell when it is read in the code. The style is inferred from the file, a key without separators is only reported when it consists of words used in the other keys. The expected style can be set in the config file instead.",
+            wrong: "SERVICE_NAME=api\nSERVICEPORT=80",
+            correct: "SERVICE_NAME=api\nSERVICE_PORT=80",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut SeparatorConsistencyChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn consistent_keys_test() {
+        let mut checker = SeparatorConsistencyChecker::default();
+        let lines = [
+            "PORT=80",
+            "SERVICE_NAME=api",
+            "SERVICE_HOST=localhost",
+            "\n",
+        ];
+
+        assert!(run_checker(&mut checker, &lines).is_empty());
+    }
+
+    #[test]
+    fn joined_key_test() {
+        let mut checker = SeparatorConsistencyChecker::default();
+        let lines = [
+            "SERVICE_NAME=api",
+            "SERVICE_HOST=localhost",
+            "SERVICEHOST=localhost",
+            "\n",
+        ];
+        let expected = vec![Warning::new(
+            line_entry(3, 4, "SERVICEHOST=localhost"),
+            "SeparatorConsistency",
+            String::from("The SERVICEHOST key has an inconsistent separator style"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn separated_key_in_joined_file_test() {
+        let mut checker = SeparatorConsistencyChecker::default();
+        let lines = [
+            "SERVICE_NAME=api",
+            "SERVICENAME=api",
+            "NAMESERVICE=api",
+            "\n",
+        ];
+        let expected = vec![Warning::new(
+            line_entry(1, 4, "SERVICE_NAME=api"),
+            "SeparatorConsistency",
+            String::from("The SERVICE_NAME key has an inconsistent separator style"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn tie_test() {
+        let mut checker = SeparatorConsistencyChecker::default();
+        let lines = ["SERVICE_NAME=api", "SERVICENAME=api", "\n"];
+
+        assert!(run_checker(&mut checker, &lines).is_empty());
+    }
+
+    #[test]
+    fn configured_style_test() {
+        let mut checker = SeparatorConsistencyChecker::new(&SeparatorConsistencyConfig {
+            style: Some(SeparatorStyle::Underscore),
+        });
+        let lines = ["SERVICE_NAME=api", "SERVICENAME=api", "\n"];
+        let expected = vec![Warning::new(
+            line_entry(2, 3, "SERVICENAME=api"),
+            "SeparatorConsistency",
+            String::from("The SERVICENAME key has an inconsistent separator style"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn is_joined_words_test() {
+        let words: HashSet<&str> = ["SERVICE", "NAME", "HOST"].iter().copied().collect();
+
+        assert!(is_joined_words("SERVICENAME", &words));
+        assert!(is_joined_words("SERVICENAMEHOST", &words));
+        assert!(!is_joined_words("SERVICE", &words));
+        assert!(!is_joined_words("SERVICENAMES", &words));
+        assert!(!is_joined_words("PORT", &words));
+    }
+}
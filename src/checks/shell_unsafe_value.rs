@@ -0,0 +1,160 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+/// Characters which are interpreted by a shell in an unquoted value
+const SHELL_CHARACTERS: &[char] = &['`', '&', '|', ';', '(', ')', '<', '>'];
+
+pub(crate) struct ShellUnsafeValueChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl ShellUnsafeValueChecker<'_> {
+    fn message(&self, key: &str, character: char) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", &character.to_string())
+    }
+}
+
+/// Returns the first character of an unquoted value which is interpreted by a shell.
+/// `$` is reported only if it doesn't start a `${KEY}` or `$KEY` substitution, since
+/// substitutions are expanded by a shell the same way as by dotenv loaders
+fn find_shell_character(value: &str) -> Option<char> {
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if SHELL_CHARACTERS.contains(&c) {
+            return Some(c);
+        }
+
+        if c == '$' {
+            let is_substitution = chars
+                .peek()
+                .is_some_and(|&next| next == '{' || next == '_' || next.is_ascii_alphabetic());
+            if !is_substitution {
+                return Some(c);
+            }
+        }
+    }
+
+    None
+}
+
+impl Default for ShellUnsafeValueChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "ShellUnsafeValue",
+            template: "The {1} key has the `{2}` shell character in its unquoted value, quote the value if the file is sourced by a shell",
+        }
+    }
+}
+
+impl<'a> Check<'a> for ShellUnsafeValueChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value, parsed.quote) {
+            (Some(key), Some(value), None) => (key, value),
+            _ => return warnings,
+        };
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => &value[..index],
+            None => value,
+        };
+
+        if let Some(character) = find_shell_character(value.trim()) {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(key, character),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Files which are sourced by a shell (`set -a; source .env`) are run as shell scripts. Characters like backticks, `&`, `|`, `;` or parentheses in unquoted values run commands or break the assignment, while dotenv loaders read them as is. Single quotes keep the value the same for both. The check is optional and can also be enabled with the flag `--shell-safe`.",
+            wrong: "COMMAND=make&&make install",
+            correct: "COMMAND='make&&make install'",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn find_shell_character_test() {
+        let asserts = vec![
+            ("make&&make install", Some('&')),
+            ("a|b", Some('|')),
+            ("`date`", Some('`')),
+            ("$(date)", Some('$')),
+            ("a;b", Some(';')),
+            ("price$5", Some('$')),
+            ("${HOME}/data", None),
+            ("$HOME/data", None),
+            ("https://example.com/?a=1", None),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, find_shell_character(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn unquoted_value_test() {
+        let mut checker = ShellUnsafeValueChecker::default();
+        let line = line_entry("COMMAND=make&&make # build");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "ShellUnsafeValue",
+            String::from("The COMMAND key has the `&` shell character in its unquoted value, quote the value if the file is sourced by a shell"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn quoted_value_test() {
+        let mut checker = ShellUnsafeValueChecker::default();
+        let line = line_entry("COMMAND='make&&make install'");
+
+        assert!(checker.run(&line).is_empty());
+    }
+}
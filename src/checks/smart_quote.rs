@@ -0,0 +1,197 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+/// Curly quotes which open a value pasted from a document, `„` is the opening quote of German
+const OPENING_QUOTES: &[char] = &['“', '‘', '„'];
+/// Curly quotes which close a value, `“` is the closing quote of German
+const CLOSING_QUOTES: &[char] = &['”', '’', '“'];
+/// Curly double quotes, they are reported anywhere in a value. Curly single quotes are only
+/// reported around a value, since `’` is also an apostrophe
+const DOUBLE_QUOTES: &[char] = &['“', '”', '„'];
+
+pub(crate) struct SmartQuoteChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl SmartQuoteChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{1}", key)
+    }
+}
+
+/// Returns the value with straight quotes instead of the curly ones, or `None` if it has no
+/// curly quotes to replace. A value wrapped in curly quotes gets the straight quotes of the
+/// same kind, and the curly double quotes inside it are escaped in double quotes
+pub(crate) fn straighten_quotes(value: &str) -> Option<String> {
+    let chars: Vec<char> = value.chars().collect();
+    let is_wrapped = chars.len() > 1
+        && OPENING_QUOTES.contains(&chars[0])
+        && CLOSING_QUOTES.contains(&chars[chars.len() - 1]);
+
+    let (quote, inner) = if is_wrapped {
+        let quote = if chars[0] == '‘' { "'" } else { "\"" };
+        (quote, chars[1..chars.len() - 1].iter().collect::<String>())
+    } else if !value.contains(DOUBLE_QUOTES) {
+        return None;
+    } else {
+        match unquote(value) {
+            Some(inner) => (&value[..1], String::from(inner)),
+            None => ("", String::from(value)),
+        }
+    };
+
+    let straight = if quote == "\"" { "\\\"" } else { "\"" };
+    Some(format!(
+        "{}{}{}",
+        quote,
+        inner.replace(DOUBLE_QUOTES, straight),
+        quote
+    ))
+}
+
+/// Returns the key and the value of a line as it is checked for curly quotes. A value in curly
+/// quotes is not quoted for the parser, so it ends at the closing curly quote followed by the
+/// end of the line or by a comment, not at the first `#` after a whitespace
+pub(crate) fn smart_quoted_value(line: &LineEntry) -> Option<(String, &str)> {
+    let parsed = line.parse();
+    let (key, value) = (parsed.key?, parsed.value?);
+
+    let trimmed = value.trim();
+    if trimmed.starts_with(OPENING_QUOTES) {
+        let end = trimmed
+            .char_indices()
+            .skip(1)
+            .map(|(index, c)| (index + c.len_utf8(), c))
+            .find(|&(end, c)| {
+                let rest = trimmed[end..].trim_start();
+                CLOSING_QUOTES.contains(&c) && (rest.is_empty() || rest.starts_with('#'))
+            });
+        if let Some((end, _)) = end {
+            return Some((String::from(key), &trimmed[..end]));
+        }
+    }
+
+    let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+        Some(index) => &value[..index],
+        None => value,
+    };
+
+    Some((String::from(key), value.trim()))
+}
+
+impl Default for SmartQuoteChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "SmartQuote",
+            template: "The {1} key has a value with curly quotes, use straight quotes",
+        }
+    }
+}
+
+impl<'a> Check<'a> for SmartQuoteChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let Some((key, value)) = smart_quoted_value(line) {
+            if straighten_quotes(value).is_some() {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(&key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Text editors and documentation sites replace straight quotes with curly ones (`“...”` or `‘...’`). Dotenv loaders only read straight quotes as quotes, so a value copied with curly quotes keeps them as a part of the value.",
+            wrong: "GREETING=“Hello world”",
+            correct: "GREETING=\"Hello world\"",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn straighten_quotes_test() {
+        let asserts = vec![
+            ("“Hello world”", Some("\"Hello world\"")),
+            ("‘Hello world’", Some("'Hello world'")),
+            ("„Hallo“", Some("\"Hallo\"")),
+            ("“say “hi””", Some("\"say \\\"hi\\\"\"")),
+            ("'say “hi”'", Some("'say \"hi\"'")),
+            ("say “hi”", Some("say \"hi\"")),
+            ("it’s", None),
+            ("\"Hello world\"", None),
+            ("‘Bob", None),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(
+                expected.map(String::from),
+                straighten_quotes(value),
+                "Value: {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn smart_quote_test() {
+        let mut checker = SmartQuoteChecker::default();
+        let line = line_entry("GREETING=“Hello # world” # a comment");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "SmartQuote",
+            String::from("The GREETING key has a value with curly quotes, use straight quotes"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn straight_quotes_test() {
+        let mut checker = SmartQuoteChecker::default();
+
+        for str in [
+            "GREETING=\"Hello world\"",
+            "MESSAGE=it’s",
+            "FOO=bar # “a”",
+            "FOO=bar # ‘a’",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+}
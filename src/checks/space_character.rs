@@ -1,15 +1,79 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
+use crate::config::SpaceCharacterConfig;
+use std::collections::HashMap;
 
 pub(crate) struct SpaceCharacterChecker<'a> {
     template: &'a str,
     name: &'a str,
+    aligned: bool,
+    // The lines with keys of the current group (separated by blank lines) in the aligned mode
+    group: Vec<LineEntry>,
 }
 
 impl SpaceCharacterChecker<'_> {
+    pub(crate) fn new(config: &SpaceCharacterConfig) -> Self {
+        Self {
+            aligned: config.aligned,
+            ..Self::default()
+        }
+    }
+
     fn message(&self) -> String {
         String::from(self.template)
     }
+
+    fn misaligned_message(&self, column: usize) -> String {
+        format!(
+            "The equal sign should be at column {} to be aligned with the other keys",
+            column
+        )
+    }
+
+    /// Checks a group of lines: padding before the equal sign is allowed, when the equal signs
+    /// of the padded keys are at the same column. Lines with other columns are reported
+    fn check_group(&self) -> Vec<Warning> {
+        let padded: Vec<usize> = self
+            .group
+            .iter()
+            .filter(|line| line.parse().key.is_some_and(|key| key.ends_with(' ')))
+            .filter_map(LineEntry::equal_sign_column)
+            .collect();
+
+        // The most frequent column of the padded keys, the first one on a tie
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for column in &padded {
+            *counts.entry(*column).or_default() += 1;
+        }
+        let mut target: Option<(usize, usize)> = None;
+        for column in &padded {
+            let count = counts[column];
+            if target.is_none_or(|(_, top)| count > top) {
+                target = Some((*column, count));
+            }
+        }
+
+        let mut warnings = Vec::new();
+        for line in &self.group {
+            let parsed = line.parse();
+            if parsed.value.is_some_and(|value| value.starts_with(' ')) {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message()));
+                continue;
+            }
+
+            if let Some((target, _)) = target {
+                if line.equal_sign_column() != Some(target) {
+                    warnings.push(Warning::new(
+                        line.clone(),
+                        self.name(),
+                        self.misaligned_message(target),
+                    ));
+                }
+            }
+        }
+
+        warnings
+    }
 }
 
 impl Default for SpaceCharacterChecker<'_> {
@@ -17,26 +81,63 @@ impl Default for SpaceCharacterChecker<'_> {
         Self {
             name: "SpaceCharacter",
             template: "The line has spaces around equal sign",
+            aligned: false,
+            group: Vec::new(),
         }
     }
 }
 
-impl Check for SpaceCharacterChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
-        let line_splitted = line.raw_string.split('=').collect::<Vec<&str>>();
+impl<'a> Check<'a> for SpaceCharacterChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        if self.aligned {
+            if !line.is_comment() && parsed.key.is_some() && parsed.value.is_some() {
+                self.group.push(line.clone());
+            }
+
+            if line.is_empty() || line.is_last_line() {
+                warnings = self.check_group();
+                self.group.clear();
+            }
 
-        if let [key, value] = &line_splitted[..] {
+            return warnings;
+        }
+
+        if let (Some(key), Some(value)) = (parsed.key, parsed.value) {
             if key.ends_with(' ') || value.starts_with(' ') {
-                return Some(Warning::new(line.clone(), self.name(), self.message()));
+                warnings.push(Warning::new(line.clone(), self.name(), self.message()));
             }
         }
 
-        None
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Whitespace around the equal sign is not allowed by most loaders and by the shell: it either becomes a part of the key or the value, or breaks the assignment entirely. In the aligned mode (`checks.SpaceCharacter.aligned` in the config file) padding before the equal sign is allowed, when the keys of a group are aligned to the same column.",
+            wrong: "FOO =BAR\nFOO= BAR",
+            correct: "FOO=BAR",
+        }
+    }
+
+    // Groups can end with a comment in the aligned mode
+    fn skip_comments(&self) -> bool {
+        !self.aligned
+    }
 }
 
 #[cfg(test)]
@@ -58,7 +159,7 @@ mod tests {
             },
             raw_string: String::from("DEBUG_HTTP=true"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -73,7 +174,7 @@ mod tests {
             },
             raw_string: String::from(" DEBUG_HTTP=true"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -88,7 +189,7 @@ mod tests {
             },
             raw_string: String::from("DEBUG_HTTP=true "),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -103,7 +204,7 @@ mod tests {
             },
             raw_string: String::from(""),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -118,7 +219,7 @@ mod tests {
             },
             raw_string: String::from("DEBUG_HTTP true"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -133,11 +234,11 @@ mod tests {
             },
             raw_string: String::from("DEBUG-HTTP = true"),
         };
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "SpaceCharacter",
             MESSAGE.to_string(),
-        ));
+        )];
         assert_eq!(expected, checker.run(&line));
     }
 
@@ -153,11 +254,11 @@ mod tests {
             },
             raw_string: String::from("DEBUG-HTTP =true"),
         };
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "SpaceCharacter",
             MESSAGE.to_string(),
-        ));
+        )];
         assert_eq!(expected, checker.run(&line));
     }
 
@@ -173,11 +274,69 @@ mod tests {
             },
             raw_string: String::from("DEBUG-HTTP= true"),
         };
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "SpaceCharacter",
             MESSAGE.to_string(),
-        ));
+        )];
         assert_eq!(expected, checker.run(&line));
     }
+
+    fn run_aligned(lines: &[&str]) -> Vec<(usize, String)> {
+        let mut checker = SpaceCharacterChecker::new(&SpaceCharacterConfig { aligned: true });
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| {
+                checker.run(&LineEntry {
+                    number: i + 1,
+                    file: FileEntry {
+                        path: PathBuf::from(".env"),
+                        file_name: ".env".to_string(),
+                        total_lines,
+                    },
+                    raw_string: String::from(*str),
+                })
+            })
+            .map(|w| (w.line_number(), w.message().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn aligned_run() {
+        let lines = [
+            "# Database",
+            "DB_HOST =localhost",
+            "DB_PORT =5432",
+            "DB_NAME1=app",
+            "",
+            "FOO=BAR",
+            "LONG_KEY=BAZ",
+        ];
+
+        assert!(run_aligned(&lines).is_empty());
+    }
+
+    #[test]
+    fn misaligned_run() {
+        let lines = [
+            "DB_HOST   =localhost",
+            "DB_PORT   =5432",
+            "DB_NAME  =app",
+            "DB_USER=user",
+            "DB_PASS   = pass",
+            "# The end",
+        ];
+        let message = "The equal sign should be at column 11 to be aligned with the other keys";
+
+        assert_eq!(
+            vec![
+                (3, String::from(message)),
+                (4, String::from(message)),
+                (5, String::from(MESSAGE)),
+            ],
+            run_aligned(&lines)
+        );
+    }
 }
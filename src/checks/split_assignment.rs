@@ -0,0 +1,151 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct SplitAssignmentChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl SplitAssignmentChecker<'_> {
+    fn message(&self, key: &str, character: char) -> String {
+        let character = if character.is_whitespace() {
+            String::from("a space")
+        } else {
+            format!("a `{}`", character)
+        };
+
+        self.template.replace("{1}", key).replace("{2}", &character)
+    }
+}
+
+/// Returns the first character of a key which can't be a part of a key and is not a likely
+/// delimiter (`-` and `.` are reported by IncorrectDelimiter)
+fn find_invalid_character(key: &str) -> Option<char> {
+    key.chars()
+        .find(|&c| !(c.is_alphanumeric() || c == '_' || c == '-' || c == '.'))
+}
+
+impl Default for SplitAssignmentChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "SplitAssignment",
+            template: "The {1} key has {2} and its value has `=`, the line is likely a value without quotes",
+        }
+    }
+}
+
+impl<'a> Check<'a> for SplitAssignmentChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value, parsed.quote) {
+            (Some(key), Some(value), None) => (key.trim(), value),
+            _ => return warnings,
+        };
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => &value[..index],
+            None => value,
+        };
+
+        // Only a second `=` makes the split likely, other invalid keys are left to the key checks
+        if !value.contains('=') {
+            return warnings;
+        }
+
+        if let Some(character) = find_invalid_character(key) {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(key, character),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A line is split into the key and the value at the first `=`. When the text before it can't be a key (it has spaces or symbols) and the value has another `=`, the line is usually a value with `=` in it which lost its quotes or its key, e.g. a connection string.",
+            wrong: "DSN host=db user=app",
+            correct: "DSN=\"host=db user=app\"",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn find_invalid_character_test() {
+        let asserts = vec![
+            ("DSN host", Some(' ')),
+            ("A:B", Some(':')),
+            ("FOO-BAR", None),
+            ("FOO.BAR", None),
+            ("FOO_BAR", None),
+        ];
+
+        for (key, expected) in asserts {
+            assert_eq!(expected, find_invalid_character(key), "Key: {}", key);
+        }
+    }
+
+    #[test]
+    fn split_assignment_test() {
+        let mut checker = SplitAssignmentChecker::default();
+        let line = line_entry("DSN host=db user=app");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "SplitAssignment",
+            String::from("The DSN host key has a space and its value has `=`, the line is likely a value without quotes"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn without_second_delimiter_test() {
+        let mut checker = SplitAssignmentChecker::default();
+
+        for str in [
+            "FOO BAR=baz",
+            "FOO=bar=baz",
+            "FOO BAR=baz # a=b",
+            "A:B='c=d'",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct SwallowedCommentChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl SwallowedCommentChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+/// Checks if an unquoted value has a `#` which doesn't start a comment (`FOO=bar#baz`, `FOO=#baz`).
+/// URLs with a fragment and hex colors (`COLOR=#fff`) are not reported
+fn has_swallowed_comment(value: &str) -> bool {
+    if value.contains("://") {
+        return false;
+    }
+
+    value.match_indices('#').any(|(index, _)| {
+        let word = value[index + 1..]
+            .split(char::is_whitespace)
+            .next()
+            .unwrap_or_default();
+        let is_color =
+            [3, 4, 6, 8].contains(&word.len()) && word.chars().all(|c| c.is_ascii_hexdigit());
+
+        !is_color
+    })
+}
+
+impl Default for SwallowedCommentChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "SwallowedComment",
+            template: "The {} key has a `#` in its unquoted value, quote the value or put a space before the comment",
+        }
+    }
+}
+
+impl<'a> Check<'a> for SwallowedCommentChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value, parsed.quote) {
+            (Some(key), Some(value), None) => (key, value),
+            _ => return warnings,
+        };
+
+        // Only the part before a legitimate trailing comment is the value
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => &value[..index],
+            None => value,
+        };
+
+        if has_swallowed_comment(value.trim()) {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "In an unquoted value `#` starts a comment only after a whitespace, so `FOO=bar#baz` has the `bar#baz` value and `FOO=#baz` is not an empty value. Parsers differ here, and such lines are usually a comment which lost its space. A quoted value or a space before the comment makes the intent clear.",
+            wrong: "FOO=bar#set by deploy\nBAR=#not set",
+            correct: "FOO=bar # set by deploy\nBAR=\"\" # not set",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn has_swallowed_comment_test() {
+        let asserts = vec![
+            ("bar#set", true),
+            ("#comment", true),
+            ("# comment", true),
+            ("bar", false),
+            ("#fff", false),
+            ("#00ff00", false),
+            ("https://example.com/#section", false),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, has_swallowed_comment(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn swallowed_comment_test() {
+        let mut checker = SwallowedCommentChecker::default();
+        let line = line_entry("FOO=bar#set by deploy");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "SwallowedComment",
+            String::from("The FOO key has a `#` in its unquoted value, quote the value or put a space before the comment"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn spaced_comment_test() {
+        let mut checker = SwallowedCommentChecker::default();
+        let asserts = vec![
+            "FOO=bar # set by deploy",
+            "FOO=\"bar#baz\"",
+            "FOO='#baz' # quoted",
+        ];
+
+        for str in asserts {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+}
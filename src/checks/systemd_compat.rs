@@ -0,0 +1,171 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct SystemdCompatChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl SystemdCompatChecker<'_> {
+    fn message(&self, key: &str, problem: &str) -> String {
+        self.template.replace("{1}", key).replace("{2}", problem)
+    }
+}
+
+/// Returns the constructs of a line which systemd's `EnvironmentFile=` reads differently than
+/// dotenv loaders and shells
+fn find_incompatibilities(parsed: &ParsedLine, key: &str, value: &str) -> Vec<&'static str> {
+    let mut problems = Vec::new();
+
+    if parsed.is_exported {
+        problems.push("the `export` prefix is not supported");
+    }
+
+    let is_valid_name = key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !is_valid_name {
+        problems.push("the key is not a valid variable name, the line is ignored");
+    }
+
+    // Trailing comments are only recognized outside of quotes
+    if parsed.quote.is_none() && parsed.comment.is_some() {
+        problems.push("the trailing comment is a part of the value");
+    }
+
+    // Nothing is interpreted in single quotes by shells, so such values are the same everywhere
+    if parsed.quote != Some(QuoteStyle::Single) {
+        if value.contains("$(") || value.contains('`') {
+            problems.push("commands in `$(...)` or backticks are not run");
+        } else if !get_substitution_keys(value).is_empty() {
+            problems.push("substitutions are not expanded by all systemd versions");
+        }
+    }
+
+    problems
+}
+
+impl Default for SystemdCompatChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "SystemdCompat",
+            template: "The {1} key isn't compatible with systemd EnvironmentFile: {2}",
+        }
+    }
+}
+
+impl<'a> Check<'a> for SystemdCompatChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return Vec::new(),
+        };
+
+        find_incompatibilities(&parsed, key, value)
+            .into_iter()
+            .map(|problem| Warning::new(line.clone(), self.name(), self.message(key, problem)))
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "systemd reads `EnvironmentFile=` files with its own parser: a line with the `export` prefix or a key which is not a valid variable name is ignored, a `# comment` after an unquoted value is a part of the value, commands in `$(...)` or backticks are not run, and substitutions are not expanded by all versions. The check is optional, it is useful for the files which are passed to systemd units.",
+            wrong: "export PORT=8080 # the public port\nDATA_DIR=${HOME}/data",
+            correct: "# the public port\nPORT=8080\nDATA_DIR=/var/lib/app/data",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn find_incompatibilities_test() {
+        let asserts = vec![
+            ("PORT=8080", vec![]),
+            ("GREETING=\"Hello # world\"", vec![]),
+            ("PRICE='$5'", vec![]),
+            (
+                "export PORT=8080",
+                vec!["the `export` prefix is not supported"],
+            ),
+            (
+                "APP.PORT=8080",
+                vec!["the key is not a valid variable name, the line is ignored"],
+            ),
+            (
+                "PORT=8080 # public",
+                vec!["the trailing comment is a part of the value"],
+            ),
+            (
+                "TODAY=$(date)",
+                vec!["commands in `$(...)` or backticks are not run"],
+            ),
+            (
+                "DATA_DIR=\"${HOME}/data\"",
+                vec!["substitutions are not expanded by all systemd versions"],
+            ),
+        ];
+
+        for (line, expected) in asserts {
+            let parsed = parse_line(line);
+            assert_eq!(
+                expected,
+                find_incompatibilities(&parsed, parsed.key.unwrap(), parsed.value.unwrap()),
+                "Line: {}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn systemd_compat_test() {
+        let mut checker = SystemdCompatChecker::default();
+        let line = line_entry("export PORT=8080 # public");
+        let expected = vec![
+            Warning::new(
+                line.clone(),
+                "SystemdCompat",
+                String::from("The PORT key isn't compatible with systemd EnvironmentFile: the `export` prefix is not supported"),
+            ),
+            Warning::new(
+                line.clone(),
+                "SystemdCompat",
+                String::from("The PORT key isn't compatible with systemd EnvironmentFile: the trailing comment is a part of the value"),
+            ),
+        ];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct TrailingCommaChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl TrailingCommaChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for TrailingCommaChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "TrailingComma",
+            template: "The {} key has a value with a trailing comma",
+        }
+    }
+}
+
+impl<'a> Check<'a> for TrailingCommaChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let (Some(key), Some(content)) = (line.parse().key, unquoted_content(line)) {
+            if content.ends_with(',') {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A trailing comma is usually left after copying a list from JSON or code. Many parsers split such a value into a list with an empty last element. Quoted values are not reported.",
+            wrong: "HOSTS=a,b,c,",
+            correct: "HOSTS=a,b,c",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+/// Returns an unquoted value without a trailing comment and whitespace
+fn unquoted_content(line: &LineEntry) -> Option<&str> {
+    let parsed = line.parse();
+    if parsed.quote.is_some() {
+        return None;
+    }
+
+    let value = parsed.value?;
+    let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+        Some(index) => &value[..index],
+        None => value,
+    };
+
+    Some(value.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn trailing_comma_test() {
+        let mut checker = TrailingCommaChecker::default();
+
+        for str in &["HOSTS=a,b,c,", "HOSTS=a,b,c, # hosts", "HOSTS=a,b,,  "] {
+            let line = line_entry(str);
+            let expected = vec![Warning::new(
+                line.clone(),
+                "TrailingComma",
+                String::from("The HOSTS key has a value with a trailing comma"),
+            )];
+
+            assert_eq!(expected, checker.run(&line), "{}", str);
+        }
+    }
+
+    #[test]
+    fn without_trailing_comma_test() {
+        let mut checker = TrailingCommaChecker::default();
+
+        for str in &[
+            "HOSTS=a,b,c",
+            "HOSTS=\"a,b,c,\"",
+            "HOSTS=','",
+            "HOSTS=a # b,",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "{}", str);
+        }
+    }
+}
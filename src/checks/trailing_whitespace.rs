@@ -1,4 +1,4 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
 
 pub(crate) struct TrailingWhitespaceChecker<'a> {
@@ -21,20 +21,44 @@ impl Default for TrailingWhitespaceChecker<'_> {
     }
 }
 
-impl Check for TrailingWhitespaceChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
-        let raw_string = &line.raw_string;
+impl<'a> Check<'a> for TrailingWhitespaceChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
 
-        if raw_string.ends_with(' ') {
-            return Some(Warning::new(line.clone(), self.name, self.message()));
+        if line.raw_string.ends_with(' ') {
+            warnings.push(Warning::new(line.clone(), self.name, self.message()));
         }
 
-        None
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Trailing whitespace is invisible, but some loaders keep it as a part of the value. This leads to hard-to-debug differences between what you see and what the application gets. Comment lines are checked too, since their trailing whitespace is noise in diffs.",
+            wrong: "# Database \nFOO=BAR ",
+            correct: "# Database\nFOO=BAR",
+        }
+    }
+
+    fn skip_comments(&self) -> bool {
+        false
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -57,7 +81,7 @@ mod tests {
             },
             raw_string: String::from("DEBUG_HTTP=true"),
         };
-        assert_eq!(None, checker.run(&line));
+        assert!(checker.run(&line).is_empty());
     }
 
     #[test]
@@ -73,11 +97,11 @@ mod tests {
             raw_string: String::from("DEBUG_HTTP=true  "),
         };
 
-        let expected = Some(Warning::new(
+        let expected = vec![Warning::new(
             line.clone(),
             "TrailingWhitespace",
             MESSAGE.to_string(),
-        ));
+        )];
         assert_eq!(expected, checker.run(&line));
     }
 }
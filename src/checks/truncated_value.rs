@@ -0,0 +1,127 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct TruncatedValueChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl TruncatedValueChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for TruncatedValueChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "TruncatedValue",
+            template: "The {} key has a value that looks truncated",
+        }
+    }
+}
+
+impl<'a> Check<'a> for TruncatedValueChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let (Some(key), Some(value)) = (line.get_key(), line.get_value()) {
+            let value = value.trim_end();
+            if unquote(value).is_none() && (value.ends_with("...") || value.ends_with('…')) {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(&key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A value ending with an ellipsis is most likely a long secret or certificate that was cut off while copying it from a dashboard or a log. Such a value will not work, and the error usually shows up far from its cause.",
+            wrong: "PUBLIC_KEY=MIIBIjANBgkqh...",
+            correct: "PUBLIC_KEY=MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const MESSAGE: &str = "The FOO key has a value that looks truncated";
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn complete_value_test() {
+        let mut checker = TruncatedValueChecker::default();
+        let line = line_entry(1, 1, "FOO=MIIBIjANBgkqh");
+
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn value_with_dots_test() {
+        let mut checker = TruncatedValueChecker::default();
+        let line = line_entry(1, 1, "FOO=MIIBIjANBgkqh...");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "TruncatedValue",
+            MESSAGE.to_string(),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn value_with_ellipsis_test() {
+        let mut checker = TruncatedValueChecker::default();
+        let line = line_entry(1, 1, "FOO=a1b2c3…");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "TruncatedValue",
+            MESSAGE.to_string(),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn quoted_value_with_dots_test() {
+        let mut checker = TruncatedValueChecker::default();
+        let line = line_entry(1, 1, "FOO=\"Loading...\"");
+
+        assert!(checker.run(&line).is_empty());
+    }
+
+    #[test]
+    fn dots_inside_value_test() {
+        let mut checker = TruncatedValueChecker::default();
+        let line = line_entry(1, 1, "FOO=a...b");
+
+        assert!(checker.run(&line).is_empty());
+    }
+}
@@ -0,0 +1,195 @@
+use crate::checks::{Explanation, MultiFileCheck};
+use crate::common::*;
+use crate::config::UnchangedExampleValueConfig;
+use std::collections::HashMap;
+use std::path::Path;
+
+pub(crate) struct UnchangedExampleValueChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    example: &'a str,
+}
+
+impl<'a> UnchangedExampleValueChecker<'a> {
+    pub(crate) fn new(config: &'a UnchangedExampleValueConfig) -> Self {
+        Self {
+            example: &config.example,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str, example: &Path) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", &example.display().to_string())
+    }
+}
+
+/// Returns the key and the value of the line without quotes and a trailing comment
+fn key_value(line: &LineEntry) -> Option<(&str, &str)> {
+    let parsed = line.parse();
+    let (key, value) = match (parsed.key, parsed.value) {
+        (Some(key), Some(value)) => (key, value),
+        _ => return None,
+    };
+
+    let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+        Some(index) => value[..index].trim(),
+        None => value.trim(),
+    };
+
+    Some((key, unquote(value).unwrap_or(value)))
+}
+
+/// Returns the lines of the example file, from the linted files or from the disk
+fn example_lines(files: &[Vec<LineEntry>], path: &Path) -> Option<Vec<LineEntry>> {
+    if let Some(lines) = files
+        .iter()
+        .find(|lines| lines.first().is_some_and(|line| line.file.path == path))
+    {
+        return Some(lines.clone());
+    }
+
+    let (fe, strs) = FileEntry::from(path.to_path_buf())?;
+    let lines = strs
+        .into_iter()
+        .enumerate()
+        .map(|(index, raw_string)| LineEntry {
+            number: index + 1,
+            file: fe.clone(),
+            raw_string,
+        })
+        .collect();
+
+    Some(lines)
+}
+
+impl Default for UnchangedExampleValueChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "UnchangedExampleValue",
+            template:
+                "The {1} key has the same value as in {2}, the example value should be replaced",
+            example: ".env.example",
+        }
+    }
+}
+
+impl<'a> MultiFileCheck<'a> for UnchangedExampleValueChecker<'a> {
+    fn run(&mut self, files: &[Vec<LineEntry>]) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        for lines in files {
+            let file = match lines.first() {
+                Some(line) if line.file.file_name != self.example => &line.file,
+                _ => continue,
+            };
+
+            // The example is paired with the files in the same directory
+            let example_path = file.path.with_file_name(self.example);
+            let example_lines = match example_lines(files, &example_path) {
+                Some(lines) => lines,
+                None => continue,
+            };
+            let example_values: HashMap<&str, &str> = example_lines
+                .iter()
+                .filter(|line| !line.is_comment())
+                .filter_map(key_value)
+                .collect();
+
+            for line in lines.iter().filter(|line| !line.is_comment()) {
+                if let Some((key, value)) = key_value(line) {
+                    if !value.is_empty() && example_values.get(key) == Some(&value) {
+                        warnings.push(Warning::new(
+                            line.clone(),
+                            self.name(),
+                            self.message(key, &example_path),
+                        ));
+                    }
+                }
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A value copied verbatim from the example file is usually a placeholder which was forgotten, e.g. a secret key left as `replace-me`. The example is looked up in the directory of the checked file, its name can be set in the config file. Empty values are not reported.",
+            wrong: "# .env.example\nSECRET_KEY=replace-me\n\n# .env\nSECRET_KEY=replace-me",
+            correct: "# .env.example\nSECRET_KEY=replace-me\n\n# .env\nSECRET_KEY=4f9b2c7e1d",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(file_name: &str, number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(file_name),
+                file_name: file_name.to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn unchanged_value_test() {
+        let mut checker = UnchangedExampleValueChecker::default();
+        let files = vec![
+            vec![
+                line_entry(".env", 1, 3, "DEBUG=true"),
+                line_entry(".env", 2, 3, "EMPTY="),
+                line_entry(".env", 3, 3, "SECRET_KEY=\"replace-me\" # secret"),
+            ],
+            vec![
+                line_entry(".env.example", 1, 3, "DEBUG=false"),
+                line_entry(".env.example", 2, 3, "EMPTY="),
+                line_entry(".env.example", 3, 3, "SECRET_KEY=replace-me"),
+            ],
+        ];
+        let expected = vec![Warning::new(
+            files[0][2].clone(),
+            "UnchangedExampleValue",
+            String::from("The SECRET_KEY key has the same value as in .env.example, the example value should be replaced"),
+        )];
+
+        assert_eq!(expected, checker.run(&files));
+    }
+
+    #[test]
+    fn custom_example_test() {
+        let config = UnchangedExampleValueConfig {
+            example: String::from(".env.dist"),
+        };
+        let mut checker = UnchangedExampleValueChecker::new(&config);
+        let files = vec![
+            vec![line_entry(".env", 1, 1, "SECRET_KEY=replace-me")],
+            vec![line_entry(".env.example", 1, 1, "SECRET_KEY=replace-me")],
+        ];
+
+        assert!(checker.run(&files).is_empty());
+    }
+}
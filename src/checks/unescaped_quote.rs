@@ -0,0 +1,158 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct UnescapedQuoteChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl UnescapedQuoteChecker<'_> {
+    fn message(&self, key: &str, column: usize) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", &column.to_string())
+    }
+}
+
+/// Returns the index of an unescaped inner quote in a double-quoted value, when the value
+/// is closed by a later quote (e.g. `"he said "hi""`)
+fn find_inner_quote(value: &str) -> Option<usize> {
+    let content = value.strip_prefix('"')?;
+    let index = find_closing_quote(content, QuoteStyle::Double)?;
+
+    // A closed value can only be followed by a comment
+    let rest = &content[index + 1..];
+    if rest.trim().is_empty()
+        || (rest.starts_with(char::is_whitespace) && rest.trim_start().starts_with('#'))
+    {
+        return None;
+    }
+
+    if find_closing_quote(rest, QuoteStyle::Double).is_some() {
+        Some(index + 1)
+    } else {
+        None
+    }
+}
+
+impl Default for UnescapedQuoteChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "UnescapedQuote",
+            template: "The {1} key has an unescaped quote at column {2}, inner quotes should be escaped with a backslash",
+        }
+    }
+}
+
+impl<'a> Check<'a> for UnescapedQuoteChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value, value_start) = match (
+            parsed.key,
+            parsed.value,
+            parsed.value_start(&line.raw_string),
+        ) {
+            (Some(key), Some(value), Some(start)) => (key, value, start),
+            _ => return warnings,
+        };
+        // The whitespace after the delimiter is skipped before the quotes
+        let value_start = value_start + value.len() - value.trim_start().len();
+
+        if let Some(index) = find_inner_quote(value.trim_start()) {
+            let column = line.raw_string[..value_start + index].chars().count() + 1;
+            let mut warning = Warning::new(line.clone(), self.name(), self.message(key, column));
+            warning.spans = vec![Span { column, length: 1 }];
+            warnings.push(warning);
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "An unescaped double quote inside a double-quoted value closes the value early. The outer quotes look balanced, but loaders either cut the value at the inner quote or fail to parse the line. Inner quotes should be escaped with a backslash, or the value can be put into single quotes.",
+            wrong: "GREETING=\"he said \"hi\"\"",
+            correct: "GREETING=\"he said \\\"hi\\\"\"",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn find_inner_quote_test() {
+        let asserts = vec![
+            ("\"he said \"hi\"\"", Some(9)),
+            ("\"a\"b\"", Some(2)),
+            ("\"he said \\\"hi\\\"\"", None),
+            ("\"bar\" # say \"hi\"", None),
+            ("\"bar\"", None),
+            ("\"bar\"baz", None),
+            ("\"multi", None),
+            ("'a'b'", None),
+            ("bar", None),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, find_inner_quote(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn unescaped_quote_test() {
+        let mut checker = UnescapedQuoteChecker::default();
+        let line = line_entry("GREETING= \"he said \"hi\"\"");
+        let mut expected = vec![Warning::new(
+            line.clone(),
+            "UnescapedQuote",
+            String::from("The GREETING key has an unescaped quote at column 20, inner quotes should be escaped with a backslash"),
+        )];
+        expected[0].spans = vec![Span {
+            column: 20,
+            length: 1,
+        }];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn escaped_quote_test() {
+        let mut checker = UnescapedQuoteChecker::default();
+        let line = line_entry("GREETING=\"he said \\\"hi\\\"\"");
+
+        assert!(checker.run(&line).is_empty());
+    }
+}
@@ -1,9 +1,13 @@
-use crate::checks::Check;
+use crate::checks::{Check, Explanation};
 use crate::common::*;
 
 pub(crate) struct UnorderedKeyChecker<'a> {
     template: &'a str,
+    // The keys of the current group (separated by blank lines), sorted
     keys: Vec<String>,
+    // The previous key of the group, the group is unordered since a key goes before it
+    last_key: Option<String>,
+    is_unordered: bool,
     name: &'a str,
 }
 
@@ -20,38 +24,73 @@ impl Default for UnorderedKeyChecker<'_> {
         Self {
             name: "UnorderedKey",
             keys: Vec::new(),
+            last_key: None,
+            is_unordered: false,
             template: "The {1} key should go before the {2} key",
         }
     }
 }
 
-impl Check for UnorderedKeyChecker<'_> {
-    fn run(&mut self, line: &LineEntry) -> Option<Warning> {
+impl<'a> Check<'a> for UnorderedKeyChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
         if line.is_empty() {
             self.keys.clear();
-            return None;
+            self.last_key = None;
+            self.is_unordered = false;
+            return warnings;
         }
 
-        let key = line.get_key()?;
-        self.keys.push(key.clone());
-        let mut sorted_keys = self.keys.clone();
-        sorted_keys.sort();
+        let key = match line.get_key() {
+            Some(key) => key,
+            None => return warnings,
+        };
 
-        if !sorted_keys.eq(&self.keys) {
-            let index = sorted_keys.iter().position(|p| p == &key)?;
+        if self
+            .last_key
+            .as_ref()
+            .is_some_and(|last_key| *last_key > key)
+        {
+            self.is_unordered = true;
+        }
 
-            let another_key = sorted_keys.get(index + 1)?;
+        // The key goes before the equal keys, so the next key is the one it should go before
+        let index = self.keys.partition_point(|k| *k < key);
+        self.keys.insert(index, key.clone());
 
-            let warning = Warning::new(line.clone(), self.name(), self.message(&key, &another_key));
-            return Some(warning);
+        if self.is_unordered {
+            if let Some(another_key) = self.keys.get(index + 1) {
+                let warning =
+                    Warning::new(line.clone(), self.name(), self.message(&key, another_key));
+                warnings.push(warning);
+            }
         }
 
-        None
+        self.last_key = Some(key);
+
+        warnings
     }
 
     fn name(&self) -> &str {
         self.name
     }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Keys ordered alphabetically (within each group separated by blank lines) are easier to find and make duplicates obvious.",
+            wrong: "FOO=BAR\nBAR=FOO",
+            correct: "BAR=FOO\nFOO=BAR",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -59,7 +98,7 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
-    fn run_unordered_tests(asserts: Vec<(LineEntry, Option<Warning>)>) {
+    fn run_unordered_tests(asserts: Vec<(LineEntry, Vec<Warning>)>) {
         let mut checker = UnorderedKeyChecker::default();
 
         for assert in asserts {
@@ -80,7 +119,7 @@ mod tests {
                 },
                 raw_string: String::from("FOO=BAR"),
             },
-            None,
+            vec![],
         )];
 
         run_unordered_tests(asserts);
@@ -99,7 +138,7 @@ mod tests {
                     },
                     raw_string: String::from("BAR=FOO"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -111,7 +150,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
         ];
 
@@ -131,7 +170,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -143,7 +182,7 @@ mod tests {
                     },
                     raw_string: String::from("BAR=FOO"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 2,
                         file: FileEntry {
@@ -155,7 +194,7 @@ mod tests {
                     },
                     "UnorderedKey",
                     String::from("The BAR key should go before the FOO key"),
-                )),
+                )],
             ),
         ];
 
@@ -175,7 +214,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -187,7 +226,7 @@ mod tests {
                     },
                     raw_string: String::from("BAR=FOO"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 2,
                         file: FileEntry {
@@ -199,7 +238,7 @@ mod tests {
                     },
                     "UnorderedKey",
                     String::from("The BAR key should go before the FOO key"),
-                )),
+                )],
             ),
             (
                 LineEntry {
@@ -211,7 +250,7 @@ mod tests {
                     },
                     raw_string: String::from("ABC=BAR"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 3,
                         file: FileEntry {
@@ -223,7 +262,7 @@ mod tests {
                     },
                     "UnorderedKey",
                     String::from("The ABC key should go before the BAR key"),
-                )),
+                )],
             ),
         ];
 
@@ -243,7 +282,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -255,7 +294,7 @@ mod tests {
                     },
                     raw_string: String::from("BAR=FOO"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 2,
                         file: FileEntry {
@@ -267,7 +306,7 @@ mod tests {
                     },
                     "UnorderedKey",
                     String::from("The BAR key should go before the FOO key"),
-                )),
+                )],
             ),
             (
                 LineEntry {
@@ -279,7 +318,7 @@ mod tests {
                     },
                     raw_string: String::from("DDD=BAR"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 3,
                         file: FileEntry {
@@ -291,7 +330,7 @@ mod tests {
                     },
                     "UnorderedKey",
                     String::from("The DDD key should go before the FOO key"),
-                )),
+                )],
             ),
         ];
 
@@ -311,7 +350,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -323,7 +362,7 @@ mod tests {
                     },
                     raw_string: String::from("BAR=FOO"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 2,
                         file: FileEntry {
@@ -335,7 +374,7 @@ mod tests {
                     },
                     "UnorderedKey",
                     String::from("The BAR key should go before the FOO key"),
-                )),
+                )],
             ),
             (
                 LineEntry {
@@ -347,7 +386,7 @@ mod tests {
                     },
                     raw_string: String::from("DDD=BAR"),
                 },
-                Some(Warning::new(
+                vec![Warning::new(
                     LineEntry {
                         number: 3,
                         file: FileEntry {
@@ -359,7 +398,7 @@ mod tests {
                     },
                     "UnorderedKey",
                     String::from("The DDD key should go before the FOO key"),
-                )),
+                )],
             ),
             (
                 LineEntry {
@@ -371,7 +410,7 @@ mod tests {
                     },
                     raw_string: String::from("ZOO=BAR"),
                 },
-                None,
+                vec![],
             ),
         ];
 
@@ -391,7 +430,7 @@ mod tests {
                     },
                     raw_string: String::from("FOO=BAR"),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -403,7 +442,7 @@ mod tests {
                     },
                     raw_string: String::from(""),
                 },
-                None,
+                vec![],
             ),
             (
                 LineEntry {
@@ -415,7 +454,7 @@ mod tests {
                     },
                     raw_string: String::from("BAR=FOO"),
                 },
-                None,
+                vec![],
             ),
         ];
 
@@ -0,0 +1,179 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::UnresolvedTemplateConfig;
+
+pub(crate) struct UnresolvedTemplateChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    // The opening and the closing delimiters of the placeholders
+    delimiters: Vec<(String, String)>,
+}
+
+impl UnresolvedTemplateChecker<'_> {
+    pub(crate) fn new(config: &UnresolvedTemplateConfig) -> Self {
+        Self {
+            name: "UnresolvedTemplate",
+            template:
+                "The {1} key has the {2} template placeholder, it should be replaced with a value",
+            delimiters: config
+                .delimiters
+                .iter()
+                .map(|d| (d.open.clone(), d.close.clone()))
+                .collect(),
+        }
+    }
+
+    fn message(&self, key: &str, placeholder: &str) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", placeholder)
+    }
+}
+
+/// Returns the first placeholder of a value with the given delimiters, e.g. `<your-key-here>`
+/// or `{{ SECRET }}`. Placeholders with only whitespace between the delimiters are not reported
+fn find_placeholder<'v>(value: &'v str, delimiters: &[(String, String)]) -> Option<&'v str> {
+    delimiters
+        .iter()
+        .filter(|(open, close)| !open.is_empty() && !close.is_empty())
+        .find_map(|(open, close)| {
+            value.match_indices(open.as_str()).find_map(|(start, _)| {
+                let rest = &value[start + open.len()..];
+                let end = rest.find(close.as_str())?;
+
+                if rest[..end].trim().is_empty() {
+                    None
+                } else {
+                    Some(&value[start..start + open.len() + end + close.len()])
+                }
+            })
+        })
+}
+
+impl Default for UnresolvedTemplateChecker<'_> {
+    fn default() -> Self {
+        Self::new(&UnresolvedTemplateConfig::default())
+    }
+}
+
+impl<'a> Check<'a> for UnresolvedTemplateChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value);
+
+        if let Some(placeholder) = find_placeholder(value, &self.delimiters) {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(key, placeholder),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Values like `<your-key-here>`, `{{ SECRET }}` or `%%TOKEN%%` are placeholders of a template which wasn't filled in, e.g. by a deploy script. The application gets the placeholder as the value and usually fails much later. The delimiters of the placeholders can be set in the config file.",
+            wrong: "API_KEY=<your-key-here>",
+            correct: "API_KEY=a1b2c3",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TemplateDelimiters;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn find_placeholder_test() {
+        let delimiters = UnresolvedTemplateChecker::default().delimiters;
+        let asserts = vec![
+            ("<your-key-here>", Some("<your-key-here>")),
+            ("{{ SECRET }}", Some("{{ SECRET }}")),
+            ("postgres://{{USER}}@localhost/db", Some("{{USER}}")),
+            ("%%TOKEN%%", Some("%%TOKEN%%")),
+            ("a < b", None),
+            ("<>", None),
+            ("{{ }}", None),
+            ("${HOME}/data", None),
+            ("100%", None),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(
+                expected,
+                find_placeholder(value, &delimiters),
+                "Value: {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn unresolved_template_test() {
+        let config = UnresolvedTemplateConfig::default();
+        let mut checker = UnresolvedTemplateChecker::new(&config);
+        let line = line_entry("API_KEY=\"<your-key-here>\" # from the dashboard");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "UnresolvedTemplate",
+            String::from(
+                "The API_KEY key has the <your-key-here> template placeholder, it should be replaced with a value",
+            ),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn custom_delimiters_test() {
+        let config = UnresolvedTemplateConfig {
+            delimiters: vec![TemplateDelimiters {
+                open: String::from("@@"),
+                close: String::from("@@"),
+            }],
+        };
+        let mut checker = UnresolvedTemplateChecker::new(&config);
+
+        assert_eq!(1, checker.run(&line_entry("TOKEN=@@TOKEN@@")).len());
+        assert!(checker.run(&line_entry("API_KEY=<key>")).is_empty());
+    }
+}
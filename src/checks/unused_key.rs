@@ -0,0 +1,154 @@
+use crate::checks::{Explanation, MultiFileCheck};
+use crate::common::*;
+use std::collections::HashSet;
+
+pub(crate) struct UnusedKeyChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl UnusedKeyChecker<'_> {
+    fn message(&self, key: &str, file: &FileEntry) -> String {
+        self.template
+            .replace("{1}", key)
+            .replace("{2}", &file.to_string())
+    }
+}
+
+impl Default for UnusedKeyChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "UnusedKey",
+            template: "The {1} key is defined in {2}, but it is never referenced",
+        }
+    }
+}
+
+impl<'a> MultiFileCheck<'a> for UnusedKeyChecker<'a> {
+    fn run(&mut self, files: &[Vec<LineEntry>]) -> Vec<Warning> {
+        // Keys referenced via `${KEY}` or `$KEY` in any of the linted files
+        let referenced: HashSet<String> = files
+            .iter()
+            .flatten()
+            .filter_map(|line| line.get_value())
+            .flat_map(|value| {
+                get_substitution_keys(&value)
+                    .into_iter()
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        files
+            .iter()
+            .flatten()
+            .filter_map(|line| line.get_key().map(|key| (line, key)))
+            .filter(|(_, key)| !referenced.contains(key))
+            .map(|(line, key)| {
+                Warning::new(line.clone(), self.name(), self.message(&key, &line.file))
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A key that is never referenced by a substitution in the linted files may be a leftover. This is only a heuristic: the application or other tools can still read the key directly, so the check is meant for files where every key is expected to be consumed through substitutions.",
+            wrong: "HOST=localhost\nPORT=80\nURL=http://${HOST}",
+            correct: "HOST=localhost\nPORT=80\nURL=http://${HOST}:${PORT}",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(file_name: &str, number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(file_name),
+                file_name: file_name.to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn referenced_keys_test() {
+        let mut checker = UnusedKeyChecker::default();
+        let files = vec![vec![
+            line_entry(".env", 1, 3, "HOST=localhost"),
+            line_entry(".env", 2, 3, "PORT=80"),
+            line_entry(".env", 3, 3, "URL=http://${HOST}:$PORT"),
+        ]];
+        let expected = vec![Warning::new(
+            files[0][2].clone(),
+            "UnusedKey",
+            String::from("The URL key is defined in .env, but it is never referenced"),
+        )];
+
+        assert_eq!(expected, checker.run(&files));
+    }
+
+    #[test]
+    fn references_across_files_test() {
+        let mut checker = UnusedKeyChecker::default();
+        let files = vec![
+            vec![line_entry(".env", 1, 1, "HOST=localhost")],
+            vec![
+                line_entry(".env.local", 1, 2, "# ${PORT} in a comment"),
+                line_entry(".env.local", 2, 2, "URL=http://${HOST}"),
+            ],
+            vec![line_entry(".env.test", 1, 1, "HOST=127.0.0.1")],
+        ];
+        let expected = vec![Warning::new(
+            files[1][1].clone(),
+            "UnusedKey",
+            String::from("The URL key is defined in .env.local, but it is never referenced"),
+        )];
+
+        assert_eq!(expected, checker.run(&files));
+    }
+
+    #[test]
+    fn unused_key_in_several_files_test() {
+        let mut checker = UnusedKeyChecker::default();
+        let files = vec![
+            vec![line_entry(".env", 1, 1, "DEBUG=true")],
+            vec![line_entry(".env.prod", 1, 1, "DEBUG=false")],
+        ];
+        let expected = vec![
+            Warning::new(
+                files[0][0].clone(),
+                "UnusedKey",
+                String::from("The DEBUG key is defined in .env, but it is never referenced"),
+            ),
+            Warning::new(
+                files[1][0].clone(),
+                "UnusedKey",
+                String::from("The DEBUG key is defined in .env.prod, but it is never referenced"),
+            ),
+        ];
+
+        assert_eq!(expected, checker.run(&files));
+    }
+}
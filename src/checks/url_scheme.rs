@@ -0,0 +1,194 @@
+use crate::checks::url_trailing_slash::is_url_scheme;
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::UrlSchemeConfig;
+
+pub(crate) struct UrlSchemeChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    suffixes: Vec<String>,
+    require_scheme: bool,
+}
+
+impl UrlSchemeChecker<'_> {
+    pub(crate) fn new(config: &UrlSchemeConfig) -> Self {
+        Self {
+            name: "UrlScheme",
+            template: "The {1} key has a URL {2}",
+            suffixes: config.suffixes.clone(),
+            require_scheme: config.require_scheme,
+        }
+    }
+
+    fn message(&self, key: &str) -> String {
+        let problem = if self.require_scheme {
+            "without a scheme, e.g. https://"
+        } else {
+            "with a scheme, use the address without it"
+        };
+
+        self.template.replace("{1}", key).replace("{2}", problem)
+    }
+
+    fn is_url_key(&self, key: &str) -> bool {
+        let key = key.to_uppercase();
+        self.suffixes
+            .iter()
+            .any(|suffix| key.ends_with(suffix.as_str()))
+    }
+}
+
+/// Checks if a value starts with a URL scheme, like `https://`
+fn has_scheme(value: &str) -> bool {
+    value
+        .split_once("://")
+        .is_some_and(|(scheme, _)| is_url_scheme(scheme))
+}
+
+impl Default for UrlSchemeChecker<'_> {
+    fn default() -> Self {
+        Self::new(&UrlSchemeConfig::default())
+    }
+}
+
+impl<'a> Check<'a> for UrlSchemeChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        let (key, value) = match (parsed.key, parsed.value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return warnings,
+        };
+        if !self.is_url_key(key) {
+            return warnings;
+        }
+
+        let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim(),
+            None => value.trim(),
+        };
+        let value = unquote(value).unwrap_or(value);
+
+        // Empty values are reported by other checks, and a substitution can have the scheme
+        if value.is_empty() || value.starts_with('$') {
+            return warnings;
+        }
+
+        if has_scheme(value) != self.require_scheme {
+            warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "HTTP clients and database drivers need the scheme of a URL to know how to connect: `api.example.com` is read as a relative path or rejected, and the error usually shows up only on the first request. The suffixes of the URL keys can be set in the config file, and `require_scheme: false` reports the values with a scheme instead, for the keys which hold plain addresses.",
+            wrong: "API_URL=api.example.com",
+            correct: "API_URL=https://api.example.com",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn has_scheme_test() {
+        let asserts = vec![
+            ("https://api.example.com", true),
+            ("postgres+psycopg://db/app", true),
+            ("redis://localhost:6379", true),
+            ("api.example.com", false),
+            ("localhost:8080", false),
+            ("/api/v1", false),
+            ("://example.com", false),
+            ("a b://example.com", false),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, has_scheme(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn without_scheme_test() {
+        let mut checker = UrlSchemeChecker::default();
+        let line = line_entry("API_URL=\"api.example.com\" # production");
+        let expected = vec![Warning::new(
+            line.clone(),
+            "UrlScheme",
+            String::from("The API_URL key has a URL without a scheme, e.g. https://"),
+        )];
+
+        assert_eq!(expected, checker.run(&line));
+    }
+
+    #[test]
+    fn full_url_test() {
+        let mut checker = UrlSchemeChecker::default();
+
+        for str in [
+            "API_URL=https://api.example.com",
+            "SEARCH_ENDPOINT='http://localhost:9200/'",
+            "API_URL=",
+            "API_URL=${BASE_URL}/api",
+            "API_HOST=api.example.com",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "Line: {}", str);
+        }
+    }
+
+    #[test]
+    fn scheme_not_required_test() {
+        let config = UrlSchemeConfig {
+            suffixes: vec![String::from("_HOST")],
+            require_scheme: false,
+        };
+        let mut checker = UrlSchemeChecker::new(&config);
+
+        assert_eq!(
+            1,
+            checker
+                .run(&line_entry("API_HOST=https://api.example.com"))
+                .len()
+        );
+        assert!(checker
+            .run(&line_entry("API_HOST=api.example.com"))
+            .is_empty());
+        assert!(checker
+            .run(&line_entry("API_URL=api.example.com"))
+            .is_empty());
+    }
+}
@@ -0,0 +1,226 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+use crate::config::UrlTrailingSlashConfig;
+use serde::{Deserialize, Serialize};
+
+/// Whether URL values should end with a slash
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrailingSlash {
+    /// `http://example.com/`
+    Require,
+    /// `http://example.com`
+    Forbid,
+}
+
+pub(crate) struct UrlTrailingSlashChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+    policy: Option<TrailingSlash>,
+    // The keys with URL values, their lines and whether the URLs end with a slash
+    urls: Vec<(String, LineEntry, bool)>,
+}
+
+impl UrlTrailingSlashChecker<'_> {
+    pub(crate) fn new(config: &UrlTrailingSlashConfig) -> Self {
+        Self {
+            policy: config.policy,
+            ..Self::default()
+        }
+    }
+
+    fn message(&self, key: &str, policy: TrailingSlash) -> String {
+        let expected = match policy {
+            TrailingSlash::Require => "with",
+            TrailingSlash::Forbid => "without",
+        };
+
+        self.template.replace("{1}", key).replace("{2}", expected)
+    }
+}
+
+/// Checks if the text before `://` is a URL scheme, like `https` or `postgres+psycopg`
+pub(crate) fn is_url_scheme(scheme: &str) -> bool {
+    scheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ['+', '-', '.'].contains(&c))
+}
+
+/// Returns the value if it is a URL (`scheme://...`) without a query or a fragment,
+/// since the end of such URLs is not their path
+fn url_value(value: &str) -> Option<&str> {
+    let (scheme, rest) = value.split_once("://")?;
+
+    if is_url_scheme(scheme) && !rest.is_empty() && !rest.contains(['?', '#']) {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+impl Default for UrlTrailingSlashChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "UrlTrailingSlash",
+            template: "The {1} key should have a URL {2} a trailing slash",
+            policy: None,
+            urls: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Check<'a> for UrlTrailingSlashChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let parsed = line.parse();
+        if let (Some(key), Some(value)) = (parsed.key, parsed.value) {
+            let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+                Some(index) => value[..index].trim(),
+                None => value.trim(),
+            };
+            let value = unquote(value).unwrap_or(value);
+
+            if let Some(url) = url_value(value) {
+                self.urls
+                    .push((String::from(key), line.clone(), url.ends_with('/')));
+            }
+        }
+
+        if !line.is_last_line() {
+            return Vec::new();
+        }
+
+        let with_slash = self.urls.iter().filter(|(_, _, slash)| *slash).count();
+        let without_slash = self.urls.len() - with_slash;
+        let policy = match self.policy {
+            Some(policy) => policy,
+            None if with_slash > without_slash => TrailingSlash::Require,
+            None if without_slash > with_slash => TrailingSlash::Forbid,
+            None => return Vec::new(),
+        };
+
+        self.urls
+            .iter()
+            .filter(|(_, _, slash)| *slash != (policy == TrailingSlash::Require))
+            .map(|(key, line, _)| {
+                Warning::new(line.clone(), self.name(), self.message(key, policy))
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "URLs are often concatenated with paths in the code. When some URLs of a file end with a slash and others don't, it is easy to get a double slash or a missing one. The expected style is inferred from the URLs of the file, or it can be set in the config file (`require` or `forbid`).",
+            wrong: "API_URL=https://api.example.com/\nAUTH_URL=https://auth.example.com\nCDN_URL=https://cdn.example.com",
+            correct: "API_URL=https://api.example.com\nAUTH_URL=https://auth.example.com\nCDN_URL=https://cdn.example.com",
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    fn run_checker(checker: &mut UrlTrailingSlashChecker, lines: &[&str]) -> Vec<Warning> {
+        let total_lines = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, str)| checker.run(&line_entry(i + 1, total_lines, str)))
+            .collect()
+    }
+
+    #[test]
+    fn url_value_test() {
+        let asserts = vec![
+            ("https://example.com/", Some("https://example.com/")),
+            ("postgres://localhost/db", Some("postgres://localhost/db")),
+            ("https://example.com/?a=b", None),
+            ("https://example.com/#top", None),
+            ("https://", None),
+            ("://example.com", None),
+            ("example.com/", None),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, url_value(value), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn inconsistent_urls_test() {
+        let mut checker = UrlTrailingSlashChecker::default();
+        let lines = [
+            "API_URL=https://api.example.com/",
+            "AUTH_URL=\"https://auth.example.com\" # auth",
+            "CDN_URL=https://cdn.example.com",
+            "\n",
+        ];
+        let expected = vec![Warning::new(
+            line_entry(1, 4, "API_URL=https://api.example.com/"),
+            "UrlTrailingSlash",
+            String::from("The API_URL key should have a URL without a trailing slash"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+
+    #[test]
+    fn tie_test() {
+        let mut checker = UrlTrailingSlashChecker::default();
+        let lines = [
+            "A_URL=https://a.example.com/",
+            "B_URL=https://b.example.com",
+        ];
+
+        assert!(run_checker(&mut checker, &lines).is_empty());
+    }
+
+    #[test]
+    fn policy_test() {
+        let config = UrlTrailingSlashConfig {
+            policy: Some(TrailingSlash::Require),
+        };
+        let mut checker = UrlTrailingSlashChecker::new(&config);
+        let lines = ["A_URL=https://a.example.com", "HOST=localhost"];
+        let expected = vec![Warning::new(
+            line_entry(1, 2, "A_URL=https://a.example.com"),
+            "UrlTrailingSlash",
+            String::from("The A_URL key should have a URL with a trailing slash"),
+        )];
+
+        assert_eq!(expected, run_checker(&mut checker, &lines));
+    }
+}
@@ -0,0 +1,202 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+/// Where a line has whitespace which is not a part of its key or value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum WhitespaceLocation {
+    /// ` FOO=bar`
+    BeforeKey,
+    /// `FOO =bar`
+    AfterKey,
+    /// `FOO= bar`
+    AfterDelimiter,
+    /// `FOO=bar `
+    AfterValue,
+}
+
+impl WhitespaceLocation {
+    fn as_str(&self) -> &str {
+        match self {
+            WhitespaceLocation::BeforeKey => "before the key",
+            WhitespaceLocation::AfterKey => "after the key",
+            WhitespaceLocation::AfterDelimiter => "after the equal sign",
+            WhitespaceLocation::AfterValue => "after the value",
+        }
+    }
+}
+
+/// Returns the locations of the stray whitespace of a line with a key and a value, in the order
+/// of the line. The whitespace after the equal sign of an empty value is reported after the value
+pub(crate) fn find_whitespace(line: &str) -> Vec<WhitespaceLocation> {
+    let mut locations = Vec::new();
+    let parsed = parse_line(line);
+    let (key, value) = match (parsed.key, parsed.value) {
+        (Some(key), Some(value)) => (key, value),
+        _ => return locations,
+    };
+
+    // The parsed key is trimmed at the start, so the whitespace before it is found in the line
+    if line.starts_with(char::is_whitespace) {
+        locations.push(WhitespaceLocation::BeforeKey);
+    }
+    if key.ends_with(char::is_whitespace) && !key.trim().is_empty() {
+        locations.push(WhitespaceLocation::AfterKey);
+    }
+    if value.starts_with(char::is_whitespace) && !value.trim().is_empty() {
+        locations.push(WhitespaceLocation::AfterDelimiter);
+    }
+    if value.ends_with(char::is_whitespace) {
+        locations.push(WhitespaceLocation::AfterValue);
+    }
+
+    locations
+}
+
+pub(crate) struct WhitespaceReportChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl WhitespaceReportChecker<'_> {
+    fn message(&self, key: &str, locations: &[WhitespaceLocation]) -> String {
+        let names: Vec<&str> = locations.iter().map(|l| l.as_str()).collect();
+        let places = match names.split_last() {
+            Some((last, [])) => last.to_string(),
+            Some((last, rest)) => format!("{} and {}", rest.join(", "), last),
+            None => String::new(),
+        };
+
+        self.template.replace("{1}", key).replace("{2}", &places)
+    }
+}
+
+impl Default for WhitespaceReportChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "WhitespaceReport",
+            template: "The {1} key has whitespace {2}",
+        }
+    }
+}
+
+impl<'a> Check<'a> for WhitespaceReportChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let key = match line.parse().key {
+            Some(key) => key.trim(),
+            None => return warnings,
+        };
+
+        let locations = find_whitespace(&line.raw_string);
+        if !locations.is_empty() {
+            warnings.push(Warning::new(
+                line.clone(),
+                self.name(),
+                self.message(key, &locations),
+            ));
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "Whitespace around the key and the value is read differently by the loaders: some trim it, some keep it in the key or the value, and some fail on the line. The check tells where the whitespace is (before the key, after the key, after the equal sign or after the value), so the line can be fixed without guessing. It reports the same lines as `LeadingCharacter`, `SpaceCharacter` and `TrailingWhitespace` with one message, and can be used instead of them.",
+            wrong: " FOO = bar ",
+            correct: "FOO=bar",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use WhitespaceLocation::*;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn find_whitespace_test() {
+        let asserts = vec![
+            (" FOO=bar", vec![BeforeKey]),
+            ("FOO =bar", vec![AfterKey]),
+            ("FOO=\tbar", vec![AfterDelimiter]),
+            ("FOO=bar ", vec![AfterValue]),
+            (
+                " export FOO = \"bar\" # baz ",
+                vec![BeforeKey, AfterKey, AfterDelimiter, AfterValue],
+            ),
+            ("FOO= ", vec![AfterValue]),
+            ("FOO=\" bar \"", vec![]),
+            ("FOO=bar", vec![]),
+            ("FOO", vec![]),
+        ];
+
+        for (line, expected) in asserts {
+            assert_eq!(expected, find_whitespace(line), "Line: {}", line);
+        }
+    }
+
+    #[test]
+    fn locations_test() {
+        let mut checker = WhitespaceReportChecker::default();
+        let asserts = vec![
+            (" FOO=bar", "The FOO key has whitespace before the key"),
+            ("FOO =bar", "The FOO key has whitespace after the key"),
+            ("FOO= bar", "The FOO key has whitespace after the equal sign"),
+            ("FOO=bar\t", "The FOO key has whitespace after the value"),
+            (
+                " FOO = bar ",
+                "The FOO key has whitespace before the key, after the key, after the equal sign and after the value",
+            ),
+        ];
+
+        for (str, message) in asserts {
+            let line = line_entry(str);
+            let expected = vec![Warning::new(
+                line.clone(),
+                "WhitespaceReport",
+                String::from(message),
+            )];
+            assert_eq!(expected, checker.run(&line), "Line: {}", str);
+        }
+    }
+
+    #[test]
+    fn comment_test() {
+        let mut checker = WhitespaceReportChecker::default();
+
+        assert!(checker.run(&line_entry("# FOO = bar ")).is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct WhitespaceValueChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl WhitespaceValueChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for WhitespaceValueChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "WhitespaceValue",
+            template: "The {} key has a value with only whitespace inside quotes",
+        }
+    }
+}
+
+impl<'a> Check<'a> for WhitespaceValueChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let parsed = line.parse();
+
+        if let (Some(key), Some(value), Some(quote)) = (parsed.key, parsed.value, parsed.quote) {
+            // Empty quotes are an intentional empty value
+            let is_whitespace = quoted_content(value, quote)
+                .is_some_and(|content| !content.is_empty() && content.trim().is_empty());
+
+            if is_whitespace {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "A quoted value with only whitespace is almost always a value that got lost while editing. It is not empty, so checks like `if [ -n \"$FOO\" ]` treat it as set.",
+            wrong: "FOO=\"   \"",
+            correct: "FOO=\"\"\nFOO=bar",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the text between the opening and the closing quotes of the value
+fn quoted_content(value: &str, quote: QuoteStyle) -> Option<&str> {
+    let value = &value.trim_start()[1..];
+    let end = find_closing_quote(value, quote)?;
+
+    Some(&value[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn whitespace_value_test() {
+        let mut checker = WhitespaceValueChecker::default();
+
+        for str in &["FOO=\"   \"", "FOO='\t'", "FOO=\" \" # comment"] {
+            let line = line_entry(str);
+            let expected = vec![Warning::new(
+                line.clone(),
+                "WhitespaceValue",
+                String::from("The FOO key has a value with only whitespace inside quotes"),
+            )];
+
+            assert_eq!(expected, checker.run(&line), "{}", str);
+        }
+    }
+
+    #[test]
+    fn not_whitespace_value_test() {
+        let mut checker = WhitespaceValueChecker::default();
+
+        for str in &[
+            "FOO=\"\"",
+            "FOO=''",
+            "FOO=\" bar \"",
+            "FOO=\"\\t\"",
+            "FOO=   ",
+            "FOO=\"  ",
+        ] {
+            assert!(checker.run(&line_entry(str)).is_empty(), "{}", str);
+        }
+    }
+}
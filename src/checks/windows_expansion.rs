@@ -0,0 +1,146 @@
+use crate::checks::{Check, Explanation};
+use crate::common::*;
+
+pub(crate) struct WindowsExpansionChecker<'a> {
+    name: &'a str,
+    template: &'a str,
+}
+
+impl WindowsExpansionChecker<'_> {
+    fn message(&self, key: &str) -> String {
+        self.template.replace("{}", key)
+    }
+}
+
+impl Default for WindowsExpansionChecker<'_> {
+    fn default() -> Self {
+        Self {
+            name: "WindowsExpansion",
+            template: "The {} key has a value with the %NAME% expansion, it is not expanded by POSIX tools",
+        }
+    }
+}
+
+impl<'a> Check<'a> for WindowsExpansionChecker<'a> {
+    fn run(&mut self, line: &LineEntry) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if let (Some(key), Some(value)) = (line.get_key(), line.get_value()) {
+            if has_windows_expansion(&value) {
+                warnings.push(Warning::new(line.clone(), self.name(), self.message(&key)));
+            }
+        }
+
+        warnings
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn template(&self) -> &str {
+        self.template
+    }
+
+    fn set_template(&mut self, template: &'a str) {
+        self.template = template;
+    }
+
+    fn explanation(&self) -> Explanation<'_> {
+        Explanation {
+            rationale: "The %NAME% syntax is expanded only by the Windows command prompt. Dotenv loaders and POSIX shells keep it as is, so the value ends up with a literal %NAME% in it. Use the ${NAME} substitution instead.",
+            wrong: "DATA_DIR=%USERPROFILE%\\data",
+            correct: "DATA_DIR=${HOME}/data",
+        }
+    }
+
+    fn skip_simple_assignments(&self) -> bool {
+        true
+    }
+
+    fn is_optional(&self) -> bool {
+        true
+    }
+}
+
+/// Checks if the value contains `%NAME%`, where NAME is a valid variable name
+fn has_windows_expansion(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('%').collect();
+
+    // The first and the last parts are not enclosed in `%`
+    parts.len() > 2
+        && parts[1..parts.len() - 1]
+            .iter()
+            .any(|p| is_variable_name(p))
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn windows_expansion_test() {
+        let mut checker = WindowsExpansionChecker::default();
+
+        for str in &[
+            "PATH_VAR=%USERPROFILE%\\data",
+            "FOO=%A%-%B%",
+            "FOO=\"%APPDATA%\"",
+            "FOO=100%%HOME%",
+        ] {
+            let line = line_entry(1, 1, str);
+            let key = line.get_key().unwrap();
+            let expected = vec![Warning::new(
+                line.clone(),
+                "WindowsExpansion",
+                format!(
+                    "The {} key has a value with the %NAME% expansion, it is not expanded by POSIX tools",
+                    key
+                ),
+            )];
+
+            assert_eq!(expected, checker.run(&line), "{}", str);
+        }
+    }
+
+    #[test]
+    fn without_windows_expansion_test() {
+        let mut checker = WindowsExpansionChecker::default();
+
+        for str in &[
+            "FOO=${HOME}/data",
+            "FOO=100%",
+            "FOO=%1%",
+            "FOO=50% off 20%",
+            "FOO=%%",
+            "FOO=%HOME",
+        ] {
+            let line = line_entry(1, 1, str);
+            assert!(checker.run(&line).is_empty(), "{}", str);
+        }
+    }
+}
@@ -1,18 +1,96 @@
+mod comment;
+mod encoding;
 mod file_entry;
+mod file_result;
 mod line_entry;
+mod parsed_line;
+mod stats;
 mod warning;
 
+pub use comment::{Comment, Ignores};
+pub use encoding::Encoding;
 pub use file_entry::FileEntry;
+pub use file_result::{FileResult, LintError};
 pub use line_entry::LineEntry;
-pub use warning::Warning;
+pub(crate) use parsed_line::{find_closing_quote, EXPORT_PREFIX};
+pub use parsed_line::{parse_line, ParsedLine, QuoteStyle};
+pub use stats::Stats;
+pub use warning::{Severity, Span, Warning};
 
 pub const LF: &str = "\n";
+pub const CRLF: &str = "\r\n";
 
-pub fn remove_invalid_leading_chars(string: &str) -> String {
-    string
-        .chars()
-        .skip_while(|&c| !(c.is_alphabetic() || c == '_'))
-        .collect()
+pub fn remove_invalid_leading_chars(string: &str) -> &str {
+    string.trim_start_matches(|c: char| !(c.is_alphabetic() || c == '_'))
+}
+
+/// Checks if a line is a plain `KEY=value` assignment: an uppercase key and an unquoted value
+/// without spaces, quotes, substitutions, comments or escapes. Most lines of a file are like
+/// this, and the checks which can only report other lines skip them
+pub fn is_simple_assignment(line: &str) -> bool {
+    let parsed = parse_line(line);
+    let (key, value) = match (parsed.key, parsed.value) {
+        (Some(key), Some(value)) => (key, value),
+        _ => return false,
+    };
+
+    // The parsed key is trimmed, so the lines with whitespace or `export ` before it are not simple
+    line.starts_with(key)
+        && key.starts_with(|c: char| c.is_ascii_uppercase())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_.,/:@-".contains(c))
+}
+
+/// Returns the content of a value surrounded by a matching pair of quotes
+pub fn unquote(value: &str) -> Option<&str> {
+    let quote = value.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+
+    if value.len() < 2 || !value.ends_with(quote) {
+        return None;
+    }
+
+    Some(&value[1..value.len() - 1])
+}
+
+/// Returns the keys referenced by a value via `${KEY}` or `$KEY` substitutions
+pub fn get_substitution_keys(value: &str) -> Vec<&str> {
+    let mut keys = Vec::new();
+    let mut rest = value;
+
+    while let Some(index) = rest.find('$') {
+        rest = &rest[index + 1..];
+
+        let braced = rest.starts_with('{');
+        if braced {
+            rest = &rest[1..];
+        }
+
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if end > 0 && !rest.starts_with(|c: char| c.is_ascii_digit()) {
+            keys.push(&rest[..end]);
+        }
+        rest = &rest[end..];
+    }
+
+    keys
+}
+
+/// Checks if a character is invisible: zero-width and non-breaking spaces,
+/// bidirectional text marks and control characters (except tabs and line breaks)
+pub fn is_invisible_char(c: char) -> bool {
+    match c {
+        '\t' | '\n' | '\r' => false,
+        '\u{00A0}' | '\u{00AD}' | '\u{2007}' | '\u{202F}' => true,
+        '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' => true,
+        '\u{2060}'..='\u{2064}' | '\u{2066}'..='\u{2069}' | '\u{FEFF}' => true,
+        _ => c.is_control(),
+    }
 }
 
 #[test]
@@ -23,3 +101,57 @@ fn remove_invalid_leading_chars_test() {
     let string = String::from("***FOO-BAR");
     assert_eq!("FOO-BAR", remove_invalid_leading_chars(&string));
 }
+
+#[test]
+fn is_simple_assignment_test() {
+    let asserts = vec![
+        ("FOO=bar", true),
+        ("DATABASE_URL=postgres://user@db:5432/app", true),
+        ("FOO_2=", true),
+        ("foo=bar", false),
+        ("FOO-BAR=baz", false),
+        ("FOO=bar baz", false),
+        ("FOO=\"bar\"", false),
+        ("FOO=${BAR}", false),
+        ("FOO=bar#baz", false),
+        ("FOO==bar", false),
+        ("FOO=bar ", false),
+        ("FOO=bar\\", false),
+        ("export FOO=bar", false),
+        ("FOO", false),
+        ("# FOO=bar", false),
+    ];
+
+    for (line, expected) in asserts {
+        assert_eq!(expected, is_simple_assignment(line), "Line: {}", line);
+    }
+}
+
+#[test]
+fn unquote_test() {
+    assert_eq!(Some("bar"), unquote("\"bar\""));
+    assert_eq!(Some("bar"), unquote("'bar'"));
+    assert_eq!(Some(""), unquote("''"));
+    assert_eq!(None, unquote("\""));
+    assert_eq!(None, unquote("bar"));
+    assert_eq!(None, unquote("'bar\""));
+}
+
+#[test]
+fn is_invisible_char_test() {
+    for c in &['\u{200B}', '\u{00A0}', '\u{FEFF}', '\u{202E}', '\u{0007}'] {
+        assert!(is_invisible_char(*c), "Expected {:?} to be invisible", c);
+    }
+    for c in &['a', ' ', '\t', '\n', 'é'] {
+        assert!(!is_invisible_char(*c), "Expected {:?} to be visible", c);
+    }
+}
+
+#[test]
+fn get_substitution_keys_test() {
+    assert_eq!(vec!["FOO"], get_substitution_keys("${FOO}"));
+    assert_eq!(vec!["FOO", "BAR"], get_substitution_keys("$FOO:${BAR}/baz"));
+    assert_eq!(vec!["FOO"], get_substitution_keys("${FOO:-default}"));
+    assert!(get_substitution_keys("$1 costs $").is_empty());
+    assert!(get_substitution_keys("BAR").is_empty());
+}
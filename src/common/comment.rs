@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+
+const PREFIX: &str = "dotenv-linter:";
+
+/// A control comment which turns checks off or on for the following lines:
+/// `# dotenv-linter:off UnorderedKey, LowercaseKey`. Without check names it applies to all checks
+#[derive(Debug, PartialEq)]
+pub struct Comment<'a> {
+    pub is_disabled: bool,
+    pub checks: Vec<&'a str>,
+}
+
+impl<'a> Comment<'a> {
+    pub fn parse(line: &'a str) -> Option<Self> {
+        let rest = line.trim().strip_prefix('#')?.trim_start();
+        let rest = rest.strip_prefix(PREFIX)?;
+
+        let (is_disabled, checks) = if let Some(checks) = rest.strip_prefix("off") {
+            (true, checks)
+        } else if let Some(checks) = rest.strip_prefix("on") {
+            (false, checks)
+        } else {
+            return None;
+        };
+
+        // `# dotenv-linter:offset` is not a control comment
+        if !checks.is_empty() && !checks.starts_with(char::is_whitespace) {
+            return None;
+        }
+
+        Some(Self {
+            is_disabled,
+            checks: checks
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|name| !name.is_empty())
+                .collect(),
+        })
+    }
+}
+
+/// Checks turned off by control comments at some line of a file
+#[derive(Debug, Default)]
+pub struct Ignores {
+    all: bool,
+    // Turned off checks, or the checks turned back on when all checks are off
+    checks: HashSet<String>,
+}
+
+impl Ignores {
+    pub fn update(&mut self, comment: &Comment) {
+        if comment.checks.is_empty() {
+            self.all = comment.is_disabled;
+            self.checks.clear();
+            return;
+        }
+
+        for &name in &comment.checks {
+            if comment.is_disabled != self.all {
+                self.checks.insert(name.to_string());
+            } else {
+                self.checks.remove(name);
+            }
+        }
+    }
+
+    pub fn contains(&self, check_name: &str) -> bool {
+        self.all != self.checks.contains(check_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(
+            Some(Comment {
+                is_disabled: true,
+                checks: vec!["UnorderedKey", "LowercaseKey"],
+            }),
+            Comment::parse("# dotenv-linter:off UnorderedKey, LowercaseKey")
+        );
+        assert_eq!(
+            Some(Comment {
+                is_disabled: false,
+                checks: vec![],
+            }),
+            Comment::parse("#dotenv-linter:on")
+        );
+    }
+
+    #[test]
+    fn parse_not_control_comment_test() {
+        assert_eq!(None, Comment::parse("# just a comment"));
+        assert_eq!(None, Comment::parse("# dotenv-linter:offset"));
+        assert_eq!(None, Comment::parse("FOO=BAR # dotenv-linter:off"));
+    }
+
+    #[test]
+    fn ignores_test() {
+        let mut ignores = Ignores::default();
+        assert!(!ignores.contains("UnorderedKey"));
+
+        ignores.update(&Comment::parse("# dotenv-linter:off UnorderedKey").unwrap());
+        assert!(ignores.contains("UnorderedKey"));
+        assert!(!ignores.contains("LowercaseKey"));
+
+        ignores.update(&Comment::parse("# dotenv-linter:off").unwrap());
+        ignores.update(&Comment::parse("# dotenv-linter:on LowercaseKey").unwrap());
+        assert!(ignores.contains("UnorderedKey"));
+        assert!(!ignores.contains("LowercaseKey"));
+
+        ignores.update(&Comment::parse("# dotenv-linter:on").unwrap());
+        assert!(!ignores.contains("UnorderedKey"));
+    }
+}
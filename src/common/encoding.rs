@@ -0,0 +1,147 @@
+use std::char::REPLACEMENT_CHARACTER;
+
+// Characters of the 0x80..=0x9F bytes in Windows-1252, the bytes which are not defined are U+FFFD
+const WINDOWS_1252: &str = "€\u{FFFD}‚ƒ„…†‡ˆ‰Š‹Œ\u{FFFD}Ž\u{FFFD}\u{FFFD}‘’“”•–—˜™š›œ\u{FFFD}žŸ";
+
+/// The encoding of the checked files, they are transcoded to UTF-8 before the checks
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Encoding {
+    Utf8,
+    Latin1,
+    Windows1252,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl Encoding {
+    pub const NAMES: [&'static str; 5] =
+        ["utf-8", "latin1", "windows-1252", "utf-16le", "utf-16be"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "utf-8" => Some(Self::Utf8),
+            "latin1" => Some(Self::Latin1),
+            "windows-1252" => Some(Self::Windows1252),
+            "utf-16le" => Some(Self::Utf16Le),
+            "utf-16be" => Some(Self::Utf16Be),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf-8",
+            Self::Latin1 => "latin1",
+            Self::Windows1252 => "windows-1252",
+            Self::Utf16Le => "utf-16le",
+            Self::Utf16Be => "utf-16be",
+        }
+    }
+
+    /// Decodes the bytes and tells if some of them were replaced with U+FFFD.
+    /// Invalid UTF-8 is never replaced, such a file is usually not a text file at all
+    pub fn decode(self, bytes: &[u8]) -> Option<(String, bool)> {
+        let mut is_replaced = false;
+        let mut replace = || {
+            is_replaced = true;
+            REPLACEMENT_CHARACTER
+        };
+
+        let content = match self {
+            Self::Utf8 => String::from_utf8(bytes.to_vec()).ok()?,
+            Self::Latin1 => bytes.iter().map(|&b| char::from(b)).collect(),
+            Self::Windows1252 => bytes
+                .iter()
+                .map(|&b| match b {
+                    0x80..=0x9F => match WINDOWS_1252.chars().nth(usize::from(b - 0x80)) {
+                        Some(REPLACEMENT_CHARACTER) | None => replace(),
+                        Some(c) => c,
+                    },
+                    _ => char::from(b),
+                })
+                .collect(),
+            Self::Utf16Le | Self::Utf16Be => {
+                let chunks = bytes.chunks_exact(2);
+                let has_odd_byte = !chunks.remainder().is_empty();
+                let units = chunks.map(|c| match self {
+                    Self::Utf16Le => u16::from_le_bytes([c[0], c[1]]),
+                    _ => u16::from_be_bytes([c[0], c[1]]),
+                });
+
+                let mut content: String = char::decode_utf16(units)
+                    .map(|c| c.unwrap_or_else(|_| replace()))
+                    .collect();
+                if has_odd_byte {
+                    content.push(replace());
+                }
+
+                // The byte order mark is not a part of the content
+                match content.strip_prefix('\u{FEFF}') {
+                    Some(content) => content.to_string(),
+                    None => content,
+                }
+            }
+        };
+
+        Some((content, is_replaced))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_name_test() {
+        for name in &Encoding::NAMES {
+            assert_eq!(Some(*name), Encoding::from_name(name).map(Encoding::name));
+        }
+        assert_eq!(None, Encoding::from_name("ascii"));
+    }
+
+    #[test]
+    fn decode_test() {
+        let asserts = vec![
+            (
+                Encoding::Utf8,
+                "A=é\n".as_bytes(),
+                Some((String::from("A=é\n"), false)),
+            ),
+            (Encoding::Utf8, &[b'A', b'=', 0xE9][..], None),
+            (
+                Encoding::Latin1,
+                &[b'A', b'=', 0xE9][..],
+                Some((String::from("A=é"), false)),
+            ),
+            (
+                Encoding::Windows1252,
+                &[b'A', b'=', 0x80][..],
+                Some((String::from("A=€"), false)),
+            ),
+            (
+                Encoding::Windows1252,
+                &[b'A', b'=', 0x81][..],
+                Some((String::from("A=\u{FFFD}"), true)),
+            ),
+            (
+                Encoding::Utf16Le,
+                &[0xFF, 0xFE, b'A', 0, b'=', 0, b'1', 0][..],
+                Some((String::from("A=1"), false)),
+            ),
+            (
+                Encoding::Utf16Be,
+                &[0, b'A', 0, b'=', 0, b'1'][..],
+                Some((String::from("A=1"), false)),
+            ),
+            (
+                Encoding::Utf16Le,
+                &[b'A', 0, b'='][..],
+                Some((String::from("A\u{FFFD}"), true)),
+            ),
+        ];
+
+        for (encoding, bytes, expected) in asserts {
+            assert_eq!(expected, encoding.decode(bytes), "Encoding: {:?}", encoding);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::common::*;
 
@@ -20,16 +20,18 @@ impl fmt::Display for FileEntry {
 impl FileEntry {
     /// Converts `PathBuf` to tuple of `(FileEntry, Vec<String>)`
     pub fn from(path: PathBuf) -> Option<(Self, Vec<String>)> {
-        let file_name = match Self::get_file_name(&path) {
-            Some(s) => s,
-            None => return None,
-        };
-
         let content = match fs::read_to_string(&path) {
             Ok(content) => content,
             Err(_) => return None,
         };
 
+        Self::from_content(path, &content)
+    }
+
+    /// Converts `PathBuf` and the file content to tuple of `(FileEntry, Vec<String>)`
+    pub fn from_content(path: PathBuf, content: &str) -> Option<(Self, Vec<String>)> {
+        let file_name = Self::get_file_name(&path)?;
+
         let mut lines: Vec<String> = content.lines().map(|str| str.to_string()).collect();
 
         // You must add a line, because [`Lines`] does not return the last empty row (excludes LF)
@@ -48,14 +50,14 @@ impl FileEntry {
     }
 
     /// Checks a file name with the `.env` pattern
-    pub fn is_env_file(path: &PathBuf) -> bool {
+    pub fn is_env_file(path: &Path) -> bool {
         let pattern = ".env";
         Self::get_file_name(path)
             .filter(|file_name| file_name.starts_with(pattern) || file_name.ends_with(pattern))
             .is_some()
     }
 
-    fn get_file_name(path: &PathBuf) -> Option<String> {
+    fn get_file_name(path: &Path) -> Option<String> {
         path.file_name()
             .map(|file_name| file_name.to_str())
             .unwrap_or(None)
@@ -99,6 +101,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_content_test() {
+        let f = FileEntry::from_content(PathBuf::from(".env"), "FOO=BAR\n");
+        assert_eq!(
+            Some((
+                FileEntry {
+                    path: PathBuf::from(".env"),
+                    file_name: String::from(".env"),
+                    total_lines: 2
+                },
+                vec![String::from("FOO=BAR"), String::from("\n")]
+            )),
+            f
+        );
+    }
+
     #[test]
     fn is_env_file_test() {
         let assertions = vec![
@@ -0,0 +1,61 @@
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::common::*;
+
+/// The reason a file couldn't be checked, e.g. it can't be read or decoded
+#[derive(Clone, Debug, PartialEq)]
+pub struct LintError {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+impl fmt::Display for LintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.reason)
+    }
+}
+
+impl Error for LintError {}
+
+/// Results of a single file: its warnings, or the error if the file couldn't be checked
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileResult {
+    pub file: FileEntry,
+    /// Warnings of the file sorted by lines, including the fixed ones
+    pub warnings: Vec<Warning>,
+    pub error: Option<LintError>,
+}
+
+impl FileResult {
+    pub(crate) fn new(file: FileEntry) -> Self {
+        Self {
+            file,
+            warnings: Vec::new(),
+            error: None,
+        }
+    }
+
+    pub(crate) fn from_error(path: PathBuf, reason: String) -> Self {
+        let file_name = path
+            .file_name()
+            .map(|f| f.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        Self {
+            file: FileEntry {
+                path: path.clone(),
+                file_name,
+                total_lines: 0,
+            },
+            warnings: Vec::new(),
+            error: Some(LintError { path, reason }),
+        }
+    }
+
+    /// Checks if some warnings of the file were fixed (with `--fix`)
+    pub fn is_fixed(&self) -> bool {
+        self.warnings.iter().any(|w| w.is_fixed)
+    }
+}
@@ -20,26 +20,44 @@ impl LineEntry {
         self.trimmed_string().starts_with('#')
     }
 
+    pub fn is_simple_assignment(&self) -> bool {
+        is_simple_assignment(&self.raw_string)
+    }
+
+    pub fn parse(&self) -> ParsedLine<'_> {
+        parse_line(&self.raw_string)
+    }
+
     pub fn get_key(&self) -> Option<String> {
-        if self.is_empty_or_comment() {
+        let parsed = self.parse();
+        if !parsed.has_delimiter {
             return None;
         }
 
-        match self.trimmed_string().find('=') {
-            Some(index) => Some(self.trimmed_string()[..index].to_owned()),
-            None => None,
+        parsed.key.map(String::from)
+    }
+
+    pub fn is_exported(&self) -> bool {
+        self.parse().is_exported
+    }
+
+    /// Returns the `export ` prefix of the line, if any (used by fixers to rebuild the line)
+    pub fn export_prefix(&self) -> &str {
+        if self.is_exported() {
+            EXPORT_PREFIX
+        } else {
+            ""
         }
     }
 
     pub fn get_value(&self) -> Option<String> {
-        if self.is_empty_or_comment() {
-            return None;
-        }
+        self.parse().value.map(String::from)
+    }
 
-        match self.raw_string.find('=') {
-            Some(index) => Some(self.raw_string[(index + 1)..].to_owned()),
-            None => None,
-        }
+    /// Returns the 1-based column of the equal sign
+    pub fn equal_sign_column(&self) -> Option<usize> {
+        let index = self.raw_string.find('=')?;
+        Some(self.raw_string[..index].chars().count() + 1)
     }
 
     pub fn trimmed_string(&self) -> &str {
@@ -70,9 +88,9 @@ mod tests {
                 raw_string: String::from(""),
             };
 
-            assert_eq!(input.is_empty(), true);
-            assert_eq!(input.is_comment(), false);
-            assert_eq!(input.is_empty_or_comment(), true);
+            assert!(input.is_empty());
+            assert!(!input.is_comment());
+            assert!(input.is_empty_or_comment());
         }
 
         #[test]
@@ -87,9 +105,9 @@ mod tests {
                 raw_string: String::from("# Comment"),
             };
 
-            assert_eq!(input.is_empty(), false);
-            assert_eq!(input.is_comment(), true);
-            assert_eq!(input.is_empty_or_comment(), true);
+            assert!(!input.is_empty());
+            assert!(input.is_comment());
+            assert!(input.is_empty_or_comment());
         }
 
         #[test]
@@ -104,9 +122,9 @@ mod tests {
                 raw_string: String::from("NotComment"),
             };
 
-            assert_eq!(input.is_empty(), false);
-            assert_eq!(input.is_comment(), false);
-            assert_eq!(input.is_empty_or_comment(), false);
+            assert!(!input.is_empty());
+            assert!(!input.is_comment());
+            assert!(!input.is_empty_or_comment());
         }
     }
 
@@ -144,6 +162,24 @@ mod tests {
             assert_eq!(expected, input.get_key());
         }
 
+        #[test]
+        fn exported_line_test() {
+            let input = LineEntry {
+                number: 1,
+                file: FileEntry {
+                    path: PathBuf::from(".env"),
+                    file_name: ".env".to_string(),
+                    total_lines: 1,
+                },
+                raw_string: String::from("export FOO=BAR"),
+            };
+            let expected = Some(String::from("FOO"));
+
+            assert_eq!(expected, input.get_key());
+            assert!(input.is_exported());
+            assert_eq!("export ", input.export_prefix());
+        }
+
         #[test]
         fn line_without_value_test() {
             let input = LineEntry {
@@ -0,0 +1,203 @@
+/// The prefix which exports a variable to subprocesses in shells: `export FOO=BAR`
+pub(crate) const EXPORT_PREFIX: &str = "export ";
+
+/// A quote character which opens a value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuoteStyle {
+    Single,
+    Double,
+}
+
+impl QuoteStyle {
+    pub fn as_char(self) -> char {
+        match self {
+            QuoteStyle::Single => '\'',
+            QuoteStyle::Double => '"',
+        }
+    }
+}
+
+/// Parts of a line of an env file, see `parse_line`
+#[derive(Debug, Default, PartialEq)]
+pub struct ParsedLine<'a> {
+    /// The line starts with `export `
+    pub is_exported: bool,
+    /// The text before the `=` delimiter, or the whole line if there is no delimiter.
+    /// It is `None` for empty lines and comments
+    pub key: Option<&'a str>,
+    pub has_delimiter: bool,
+    /// The text after the delimiter as is, including quotes and a trailing comment
+    pub value: Option<&'a str>,
+    /// The quote opening the value, if any
+    pub quote: Option<QuoteStyle>,
+    /// A trailing comment starting with `#`, or the whole line for comment lines
+    pub comment: Option<&'a str>,
+}
+
+impl ParsedLine<'_> {
+    /// Returns the index of the value (after the delimiter) in the parsed line. The value is
+    /// taken to the end of the line, so it is counted from the end
+    pub fn value_start(&self, raw: &str) -> Option<usize> {
+        self.value.map(|value| raw.len() - value.len())
+    }
+}
+
+/// Splits a line of an env file into its parts
+pub fn parse_line(raw: &str) -> ParsedLine<'_> {
+    let line = raw.trim();
+    if line.is_empty() {
+        return ParsedLine::default();
+    }
+
+    if line.starts_with('#') {
+        return ParsedLine {
+            comment: Some(line),
+            ..ParsedLine::default()
+        };
+    }
+
+    let (is_exported, line) = match line.strip_prefix(EXPORT_PREFIX) {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, line),
+    };
+
+    let index = match line.find('=') {
+        Some(index) => index,
+        None => {
+            return ParsedLine {
+                is_exported,
+                key: Some(line),
+                ..ParsedLine::default()
+            }
+        }
+    };
+
+    // The value is taken from the raw line to keep its trailing whitespace
+    let value = &raw[raw.find('=').unwrap_or_default() + 1..];
+    let trimmed_value = value.trim_start();
+    let quote = match trimmed_value.chars().next() {
+        Some('\'') => Some(QuoteStyle::Single),
+        Some('"') => Some(QuoteStyle::Double),
+        _ => None,
+    };
+
+    ParsedLine {
+        is_exported,
+        key: Some(&line[..index]),
+        has_delimiter: true,
+        value: Some(value),
+        quote,
+        comment: find_trailing_comment(trimmed_value, quote),
+    }
+}
+
+/// Returns the index of the quote closing the value, a double quote can be escaped with a backslash
+pub fn find_closing_quote(string: &str, quote: QuoteStyle) -> Option<usize> {
+    let mut is_escaped = false;
+
+    for (i, c) in string.char_indices() {
+        match c {
+            _ if is_escaped => is_escaped = false,
+            '\\' if quote == QuoteStyle::Double => is_escaped = true,
+            _ if c == quote.as_char() => return Some(i),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn find_trailing_comment(value: &str, quote: Option<QuoteStyle>) -> Option<&str> {
+    let rest = match quote {
+        Some(quote) => {
+            let start = find_closing_quote(&value[1..], quote)? + 2;
+            &value[start..]
+        }
+        None => value,
+    };
+
+    // In an unquoted value `#` starts a comment only after a whitespace
+    let index = rest
+        .char_indices()
+        .find(|&(i, c)| {
+            c == '#' && (quote.is_some() || rest[..i].ends_with(|c: char| c.is_whitespace()))
+        })?
+        .0;
+
+    Some(rest[index..].trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_test() {
+        assert_eq!(
+            ParsedLine {
+                is_exported: true,
+                key: Some("FOO"),
+                has_delimiter: true,
+                value: Some("\"bar baz\" # comment "),
+                quote: Some(QuoteStyle::Double),
+                comment: Some("# comment"),
+            },
+            parse_line("  export FOO=\"bar baz\" # comment ")
+        );
+    }
+
+    #[test]
+    fn unquoted_value_test() {
+        let parsed = parse_line("FOO =bar#baz # comment");
+
+        assert_eq!(Some("FOO "), parsed.key);
+        assert_eq!(Some("bar#baz # comment"), parsed.value);
+        assert_eq!(None, parsed.quote);
+        assert_eq!(Some("# comment"), parsed.comment);
+    }
+
+    #[test]
+    fn quoted_hash_test() {
+        let parsed = parse_line("FOO='bar # baz'");
+
+        assert_eq!(Some(QuoteStyle::Single), parsed.quote);
+        assert_eq!(None, parsed.comment);
+    }
+
+    #[test]
+    fn line_without_delimiter_test() {
+        assert_eq!(
+            ParsedLine {
+                key: Some("FOO"),
+                ..ParsedLine::default()
+            },
+            parse_line("FOO")
+        );
+    }
+
+    #[test]
+    fn comment_and_empty_lines_test() {
+        assert_eq!(ParsedLine::default(), parse_line("  \n"));
+        assert_eq!(
+            ParsedLine {
+                comment: Some("# FOO=BAR"),
+                ..ParsedLine::default()
+            },
+            parse_line("# FOO=BAR\n")
+        );
+    }
+
+    #[test]
+    fn value_start_test() {
+        let raw = " export FOO = bar ";
+        assert_eq!(Some(13), parse_line(raw).value_start(raw));
+        assert_eq!(None, parse_line("FOO").value_start("FOO"));
+    }
+
+    #[test]
+    fn find_closing_quote_test() {
+        assert_eq!(Some(5), find_closing_quote("ba\\\"r\"", QuoteStyle::Double));
+        assert_eq!(Some(1), find_closing_quote("\\'", QuoteStyle::Single));
+        assert_eq!(None, find_closing_quote("bar", QuoteStyle::Double));
+    }
+}
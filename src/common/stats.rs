@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Statistics of a run, gathered while the files are checked
+#[derive(Debug, Default, PartialEq)]
+pub struct Stats {
+    pub files: usize,
+    pub lines: usize,
+    /// Warnings suppressed by control comments
+    pub suppressed: usize,
+    /// Paths which were skipped instead of failing the run, with the reasons
+    pub skipped: Vec<(PathBuf, String)>,
+    /// Paths with bytes which can't be decoded with the encoding, they are replaced with U+FFFD
+    pub replaced: Vec<PathBuf>,
+    /// Paths of the generated files which were skipped with the `generated.skip` setting
+    pub generated: Vec<PathBuf>,
+    /// Unified diff of the fixes of all files, written instead of the files with `--patch`
+    pub patch: String,
+    pub checks: BTreeMap<String, CheckStats>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct CheckStats {
+    pub duration: Duration,
+    pub warnings: usize,
+}
+
+impl Stats {
+    pub fn add_check(&mut self, name: &str, duration: Duration, warnings: usize) {
+        // The name is only allocated for the first line, the check is run on every line
+        if !self.checks.contains_key(name) {
+            self.checks.insert(name.to_string(), CheckStats::default());
+        }
+
+        let check = self.checks.get_mut(name).expect("the check is added");
+        check.duration += duration;
+        check.warnings += warnings;
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Files: {}", self.files)?;
+        writeln!(f, "Lines: {}", self.lines)?;
+        write!(f, "Checks:")?;
+
+        for (name, check) in &self.checks {
+            write!(
+                f,
+                "\n  {}: {:?}, {} warning(s)",
+                name, check.duration, check.warnings
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_check_test() {
+        let mut stats = Stats::default();
+        stats.add_check("LowercaseKey", Duration::from_millis(1), 1);
+        stats.add_check("LowercaseKey", Duration::from_millis(2), 0);
+
+        assert_eq!(
+            Some(&CheckStats {
+                duration: Duration::from_millis(3),
+                warnings: 1,
+            }),
+            stats.checks.get("LowercaseKey")
+        );
+    }
+
+    #[test]
+    fn stats_fmt_test() {
+        let mut stats = Stats {
+            files: 2,
+            lines: 10,
+            ..Stats::default()
+        };
+        stats.add_check("DuplicatedKey", Duration::from_millis(1), 2);
+
+        assert_eq!(
+            "Files: 2\nLines: 10\nChecks:\n  DuplicatedKey: 1ms, 2 warning(s)",
+            format!("{}", stats)
+        );
+    }
+}
@@ -1,13 +1,43 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::path::{Path, PathBuf};
 
 use crate::common::*;
 
+/// Errors always fail the run, while warnings can be allowed with `--max-warnings`
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    #[default]
+    Warning,
+    Error,
+}
+
+/// A range of characters in the line of a warning, the column is 1-based
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Span {
+    pub column: usize,
+    pub length: usize,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Warning {
     pub check_name: String,
     line: LineEntry,
     message: String,
     pub is_fixed: bool,
+    pub severity: Severity,
+    /// Lines which would replace the line of the warning after the fix (with `--suggest`)
+    pub suggestion: Vec<String>,
+    /// The value of the line of the warning with resolved substitutions (with `--resolve`)
+    pub resolved: Option<String>,
+    /// Lines which replaced the line of the warning, when it was fixed (with `--fix`)
+    pub fixed_lines: Vec<String>,
+    /// Offending parts of the line, when a check can point to them
+    pub spans: Vec<Span>,
+    /// The index of the warning among the earlier warnings of its file with the same check,
+    /// message and key, it tells the fingerprints of identical lines apart
+    pub occurrence: usize,
 }
 
 impl Warning {
@@ -18,6 +48,12 @@ impl Warning {
             check_name,
             message,
             is_fixed: false,
+            severity: Severity::Warning,
+            suggestion: Vec::new(),
+            resolved: None,
+            fixed_lines: Vec::new(),
+            spans: Vec::new(),
+            occurrence: 0,
         }
     }
 
@@ -25,6 +61,85 @@ impl Warning {
         self.line.number
     }
 
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The line of the warning as it is in the file
+    pub fn source(&self) -> &str {
+        &self.line.raw_string
+    }
+
+    pub fn file(&self) -> &FileEntry {
+        &self.line.file
+    }
+
+    pub fn file_path(&self) -> &Path {
+        &self.line.file.path
+    }
+
+    /// Warnings are emitted ordered by the file path, then by the line number and the check name
+    pub fn sort_key(&self) -> (&Path, usize, &str) {
+        (self.file_path(), self.line.number, &self.check_name)
+    }
+
+    /// A hash of the file, the check, the message and the key of the warning, e.g. to track the
+    /// warning across runs. The value of the line is not hashed, so it can't be guessed from the
+    /// fingerprint. The line number is not hashed either (numbers of other lines in the message
+    /// are replaced too), so the fingerprint survives lines added above the warning, while
+    /// warnings of identical lines are told apart by their occurrence
+    pub fn fingerprint(&self) -> String {
+        let fields = [
+            self.file_path().display().to_string(),
+            self.check_name.clone(),
+            normalize_message(&self.message),
+            self.line.parse().key.unwrap_or_default().to_string(),
+            self.occurrence.to_string(),
+        ];
+
+        // FNV-1a is used since the hashers of std are not guaranteed to be the same across versions
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in fields.join("\0").bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+
+        format!("{:016x}", hash)
+    }
+
+    /// Formats the warning without the file path (used when warnings are grouped by files)
+    pub fn format_without_path(&self) -> String {
+        format!(
+            "{} {}{}: {}",
+            self.line.number,
+            self.severity_label(),
+            self.check_name,
+            self.message
+        )
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    // Warnings are printed without a label to keep the default output unchanged
+    fn severity_label(&self) -> &str {
+        match self.severity {
+            Severity::Warning => "",
+            Severity::Error => "[error] ",
+        }
+    }
+
+    /// Replaces the path of the file of the warning, e.g. to show it relative to another directory
+    pub fn set_file_path(&mut self, path: PathBuf) {
+        self.line.file.path = path;
+    }
+
+    /// Moves the warning to another line, e.g. to the line removed by its fix
+    pub(crate) fn set_line(&mut self, line: LineEntry) {
+        self.line = line;
+    }
+
     pub fn mark_as_fixed(&mut self) {
         self.is_fixed = true;
     }
@@ -34,12 +149,29 @@ impl Warning {
     }
 }
 
+// Replaces the numbers after `line` in a message (e.g. `at line 3`) and collapses whitespace
+fn normalize_message(message: &str) -> String {
+    let mut words: Vec<&str> = Vec::new();
+
+    for word in message.split_whitespace() {
+        let is_line_number = words.last().is_some_and(|w| *w == "line" || *w == "lines")
+            && word.starts_with(|c: char| c.is_ascii_digit());
+        words.push(if is_line_number { "N" } else { word });
+    }
+
+    words.join(" ")
+}
+
 impl fmt::Display for Warning {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}:{} {}: {}",
-            self.line.file, self.line.number, self.check_name, self.message
+            "{}:{} {}{}: {}",
+            self.line.file,
+            self.line.number,
+            self.severity_label(),
+            self.check_name,
+            self.message
         )
     }
 }
@@ -70,5 +202,92 @@ mod tests {
             ".env:1 DuplicatedKey: The FOO key is duplicated",
             format!("{}", warning)
         );
+        assert_eq!(
+            "1 DuplicatedKey: The FOO key is duplicated",
+            warning.format_without_path()
+        );
+    }
+
+    #[test]
+    fn error_fmt_test() {
+        let line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from("FOO=BAR"),
+        };
+        let mut warning = Warning::new(
+            line,
+            "DuplicatedKey",
+            String::from("The FOO key is duplicated"),
+        );
+        warning.severity = Severity::Error;
+
+        assert_eq!(
+            ".env:1 [error] DuplicatedKey: The FOO key is duplicated",
+            format!("{}", warning)
+        );
+        assert_eq!(
+            "1 [error] DuplicatedKey: The FOO key is duplicated",
+            warning.format_without_path()
+        );
+    }
+
+    fn warning(number: usize, raw_string: &str, message: &str) -> Warning {
+        let line = LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 10,
+            },
+            raw_string: String::from(raw_string),
+        };
+
+        Warning::new(line, "InconsistentExport", String::from(message))
+    }
+
+    #[test]
+    fn fingerprint_test() {
+        let fingerprint = warning(1, "FOO=BAR", "The message").fingerprint();
+
+        assert_eq!(16, fingerprint.len());
+        assert_eq!(
+            fingerprint,
+            warning(5, "FOO=BAR  ", "The  message").fingerprint()
+        );
+        assert_ne!(
+            fingerprint,
+            warning(1, "BAR=BAR", "The message").fingerprint()
+        );
+        assert_eq!(
+            fingerprint,
+            warning(1, "FOO=BAZ", "The message").fingerprint()
+        );
+        assert_ne!(
+            fingerprint,
+            warning(1, "FOO=BAR", "Another message").fingerprint()
+        );
+
+        let mut next = warning(2, "FOO=BAR", "The message");
+        next.occurrence = 1;
+        assert_ne!(fingerprint, next.fingerprint());
+    }
+
+    #[test]
+    fn normalize_message_test() {
+        assert_eq!(
+            "The FOO key is defined with export at line N and without it at line N",
+            normalize_message(
+                "The FOO key is defined with export at line 1 and without it at line 3"
+            )
+        );
+        assert_eq!(
+            "The FOO key has 3 values",
+            normalize_message("The FOO key has  3 values")
+        );
     }
 }
@@ -0,0 +1,166 @@
+//! Extraction of the `environment:` blocks of docker-compose files, so the entries can be
+//! checked like the lines of env files
+
+use std::path::Path;
+
+/// Checks about the layout of env files, which don't apply to the extracted entries
+pub const LAYOUT_CHECKS: &[&str] = &["EndingBlankLine"];
+
+/// The entry of an `environment:` block as a line of an env file, with its line number
+#[derive(Debug, PartialEq)]
+pub struct Entry {
+    pub number: usize,
+    pub line: String,
+}
+
+/// Files named like `docker-compose.yml`, `docker-compose.prod.yaml` or `compose.yml`
+pub fn is_compose_file(path: &Path) -> bool {
+    let file_name = match path.file_name().and_then(|f| f.to_str()) {
+        Some(file_name) => file_name,
+        None => return false,
+    };
+
+    (file_name.starts_with("docker-compose") || file_name.starts_with("compose"))
+        && (file_name.ends_with(".yml") || file_name.ends_with(".yaml"))
+}
+
+/// Returns the entries of each `environment:` block of the content.
+///
+/// Only the block styles are recognized (lists of `KEY=VALUE` items and mappings), the entries
+/// without a value pass the variable from the host and are left out
+pub fn extract(content: &str) -> Vec<Vec<Entry>> {
+    let mut blocks = Vec::new();
+    // The indentation of the current `environment:` key and the entries of its block
+    let mut block: Option<(usize, Vec<Entry>)> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        let indent = line.len() - line.trim_start().len();
+
+        if let Some((block_indent, entries)) = &mut block {
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            // Items of a list can have the same indentation as the key
+            if indent > *block_indent || (indent == *block_indent && trimmed.starts_with("- ")) {
+                if let Some(entry) = parse_entry(trimmed) {
+                    entries.push(Entry {
+                        number: index + 1,
+                        line: entry,
+                    });
+                }
+                continue;
+            }
+
+            blocks.extend(block.take().map(|(_, entries)| entries));
+        }
+
+        if without_comment(trimmed) == "environment:" {
+            block = Some((indent, Vec::new()));
+        }
+    }
+
+    blocks.extend(block.map(|(_, entries)| entries));
+    blocks.retain(|entries| !entries.is_empty());
+    blocks
+}
+
+fn parse_entry(item: &str) -> Option<String> {
+    if let Some(item) = item.strip_prefix("- ") {
+        let item = unquote(item.trim());
+        return if item.contains('=') {
+            Some(item.to_string())
+        } else {
+            None
+        };
+    }
+
+    let (key, value) = match item.find(": ") {
+        Some(index) => (&item[..index], item[index + 1..].trim()),
+        None => (item.strip_suffix(':')?, ""),
+    };
+
+    if without_comment(value).is_empty() || value == "null" || value == "~" {
+        return None;
+    }
+
+    Some(format!("{}={}", unquote(key.trim()), unquote(value)))
+}
+
+fn without_comment(str: &str) -> &str {
+    match str.find(" #") {
+        Some(index) => str[..index].trim_end(),
+        None if str.starts_with('#') => "",
+        None => str,
+    }
+}
+
+/// Removes the quotes of a YAML scalar, they are not a part of the value
+fn unquote(str: &str) -> &str {
+    for quote in &['"', '\''] {
+        if str.len() >= 2 && str.starts_with(*quote) && str.ends_with(*quote) {
+            return &str[1..str.len() - 1];
+        }
+    }
+
+    str
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(number: usize, line: &str) -> Entry {
+        Entry {
+            number,
+            line: String::from(line),
+        }
+    }
+
+    #[test]
+    fn is_compose_file_test() {
+        for (file_name, expected) in &[
+            ("docker-compose.yml", true),
+            ("docker-compose.prod.yaml", true),
+            ("compose.yml", true),
+            ("config.yml", false),
+            ("docker-compose.json", false),
+        ] {
+            assert_eq!(
+                *expected,
+                is_compose_file(Path::new(file_name)),
+                "{}",
+                file_name
+            );
+        }
+    }
+
+    #[test]
+    fn extract_list_test() {
+        let content = "services:\n  web:\n    image: web\n    environment:\n      - FOO=bar\n      # comment\n      - \"BAR=baz qux\"\n      - HOST_VAR\n    ports:\n      - \"80:80\"\n";
+
+        assert_eq!(
+            vec![vec![entry(5, "FOO=bar"), entry(7, "BAR=baz qux")]],
+            extract(content)
+        );
+    }
+
+    #[test]
+    fn extract_mapping_test() {
+        let content = "services:\n  web:\n    environment: # settings\n      FOO: bar\n      BAR: \"1\"\n      HOST_VAR:\n  db:\n    environment:\n    - db_name=db\n";
+
+        assert_eq!(
+            vec![
+                vec![entry(4, "FOO=bar"), entry(5, "BAR=1")],
+                vec![entry(9, "db_name=db")]
+            ],
+            extract(content)
+        );
+    }
+
+    #[test]
+    fn extract_without_environment_test() {
+        assert!(extract("services:\n  web:\n    image: web\n").is_empty());
+    }
+}
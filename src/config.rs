@@ -0,0 +1,1255 @@
+use crate::checks::{
+    self, BooleanStyle, CommentStyle, EmptyValueStyle, ExportStyle, IntegerWidth, Language,
+    ListSeparator, Platform, SeparatorStyle, TrailingSlash,
+};
+use crate::common::{FileEntry, Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// Names of config files which are looked up in the current directory, in order of priority
+pub const CONFIG_FILE_NAMES: &[&str] = &[
+    ".dotenv-linter.yml",
+    ".dotenv-linter.toml",
+    ".dotenv-linter.json",
+];
+
+/// JSON Schema of the config file, for autocompletion and validation in editors.
+/// The tests keep it in sync with the config and the names of the checks
+pub const CONFIG_SCHEMA: &str = include_str!("config.schema.json");
+
+/// Checks of the key of a line, a problem at an earlier one usually causes the later ones
+pub const DEFAULT_PRIORITY: &[&str] = &[
+    "InvisibleCharacter",
+    "LeadingCharacter",
+    "KeyWithoutValue",
+    "DuplicatedDelimiter",
+    "SpaceCharacter",
+    "SplitAssignment",
+    "IncorrectDelimiter",
+    "LowercaseKey",
+];
+
+/// Name of the profile which is used without `--profile`
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Format of a config file, detected by its extension
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl Format {
+    /// Files with an unknown extension are treated as YAML
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => Format::Toml,
+            Some("json") => Format::Json,
+            _ => Format::Yaml,
+        }
+    }
+}
+
+/// Settings from the config file, merged with the command line arguments
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Names of the checks which should be skipped
+    pub skip: Vec<String>,
+    /// Names of the optional checks which should be run
+    pub enable: Vec<String>,
+    /// Names of the only checks which should be run (optional ones too), `skip` still applies to them
+    pub only: Vec<String>,
+    /// Glob patterns of the file names which are checked in directories, instead of `.env` files
+    pub patterns: Vec<String>,
+    /// Searches the files in the subdirectories of the passed directories too
+    pub recursive: bool,
+    /// Custom message templates by check names
+    pub templates: BTreeMap<String, String>,
+    /// Severities by check names, the checks which are not listed keep the severity of their
+    /// warnings (the warning severity, except conflicting duplicates of `DuplicatedKey`)
+    pub severity: BTreeMap<String, Severity>,
+    /// Skips the warnings of the checks from `priority` on a line, when a check before them
+    /// in the list has a warning on the same line
+    pub short_circuit: bool,
+    /// Names of the checks in order of priority for `short_circuit`, `DEFAULT_PRIORITY` if empty
+    pub priority: Vec<String>,
+    /// Settings of the individual checks
+    pub checks: ChecksConfig,
+    /// Named sets of settings selected with `--profile`, the profile named `default` is used
+    /// without the argument
+    pub profiles: BTreeMap<String, Profile>,
+    /// Detection of the generated files by a marker in their header
+    pub generated: GeneratedConfig,
+}
+
+/// The settings of a run as the checks get them (`--print-config`): the config file with
+/// the profile and the command line arguments applied
+#[derive(Debug, PartialEq, Serialize)]
+pub struct EffectiveConfig {
+    /// Names of the checks which are run with the settings
+    pub active_checks: Vec<String>,
+    #[serde(flatten)]
+    pub config: Config,
+}
+
+/// Settings of a profile, they are added to the settings of the config
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    /// Names of the checks which should be skipped
+    pub skip: Vec<String>,
+    /// Names of the optional checks which should be run
+    pub enable: Vec<String>,
+    /// Severities by check names, they take precedence over the severities of the config
+    pub severity: BTreeMap<String, Severity>,
+}
+
+/// Settings of the generated files, e.g. with a `# GENERATED - DO NOT EDIT` header
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GeneratedConfig {
+    /// Skips the generated files instead of checking them
+    pub skip: bool,
+    /// Glob pattern of a line which marks the file as generated, matched case-insensitively
+    pub marker: String,
+    /// Number of the first lines of a file which are searched for the marker
+    pub lines: usize,
+}
+
+impl Default for GeneratedConfig {
+    fn default() -> Self {
+        Self {
+            skip: false,
+            marker: String::from("*generated*do not edit*"),
+            lines: 5,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ChecksConfig {
+    #[serde(rename = "AssignmentShape")]
+    pub assignment_shape: AssignmentShapeConfig,
+    #[serde(rename = "BooleanConsistency")]
+    pub boolean_consistency: BooleanConsistencyConfig,
+    #[serde(rename = "CommentSpacing")]
+    pub comment_spacing: CommentSpacingConfig,
+    #[serde(rename = "CommentStyle")]
+    pub comment_style: CommentStyleConfig,
+    #[serde(rename = "CommentedKey")]
+    pub commented_key: CommentedKeyConfig,
+    #[serde(rename = "DuplicatedKey")]
+    pub duplicated_key: DuplicatedKeyConfig,
+    #[serde(rename = "DurationUnit")]
+    pub duration_unit: DurationUnitConfig,
+    #[serde(rename = "EmptyProductionSecret")]
+    pub empty_production_secret: EmptyProductionSecretConfig,
+    #[serde(rename = "EmptyValueStyle")]
+    pub empty_value_style: EmptyValueStyleConfig,
+    #[serde(rename = "ExportStyle")]
+    pub export_style: ExportStyleConfig,
+    #[serde(rename = "ForbiddenKey")]
+    pub forbidden_key: ForbiddenKeyConfig,
+    #[serde(rename = "IndexGap")]
+    pub index_gap: IndexGapConfig,
+    #[serde(rename = "IntegerRange")]
+    pub integer_range: IntegerRangeConfig,
+    #[serde(rename = "KeyBeforeHeader")]
+    pub key_before_header: KeyBeforeHeaderConfig,
+    #[serde(rename = "ListSeparator")]
+    pub list_separator: ListSeparatorConfig,
+    #[serde(rename = "LocalhostValue")]
+    pub localhost_value: LocalhostValueConfig,
+    #[serde(rename = "MissingPath")]
+    pub missing_path: MissingPathConfig,
+    #[serde(rename = "NumberedKey")]
+    pub numbered_key: NumberedKeyConfig,
+    #[serde(rename = "NumericKeyBooleanValue")]
+    pub numeric_key_boolean_value: NumericKeyBooleanValueConfig,
+    #[serde(rename = "PairList")]
+    pub pair_list: PairListConfig,
+    #[serde(rename = "PathSeparator")]
+    pub path_separator: PathSeparatorConfig,
+    #[serde(rename = "PortValue")]
+    pub port_value: PortValueConfig,
+    #[serde(rename = "RepeatedComment")]
+    pub repeated_comment: RepeatedCommentConfig,
+    #[serde(rename = "RequiredKeys")]
+    pub required_keys: RequiredKeysConfig,
+    #[serde(rename = "ReservedIdentifier")]
+    pub reserved_identifier: ReservedIdentifierConfig,
+    #[serde(rename = "SeparatorConsistency")]
+    pub separator_consistency: SeparatorConsistencyConfig,
+    #[serde(rename = "SpaceCharacter")]
+    pub space_character: SpaceCharacterConfig,
+    #[serde(rename = "UnchangedExampleValue")]
+    pub unchanged_example_value: UnchangedExampleValueConfig,
+    #[serde(rename = "UnresolvedTemplate")]
+    pub unresolved_template: UnresolvedTemplateConfig,
+    #[serde(rename = "UrlScheme")]
+    pub url_scheme: UrlSchemeConfig,
+    #[serde(rename = "UrlTrailingSlash")]
+    pub url_trailing_slash: UrlTrailingSlashConfig,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AssignmentShapeConfig {
+    /// Keys shorter than this are reported
+    pub min_key_length: usize,
+    /// Keys longer than this with an empty value are reported
+    pub max_empty_key_length: usize,
+}
+
+impl Default for AssignmentShapeConfig {
+    fn default() -> Self {
+        Self {
+            min_key_length: 2,
+            max_empty_key_length: 40,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BooleanConsistencyConfig {
+    /// The expected representation of boolean values. It is inferred from the file when not set
+    pub style: Option<BooleanStyle>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CommentSpacingConfig {
+    /// Requires a blank line before every comment after a key, instead of inferring it from the file
+    pub required: bool,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CommentStyleConfig {
+    /// The expected style of comments. It is inferred from the file when not set
+    pub style: Option<CommentStyle>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CommentedKeyConfig {
+    /// The number of commented out keys which is allowed in a file
+    pub threshold: usize,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DuplicatedKeyConfig {
+    /// Duplicates with the same value are reported apart from the ones with a different value,
+    /// which are errors
+    pub compare_values: bool,
+}
+
+impl Default for DuplicatedKeyConfig {
+    fn default() -> Self {
+        Self {
+            compare_values: true,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DurationUnitConfig {
+    /// Suffixes of the keys which are durations
+    pub suffixes: Vec<String>,
+    /// Reports the plain numbers if set, otherwise the values with a unit are reported
+    pub require_unit: bool,
+}
+
+impl Default for DurationUnitConfig {
+    fn default() -> Self {
+        Self {
+            suffixes: ["_TIMEOUT", "_TTL", "_INTERVAL"]
+                .iter()
+                .map(|s| String::from(*s))
+                .collect(),
+            require_unit: true,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EmptyProductionSecretConfig {
+    /// Glob patterns of the names of the production files
+    pub files: Vec<String>,
+    /// Suffixes of the keys which are secrets
+    pub suffixes: Vec<String>,
+}
+
+impl Default for EmptyProductionSecretConfig {
+    fn default() -> Self {
+        Self {
+            files: vec![String::from("*.prod*"), String::from("*.production*")],
+            suffixes: ["_SECRET", "_PASSWORD", "_TOKEN"]
+                .iter()
+                .map(|s| String::from(*s))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EmptyValueStyleConfig {
+    /// The expected style of empty values. It is inferred from the file when not set
+    pub style: Option<EmptyValueStyle>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExportStyleConfig {
+    /// Whether keys can, can't or must be defined with `export`
+    pub style: ExportStyle,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ForbiddenKeyConfig {
+    /// Keys which shouldn't be defined
+    pub keys: Vec<ForbiddenKeyItem>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ForbiddenKeyItem {
+    pub key: String,
+    /// Shown in the warning instead of the default advice, e.g. the key to use instead
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IndexGapConfig {
+    /// The minimum number of keys with the same prefix and a numeric index to be checked
+    pub min_family_size: usize,
+}
+
+impl Default for IndexGapConfig {
+    fn default() -> Self {
+        Self { min_family_size: 3 }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IntegerRangeConfig {
+    /// The integer type which the values should fit in
+    pub width: IntegerWidth,
+    /// Suffixes of the keys whose values are checked, all keys are checked when empty
+    pub suffixes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeyBeforeHeaderConfig {
+    /// Ignores commented out keys (`# KEY=value`), which are not documentation comments
+    pub ignore_commented_keys: bool,
+}
+
+impl Default for KeyBeforeHeaderConfig {
+    fn default() -> Self {
+        Self {
+            ignore_commented_keys: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ListSeparatorConfig {
+    /// The expected separator of list values. Without it only mixed separators are reported
+    pub separator: Option<ListSeparator>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LocalhostValueConfig {
+    /// Glob patterns of the names of the development files, where local hosts are not reported
+    pub dev_files: Vec<String>,
+    /// Hosts which are local
+    pub hosts: Vec<String>,
+}
+
+impl Default for LocalhostValueConfig {
+    fn default() -> Self {
+        Self {
+            dev_files: [
+                ".env",
+                "*.local*",
+                "*.dev*",
+                "*.test*",
+                "*.example*",
+                "*.sample*",
+                "*.template*",
+            ]
+            .iter()
+            .map(|s| String::from(*s))
+            .collect(),
+            hosts: ["localhost", "127.0.0.1", "::1", "0.0.0.0"]
+                .iter()
+                .map(|s| String::from(*s))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MissingPathConfig {
+    /// Suffixes of the keys whose values should be existing paths
+    pub suffixes: Vec<String>,
+}
+
+impl Default for MissingPathConfig {
+    fn default() -> Self {
+        Self {
+            suffixes: vec![String::from("_PATH"), String::from("_FILE")],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NumberedKeyConfig {
+    /// The minimum number of keys differing only by a numeric suffix to be reported
+    pub min_group_size: usize,
+}
+
+impl Default for NumberedKeyConfig {
+    fn default() -> Self {
+        Self { min_group_size: 2 }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NumericKeyBooleanValueConfig {
+    /// Suffixes of the keys whose values should be numbers
+    pub suffixes: Vec<String>,
+}
+
+impl Default for NumericKeyBooleanValueConfig {
+    fn default() -> Self {
+        Self {
+            suffixes: ["_COUNT", "_RETRIES", "_LIMIT", "_SIZE"]
+                .iter()
+                .map(|s| String::from(*s))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PairListConfig {
+    /// Suffixes of the keys whose values are `key=value` pairs separated by `;`
+    pub suffixes: Vec<String>,
+}
+
+impl Default for PairListConfig {
+    fn default() -> Self {
+        Self {
+            suffixes: vec![
+                String::from("_OPTIONS"),
+                String::from("_PARAMS"),
+                String::from("_PROPERTIES"),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PathSeparatorConfig {
+    /// The platform whose path separator is expected
+    pub platform: Platform,
+    /// Suffixes of the keys whose values are paths
+    pub suffixes: Vec<String>,
+}
+
+impl Default for PathSeparatorConfig {
+    fn default() -> Self {
+        Self {
+            platform: Platform::default(),
+            suffixes: vec![
+                String::from("_PATH"),
+                String::from("_DIR"),
+                String::from("_FILE"),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PortValueConfig {
+    /// Suffixes of the keys whose values should be port numbers
+    pub suffixes: Vec<String>,
+    /// Quoted values are treated as intentional strings and are not checked
+    pub skip_quoted: bool,
+}
+
+impl Default for PortValueConfig {
+    fn default() -> Self {
+        Self {
+            suffixes: vec![String::from("_PORT")],
+            skip_quoted: true,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RepeatedCommentConfig {
+    /// Number of lines the same inline comment can be on
+    pub max_count: usize,
+}
+
+impl Default for RepeatedCommentConfig {
+    fn default() -> Self {
+        Self { max_count: 2 }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RequiredKeysConfig {
+    /// Keys which should be defined in every file
+    pub keys: Vec<String>,
+    /// Additional required keys for the files matching glob patterns
+    pub overrides: Vec<RequiredKeysOverride>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RequiredKeysOverride {
+    /// Glob pattern of the file paths, relative to the current directory
+    pub files: String,
+    pub keys: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SeparatorConsistencyConfig {
+    /// The expected style of keys. It is inferred from the file when not set
+    pub style: Option<SeparatorStyle>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpaceCharacterConfig {
+    /// Allows padding before the equal sign, when the keys of a group are aligned to one column
+    pub aligned: bool,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UnchangedExampleValueConfig {
+    /// The name of the example file, it is looked up in the directory of each checked file
+    pub example: String,
+}
+
+impl Default for UnchangedExampleValueConfig {
+    fn default() -> Self {
+        Self {
+            example: String::from(".env.example"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UnresolvedTemplateConfig {
+    /// Delimiters of the template placeholders
+    pub delimiters: Vec<TemplateDelimiters>,
+}
+
+impl Default for UnresolvedTemplateConfig {
+    fn default() -> Self {
+        Self {
+            delimiters: [("<", ">"), ("{{", "}}"), ("%%", "%%")]
+                .iter()
+                .map(|(open, close)| TemplateDelimiters {
+                    open: String::from(*open),
+                    close: String::from(*close),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateDelimiters {
+    pub open: String,
+    pub close: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UrlSchemeConfig {
+    /// Suffixes of the keys which are URLs
+    pub suffixes: Vec<String>,
+    /// Reports the values without a scheme if set, otherwise the values with a scheme are reported
+    pub require_scheme: bool,
+}
+
+impl Default for UrlSchemeConfig {
+    fn default() -> Self {
+        Self {
+            suffixes: ["_URL", "_ENDPOINT"]
+                .iter()
+                .map(|s| String::from(*s))
+                .collect(),
+            require_scheme: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UrlTrailingSlashConfig {
+    /// Whether URL values should end with a slash. It is inferred from the file when not set
+    pub policy: Option<TrailingSlash>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReservedIdentifierConfig {
+    /// The language whose keywords are reserved
+    pub language: Language,
+    /// Additional reserved words
+    pub words: Vec<String>,
+}
+
+impl Config {
+    /// Reads the config from the given file
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+        Self::from_content(&content, Format::from_path(path))
+            .map_err(|e| format!("Invalid config {}: {}", path.display(), e).into())
+    }
+
+    /// Reads the config from the first of `CONFIG_FILE_NAMES` found in the nearest directory,
+    /// starting from `current_dir` and walking up to the root of the repository (a directory
+    /// with `.git`) or to the root directory
+    pub fn find(current_dir: &Path) -> Result<Self, Box<dyn Error>> {
+        for dir in current_dir.ancestors() {
+            if let Some(path) = CONFIG_FILE_NAMES
+                .iter()
+                .map(|name| dir.join(name))
+                .find(|path| path.is_file())
+            {
+                return Self::from_file(&path);
+            }
+
+            if dir.join(".git").exists() {
+                break;
+            }
+        }
+
+        Ok(Self::default())
+    }
+
+    /// Returns the index of the check in the priority order, if it takes part in `short_circuit`
+    pub fn priority_of(&self, check_name: &str) -> Option<usize> {
+        if self.priority.is_empty() {
+            DEFAULT_PRIORITY.iter().position(|name| *name == check_name)
+        } else {
+            self.priority.iter().position(|name| name == check_name)
+        }
+    }
+
+    pub fn severity_of(&self, check_name: &str) -> Severity {
+        self.severity.get(check_name).copied().unwrap_or_default()
+    }
+
+    fn from_content(content: &str, format: Format) -> Result<Self, String> {
+        // An empty file is a valid config
+        if content.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        let config: Self = match format {
+            Format::Yaml => serde_yaml::from_str(content).map_err(|e| e.to_string())?,
+            Format::Toml => toml::from_str(content).map_err(|e| e.to_string())?,
+            Format::Json => serde_json::from_str(content).map_err(|e| e.to_string())?,
+        };
+
+        config.validate_patterns()?;
+
+        Ok(config)
+    }
+
+    /// Adds the settings of the profile to the config, the `default` profile is used if
+    /// the name is not given and the config has it
+    pub fn apply_profile(&mut self, name: Option<&str>) -> Result<(), String> {
+        let name = match name {
+            Some(name) => name,
+            None if self.profiles.contains_key(DEFAULT_PROFILE) => DEFAULT_PROFILE,
+            None => return Ok(()),
+        };
+
+        let profile = match self.profiles.remove(name) {
+            Some(profile) => profile,
+            None if self.profiles.is_empty() => {
+                return Err(format!(
+                    "Unknown profile {}, the config has no profiles",
+                    name
+                ))
+            }
+            None => {
+                let names: Vec<&str> = self.profiles.keys().map(|n| n.as_str()).collect();
+                return Err(format!(
+                    "Unknown profile {}, the available profiles are: {}",
+                    name,
+                    names.join(", ")
+                ));
+            }
+        };
+
+        self.skip.extend(profile.skip);
+        self.enable.extend(profile.enable);
+        self.severity.extend(profile.severity);
+
+        Ok(())
+    }
+
+    /// Checks the patterns and the names of the checks of the config
+    pub fn validate(&self) -> Result<(), String> {
+        self.validate_patterns()?;
+        checks::validate_config(self)
+    }
+
+    fn validate_patterns(&self) -> Result<(), String> {
+        let patterns = self
+            .checks
+            .required_keys
+            .overrides
+            .iter()
+            .map(|item| &item.files)
+            .chain(&self.checks.empty_production_secret.files)
+            .chain(&self.checks.localhost_value.dev_files)
+            .chain(&self.patterns)
+            .chain(std::iter::once(&self.generated.marker));
+
+        for pattern in patterns {
+            glob::Pattern::new(pattern)
+                .map_err(|e| format!("Invalid pattern {}: {}", pattern, e))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Glob patterns of the files from the config
+#[derive(Debug)]
+pub(crate) struct FilePatterns(Vec<glob::Pattern>);
+
+impl FilePatterns {
+    /// Compiles the patterns, invalid patterns are skipped since they are rejected when the config
+    /// is validated
+    pub(crate) fn new<'a>(patterns: impl IntoIterator<Item = &'a String>) -> Self {
+        Self(
+            patterns
+                .into_iter()
+                .filter_map(|pattern| glob::Pattern::new(pattern).ok())
+                .collect(),
+        )
+    }
+
+    /// Checks if one of the patterns matches the file name
+    pub(crate) fn matches_name(&self, file: &FileEntry) -> bool {
+        self.0
+            .iter()
+            .any(|pattern| pattern.matches(&file.file_name))
+    }
+
+    /// Checks if one of the patterns matches the file path, relative to the current directory
+    pub(crate) fn matches_path(&self, file: &FileEntry) -> bool {
+        self.0
+            .iter()
+            .any(|pattern| pattern.matches_path(&file.path))
+    }
+}
+
+/// Builds a config in code, the defaults are the same as without a config file:
+///
+/// ```
+/// use dotenv_linter::{ConfigBuilder, Severity};
+///
+/// let config = ConfigBuilder::new()
+///     .skip("UnorderedKey")
+///     .severity("DuplicatedKey", Severity::Error)
+///     .build()
+///     .expect("valid config");
+/// let warnings = dotenv_linter::lint_str_with_config("B=1\nA=2\n", ".env", &config);
+/// assert!(warnings.is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct ConfigBuilder {
+    config: Config,
+}
+
+impl ConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skips the check
+    pub fn skip(mut self, check_name: &str) -> Self {
+        self.config.skip.push(String::from(check_name));
+        self
+    }
+
+    /// Runs the optional check
+    pub fn enable(mut self, check_name: &str) -> Self {
+        self.config.enable.push(String::from(check_name));
+        self
+    }
+
+    /// Runs only the check and the other checks passed to this method
+    pub fn only(mut self, check_name: &str) -> Self {
+        self.config.only.push(String::from(check_name));
+        self
+    }
+
+    /// Adds a glob pattern of the file names which are checked in directories
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        self.config.patterns.push(String::from(pattern));
+        self
+    }
+
+    /// Overrides the message template of the check
+    pub fn template(mut self, check_name: &str, template: &str) -> Self {
+        self.config
+            .templates
+            .insert(String::from(check_name), String::from(template));
+        self
+    }
+
+    /// Sets the severity of the warnings of the check
+    pub fn severity(mut self, check_name: &str, severity: Severity) -> Self {
+        self.config
+            .severity
+            .insert(String::from(check_name), severity);
+        self
+    }
+
+    /// Shows only the first warning of the checks from the priority list on a line
+    pub fn short_circuit(mut self, short_circuit: bool) -> Self {
+        self.config.short_circuit = short_circuit;
+        self
+    }
+
+    /// Sets the order of the checks for `short_circuit`
+    pub fn priority(mut self, check_names: &[&str]) -> Self {
+        self.config.priority = check_names.iter().map(|name| String::from(*name)).collect();
+        self
+    }
+
+    /// Sets the settings of the individual checks
+    pub fn checks(mut self, checks: ChecksConfig) -> Self {
+        self.config.checks = checks;
+        self
+    }
+
+    /// Returns the config, or an error if it refers to unknown checks or has invalid patterns
+    pub fn build(self) -> Result<Config, String> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_content_test() {
+        let config = Config::from_content(
+            "skip:\n  - UnorderedKey\nenable: [SeparatorConsistency]\nchecks:\n  SeparatorConsistency:\n    style: none\n",
+            Format::Yaml,
+        )
+        .expect("valid config");
+
+        assert_eq!(
+            Config {
+                skip: vec![String::from("UnorderedKey")],
+                enable: vec![String::from("SeparatorConsistency")],
+                only: Vec::new(),
+                patterns: Vec::new(),
+                templates: BTreeMap::new(),
+                severity: BTreeMap::new(),
+                recursive: false,
+                short_circuit: false,
+                priority: Vec::new(),
+                checks: ChecksConfig {
+                    assignment_shape: AssignmentShapeConfig::default(),
+                    boolean_consistency: BooleanConsistencyConfig::default(),
+                    comment_spacing: CommentSpacingConfig::default(),
+                    comment_style: CommentStyleConfig::default(),
+                    commented_key: CommentedKeyConfig::default(),
+                    duplicated_key: DuplicatedKeyConfig::default(),
+                    duration_unit: DurationUnitConfig::default(),
+                    empty_production_secret: EmptyProductionSecretConfig::default(),
+                    empty_value_style: EmptyValueStyleConfig::default(),
+                    export_style: ExportStyleConfig::default(),
+                    forbidden_key: ForbiddenKeyConfig::default(),
+                    index_gap: IndexGapConfig::default(),
+                    integer_range: IntegerRangeConfig::default(),
+                    key_before_header: KeyBeforeHeaderConfig::default(),
+                    list_separator: ListSeparatorConfig::default(),
+                    localhost_value: LocalhostValueConfig::default(),
+                    missing_path: MissingPathConfig::default(),
+                    numbered_key: NumberedKeyConfig::default(),
+                    numeric_key_boolean_value: NumericKeyBooleanValueConfig::default(),
+                    pair_list: PairListConfig::default(),
+                    path_separator: PathSeparatorConfig::default(),
+                    port_value: PortValueConfig::default(),
+                    repeated_comment: RepeatedCommentConfig::default(),
+                    required_keys: RequiredKeysConfig::default(),
+                    reserved_identifier: ReservedIdentifierConfig::default(),
+                    separator_consistency: SeparatorConsistencyConfig {
+                        style: Some(SeparatorStyle::None),
+                    },
+                    space_character: SpaceCharacterConfig::default(),
+                    unchanged_example_value: UnchangedExampleValueConfig::default(),
+                    unresolved_template: UnresolvedTemplateConfig::default(),
+                    url_scheme: UrlSchemeConfig::default(),
+                    url_trailing_slash: UrlTrailingSlashConfig::default(),
+                },
+                profiles: BTreeMap::new(),
+                generated: GeneratedConfig::default(),
+            },
+            config
+        );
+    }
+
+    #[test]
+    fn templates_test() {
+        let config = Config::from_content(
+            "templates:\n  LowercaseKey: \"{} is lowercase\"\n",
+            Format::Yaml,
+        )
+        .expect("valid config");
+
+        assert_eq!(
+            Some(&String::from("{} is lowercase")),
+            config.templates.get("LowercaseKey")
+        );
+    }
+
+    #[test]
+    fn severity_test() {
+        let config = Config::from_content("severity:\n  LowercaseKey: error\n", Format::Yaml)
+            .expect("valid config");
+
+        assert_eq!(Severity::Error, config.severity_of("LowercaseKey"));
+        assert_eq!(Severity::Warning, config.severity_of("UnorderedKey"));
+    }
+
+    #[test]
+    fn required_keys_test() {
+        let config = Config::from_content(
+            "checks:\n  RequiredKeys:\n    keys: [APP_ENV]\n    overrides:\n      - files: \".env.production\"\n        keys: [SECRET_KEY]\n",
+            Format::Yaml,
+        )
+        .expect("valid config");
+
+        assert_eq!(
+            RequiredKeysConfig {
+                keys: vec![String::from("APP_ENV")],
+                overrides: vec![RequiredKeysOverride {
+                    files: String::from(".env.production"),
+                    keys: vec![String::from("SECRET_KEY")],
+                }],
+            },
+            config.checks.required_keys
+        );
+        assert!(Config::from_content(
+            "checks:\n  RequiredKeys:\n    overrides:\n      - files: \"[\"\n        keys: []\n",
+            Format::Yaml,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn profiles_test() {
+        let content = "skip: [UnorderedKey]\nprofiles:\n  default:\n    skip: [LowercaseKey]\n  strict:\n    enable: [ShellUnsafeValue]\n    severity:\n      DuplicatedKey: error\n";
+
+        let mut config = Config::from_content(content, Format::Yaml).expect("valid config");
+        config.apply_profile(Some("strict")).expect("known profile");
+        assert_eq!(vec![String::from("UnorderedKey")], config.skip);
+        assert_eq!(vec![String::from("ShellUnsafeValue")], config.enable);
+        assert_eq!(Severity::Error, config.severity_of("DuplicatedKey"));
+
+        let mut config = Config::from_content(content, Format::Yaml).expect("valid config");
+        config.apply_profile(None).expect("default profile");
+        assert_eq!(
+            vec![String::from("UnorderedKey"), String::from("LowercaseKey")],
+            config.skip
+        );
+
+        let mut config = Config::from_content(content, Format::Yaml).expect("valid config");
+        assert_eq!(
+            Err(String::from(
+                "Unknown profile lenient, the available profiles are: default, strict"
+            )),
+            config.apply_profile(Some("lenient"))
+        );
+
+        let mut config = Config::default();
+        assert!(config.apply_profile(None).is_ok());
+        assert_eq!(
+            Err(String::from(
+                "Unknown profile strict, the config has no profiles"
+            )),
+            config.apply_profile(Some("strict"))
+        );
+    }
+
+    #[test]
+    fn priority_test() {
+        let config = Config::default();
+        assert_eq!(Some(1), config.priority_of("LeadingCharacter"));
+        assert_eq!(None, config.priority_of("UnorderedKey"));
+
+        let config = Config::from_content(
+            "short_circuit: true\npriority: [UnorderedKey, LowercaseKey]\n",
+            Format::Yaml,
+        )
+        .expect("valid config");
+        assert!(config.short_circuit);
+        assert_eq!(Some(0), config.priority_of("UnorderedKey"));
+        assert_eq!(None, config.priority_of("LeadingCharacter"));
+    }
+
+    #[test]
+    fn empty_content_test() {
+        assert_eq!(
+            Config::default(),
+            Config::from_content("", Format::Json).expect("valid config")
+        );
+    }
+
+    #[test]
+    fn unknown_field_test() {
+        assert!(Config::from_content("unknown: true\n", Format::Yaml).is_err());
+    }
+
+    #[test]
+    fn unknown_check_option_test() {
+        let content = "checks:\n  CommentedKey:\n    max: 1\n";
+        let error = Config::from_content(content, Format::Yaml).unwrap_err();
+
+        assert!(error.contains("unknown field `max`"));
+    }
+
+    fn schema() -> serde_json::Value {
+        serde_json::from_str(CONFIG_SCHEMA).expect("valid schema")
+    }
+
+    // The fields of a config object are listed by the error of an unknown field
+    fn expected_fields(content: &str) -> Vec<String> {
+        let error = Config::from_content(content, Format::Json).unwrap_err();
+        let (_, expected) = error.split_once("expected").expect("list of fields");
+
+        expected
+            .split('`')
+            .skip(1)
+            .step_by(2)
+            .map(String::from)
+            .collect()
+    }
+
+    fn property_names(object: &serde_json::Value) -> Vec<String> {
+        let mut names: Vec<String> = object["properties"]
+            .as_object()
+            .expect("properties")
+            .keys()
+            .cloned()
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn schema_check_names_test() {
+        let schema = schema();
+        let names: Vec<&str> = schema["definitions"]["checkName"]["enum"]
+            .as_array()
+            .expect("check names")
+            .iter()
+            .filter_map(|name| name.as_str())
+            .collect();
+
+        assert_eq!(checks::available_check_names(), names);
+    }
+
+    #[test]
+    fn schema_fields_test() {
+        let schema = schema();
+        let mut fields = expected_fields(r#"{"unknown": true}"#);
+        fields.sort();
+        assert_eq!(fields, property_names(&schema));
+
+        let checks = &schema["properties"]["checks"];
+        let mut fields = expected_fields(r#"{"checks": {"unknown": {}}}"#);
+        fields.sort();
+        assert_eq!(fields, property_names(checks));
+
+        for (check_name, options) in checks["properties"].as_object().expect("checks") {
+            let content = format!(r#"{{"checks": {{"{}": {{"unknown": 1}}}}}}"#, check_name);
+            let mut fields = expected_fields(&content);
+            fields.sort();
+            assert_eq!(fields, property_names(options), "Check: {}", check_name);
+        }
+    }
+
+    #[test]
+    fn schema_defaults_test() {
+        let schema = schema();
+        let mut checks = serde_json::Map::new();
+        for (check_name, options) in schema["properties"]["checks"]["properties"]
+            .as_object()
+            .expect("checks")
+        {
+            let defaults: serde_json::Map<String, serde_json::Value> = options["properties"]
+                .as_object()
+                .expect("options")
+                .iter()
+                .map(|(name, option)| (name.clone(), option["default"].clone()))
+                .collect();
+            checks.insert(check_name.clone(), defaults.into());
+        }
+
+        let content = serde_json::json!({ "checks": checks }).to_string();
+        assert_eq!(
+            Config::default(),
+            Config::from_content(&content, Format::Json).expect("valid config")
+        );
+    }
+
+    #[test]
+    fn format_from_path_test() {
+        assert_eq!(
+            Format::Yaml,
+            Format::from_path(Path::new(".dotenv-linter.yml"))
+        );
+        assert_eq!(Format::Yaml, Format::from_path(Path::new("config")));
+        assert_eq!(Format::Toml, Format::from_path(Path::new("config.toml")));
+        assert_eq!(Format::Json, Format::from_path(Path::new("config.json")));
+    }
+
+    #[test]
+    fn toml_content_test() {
+        let config = Config::from_content(
+            "skip = [\"UnorderedKey\"]\n\n[checks.SeparatorConsistency]\nstyle = \"none\"\n",
+            Format::Toml,
+        )
+        .expect("valid config");
+
+        assert_eq!(vec![String::from("UnorderedKey")], config.skip);
+        assert_eq!(
+            Some(SeparatorStyle::None),
+            config.checks.separator_consistency.style
+        );
+    }
+
+    #[test]
+    fn json_content_test() {
+        let config = Config::from_content(
+            r#"{"enable": ["SeparatorConsistency"], "checks": {"SeparatorConsistency": {"style": "underscore"}}}"#,
+            Format::Json,
+        )
+        .expect("valid config");
+
+        assert_eq!(vec![String::from("SeparatorConsistency")], config.enable);
+        assert_eq!(
+            Some(SeparatorStyle::Underscore),
+            config.checks.separator_consistency.style
+        );
+        assert!(Config::from_content("{\"skip\": ", Format::Json).is_err());
+    }
+
+    #[test]
+    fn builder_test() {
+        let config = ConfigBuilder::new()
+            .skip("UnorderedKey")
+            .enable("NumberedKey")
+            .severity("DuplicatedKey", Severity::Error)
+            .build()
+            .expect("valid config");
+
+        assert_eq!(vec![String::from("UnorderedKey")], config.skip);
+        assert_eq!(vec![String::from("NumberedKey")], config.enable);
+        assert_eq!(Severity::Error, config.severity_of("DuplicatedKey"));
+
+        assert!(ConfigBuilder::new().skip("UnknownCheck").build().is_err());
+        assert!(ConfigBuilder::new().pattern("[").build().is_err());
+    }
+
+    #[test]
+    fn find_without_file_test() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+
+        assert_eq!(
+            Config::default(),
+            Config::find(dir.path()).expect("default config")
+        );
+
+        dir.close().expect("temp dir deleted");
+    }
+
+    #[test]
+    fn find_in_parent_dir_test() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let subdir = dir.path().join("app");
+        fs::create_dir(&subdir).expect("create subdir");
+        fs::write(
+            dir.path().join(CONFIG_FILE_NAMES[0]),
+            "skip: [UnorderedKey]\n",
+        )
+        .expect("write config");
+
+        let config = Config::find(&subdir).expect("config from parent dir");
+        assert_eq!(vec![String::from("UnorderedKey")], config.skip);
+
+        // The root of the repository stops the search
+        fs::create_dir(subdir.join(".git")).expect("create git dir");
+        assert_eq!(
+            Config::default(),
+            Config::find(&subdir).expect("default config")
+        );
+
+        dir.close().expect("temp dir deleted");
+    }
+
+    #[test]
+    fn find_invalid_file_test() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        fs::write(dir.path().join(CONFIG_FILE_NAMES[0]), "skip: 1\n").expect("write config");
+
+        let error = Config::find(dir.path()).expect_err("invalid config");
+        assert!(error.to_string().starts_with("Invalid config "));
+
+        dir.close().expect("temp dir deleted");
+    }
+}
@@ -0,0 +1,347 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Lines added or changed by a unified diff, by the paths of the new files
+#[derive(Debug, Default, PartialEq)]
+pub struct ChangedLines {
+    files: HashMap<PathBuf, HashSet<usize>>,
+}
+
+impl ChangedLines {
+    /// Parses a unified diff, e.g. the output of `git diff`
+    pub fn parse(diff: &str) -> Self {
+        let mut files: HashMap<PathBuf, HashSet<usize>> = HashMap::new();
+        let mut path: Option<PathBuf> = None;
+        // The line number in the new file and the number of lines left in the current hunk
+        let (mut number, mut old_left, mut new_left) = (0, 0, 0);
+
+        for line in diff.lines() {
+            if old_left == 0 && new_left == 0 {
+                if let Some(new_path) = line.strip_prefix("+++ ") {
+                    path = parse_path(new_path);
+                } else if let Some((start, old_len, new_len)) = parse_hunk_header(line) {
+                    number = start;
+                    old_left = old_len;
+                    new_left = new_len;
+                }
+                continue;
+            }
+
+            match line.chars().next() {
+                Some('+') => {
+                    if let Some(path) = &path {
+                        files.entry(path.clone()).or_default().insert(number);
+                    }
+                    number += 1;
+                    new_left -= 1;
+                }
+                Some('-') => old_left -= 1,
+                // `\ No newline at end of file`
+                Some('\\') => {}
+                _ => {
+                    number += 1;
+                    old_left -= 1;
+                    new_left -= 1;
+                }
+            }
+        }
+
+        Self { files }
+    }
+
+    /// Runs `git diff` against `since` in `current_dir` and parses its output
+    pub fn from_git(current_dir: &Path, since: &str) -> Result<Self, Box<dyn Error>> {
+        let output = Command::new("git")
+            .args([
+                "diff",
+                "--relative",
+                "--no-color",
+                "--no-ext-diff",
+                "--unified=0",
+            ])
+            .arg(since)
+            .current_dir(current_dir)
+            .output()
+            .map_err(|e| format!("Failed to run git diff: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Failed to run git diff {}: {}",
+                since,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )
+            .into());
+        }
+
+        Ok(Self::parse(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    pub fn contains(&self, path: &Path, number: usize) -> bool {
+        self.files
+            .get(path)
+            .is_some_and(|lines| lines.contains(&number))
+    }
+}
+
+/// Returns the path of a `+++ b/path` header, skipping deleted files
+fn parse_path(path: &str) -> Option<PathBuf> {
+    // A path can be followed by a timestamp separated with a tab
+    let path = path.split('\t').next()?.trim_end();
+    if path == "/dev/null" {
+        return None;
+    }
+
+    Some(PathBuf::from(path.strip_prefix("b/").unwrap_or(path)))
+}
+
+/// Parses `@@ -1,2 +3,4 @@` into the start line of the new file and the lengths of the hunk
+fn parse_hunk_header(line: &str) -> Option<(usize, usize, usize)> {
+    let mut ranges = line.strip_prefix("@@ ")?.split(' ');
+    let old = ranges.next()?.strip_prefix('-')?;
+    let new = ranges.next()?.strip_prefix('+')?;
+
+    let parse_range = |range: &str| -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    };
+
+    let (_, old_len) = parse_range(old)?;
+    let (start, new_len) = parse_range(new)?;
+
+    Some((start, old_len, new_len))
+}
+
+/// The number of unchanged lines shown around the changes of a hunk
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Edit {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Returns a unified diff of two versions of a file, which can be applied with `git apply`.
+/// It is empty if the contents are the same
+pub fn unified_diff(path: &Path, old: &str, new: &str) -> String {
+    if old == new {
+        return String::new();
+    }
+
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+    let edits = diff_lines(&old_lines, &new_lines);
+
+    let path = path.to_string_lossy().replace('\\', "/");
+    let mut diff = format!("diff --git a/{0} b/{0}\n--- a/{0}\n+++ b/{0}\n", path);
+
+    // The positions of the edits in the old and the new files
+    let mut positions = Vec::with_capacity(edits.len() + 1);
+    let (mut old_pos, mut new_pos) = (0, 0);
+    for (edit, _) in &edits {
+        positions.push((old_pos, new_pos));
+        match edit {
+            Edit::Equal => {
+                old_pos += 1;
+                new_pos += 1;
+            }
+            Edit::Delete => old_pos += 1,
+            Edit::Insert => new_pos += 1,
+        }
+    }
+    positions.push((old_pos, new_pos));
+
+    let changes: Vec<usize> = (0..edits.len())
+        .filter(|&i| edits[i].0 != Edit::Equal)
+        .collect();
+
+    let mut i = 0;
+    while i < changes.len() {
+        // Changes which are close to each other share a hunk
+        let first = changes[i];
+        let mut last = first;
+        while i + 1 < changes.len() && changes[i + 1] - last <= 2 * CONTEXT_LINES {
+            i += 1;
+            last = changes[i];
+        }
+
+        let start = first.saturating_sub(CONTEXT_LINES);
+        let end = (last + CONTEXT_LINES + 1).min(edits.len());
+        let (old_start, new_start) = positions[start];
+        let (old_end, new_end) = positions[end];
+
+        diff.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_start, old_end - old_start),
+            hunk_range(new_start, new_end - new_start)
+        ));
+
+        for (edit, line) in &edits[start..end] {
+            let prefix = match edit {
+                Edit::Equal => ' ',
+                Edit::Delete => '-',
+                Edit::Insert => '+',
+            };
+            diff.push(prefix);
+            diff.push_str(line);
+            if !line.ends_with('\n') {
+                diff.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+
+        i += 1;
+    }
+
+    diff
+}
+
+// A range of an empty hunk starts at the line before it
+fn hunk_range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, len),
+    }
+}
+
+/// Finds the edits between the lines with the longest common subsequence, env files are small
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(Edit, &'a str)> {
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            edits.push((Edit::Equal, old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            edits.push((Edit::Delete, old[i]));
+            i += 1;
+        } else {
+            edits.push((Edit::Insert, new[j]));
+            j += 1;
+        }
+    }
+
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "diff --git a/.env b/.env
+index 1111111..2222222 100644
+--- a/.env
++++ b/.env
+@@ -1,3 +1,4 @@
+ A=1
+-B=2
++B=3
++C=4
+ D=5
+@@ -10 +11,0 @@
+-X=1
+diff --git a/dir/.env.test b/dir/.env.test
+new file mode 100644
+--- /dev/null
++++ b/dir/.env.test
+@@ -0,0 +1 @@
+++++=1
+\\ No newline at end of file
+";
+
+    #[test]
+    fn parse_test() {
+        let changed = ChangedLines::parse(DIFF);
+
+        assert!(!changed.contains(Path::new(".env"), 1));
+        assert!(changed.contains(Path::new(".env"), 2));
+        assert!(changed.contains(Path::new(".env"), 3));
+        assert!(!changed.contains(Path::new(".env"), 4));
+        assert!(!changed.contains(Path::new(".env"), 11));
+        assert!(changed.contains(Path::new("dir/.env.test"), 1));
+        assert!(!changed.contains(Path::new(".env.local"), 1));
+    }
+
+    #[test]
+    fn parse_hunk_header_test() {
+        assert_eq!(
+            Some((3, 2, 4)),
+            parse_hunk_header("@@ -1,2 +3,4 @@ FOO=BAR")
+        );
+        assert_eq!(Some((5, 1, 1)), parse_hunk_header("@@ -5 +5 @@"));
+        assert_eq!(None, parse_hunk_header("@@@ -1 -1 +1 @@@"));
+    }
+
+    #[test]
+    fn unified_diff_test() {
+        let old = "a=1\nB=2\nC=3\nD=4\nE=5\nF=6\nG=7\nH=8\nI=9\nj=10\n";
+        let new = "A=1\nB=2\nC=3\nD=4\nE=5\nF=6\nG=7\nH=8\nI=9\nJ=10\n";
+        let expected = "diff --git a/.env b/.env
+--- a/.env
++++ b/.env
+@@ -1,4 +1,4 @@
+-a=1
++A=1
+ B=2
+ C=3
+ D=4
+@@ -7,4 +7,4 @@
+ G=7
+ H=8
+ I=9
+-j=10
++J=10
+";
+
+        assert_eq!(expected, unified_diff(Path::new(".env"), old, new));
+    }
+
+    #[test]
+    fn unified_diff_without_newline_test() {
+        let expected = "diff --git a/.env b/.env
+--- a/.env
++++ b/.env
+@@ -1,2 +1 @@
+ A=1
+-A=1
+\\ No newline at end of file
+";
+
+        assert_eq!(
+            expected,
+            unified_diff(Path::new(".env"), "A=1\nA=1", "A=1\n")
+        );
+        assert_eq!("", unified_diff(Path::new(".env"), "A=1\n", "A=1\n"));
+    }
+
+    #[test]
+    fn unified_diff_changed_lines_test() {
+        let diff = unified_diff(Path::new(".env"), "A=1\nb=2\n", "A=1\nB=2\nC=3\n");
+        let changed = ChangedLines::parse(&diff);
+
+        assert!(!changed.contains(Path::new(".env"), 1));
+        assert!(changed.contains(Path::new(".env"), 2));
+        assert!(changed.contains(Path::new(".env"), 3));
+    }
+
+    #[test]
+    fn empty_diff_test() {
+        assert_eq!(ChangedLines::default(), ChangedLines::parse(""));
+    }
+}
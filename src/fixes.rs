@@ -1,12 +1,36 @@
+use crate::checks;
 use crate::common::*;
+use crate::config::Config;
 
+mod comment_spacing;
+mod comment_style;
+mod duplicated_delimiter;
+mod duplicated_key;
+mod empty_value_style;
 mod ending_blank_line;
+mod export_style;
+mod invisible_character;
+mod key_whitespace;
 mod key_without_value;
 mod lowercase_key;
+mod padded_number;
 mod quote_character;
+mod redundant_quotes;
+mod smart_quote;
 mod space_character;
+mod trailing_comma;
 mod trailing_whitespace;
+mod whitespace_report;
+mod whitespace_value;
 
+/// Fixes a single line of a warning, like a check runs on a single line. It returns whether
+/// the line was changed
+pub(crate) trait Fixer {
+    fn fix(&self, line: &mut LineEntry) -> bool;
+}
+
+/// Fixes the warnings of the check with the same name. A fixer changes only the lines of its
+/// warnings, and the fixed lines should not trigger the check again
 trait Fix {
     fn name(&self) -> &str;
 
@@ -18,7 +42,7 @@ trait Fix {
         let mut count: usize = 0;
         for warning in warnings {
             let line = lines.get_mut(warning.line_number() - 1)?;
-            if self.fix_line(line).is_some() {
+            if self.fix(line) {
                 warning.mark_as_fixed();
                 count += 1;
             }
@@ -32,24 +56,64 @@ trait Fix {
     }
 }
 
-// TODO: skip fixes (like checks)
-// The fix order is matter
+// The fixers of single lines implement `fix_line`, the fixers of whole files change nothing here.
+// Some fixers rebuild a line which is already fixed, so the line is compared with the original
+impl<T: Fix + ?Sized> Fixer for T {
+    fn fix(&self, line: &mut LineEntry) -> bool {
+        let original = line.raw_string.clone();
+        self.fix_line(line).is_some() && line.raw_string != original
+    }
+}
+
+// The fixers get only the warnings of the checks which were run, so the skipped checks are not
+// fixed either. The fix order is matter
 fn fixlist() -> Vec<Box<dyn Fix>> {
     vec![
         // At first we run the fixers that handle a single line entry (they use default
-        // implementation of the fix_warnings() function)
+        // implementation of the fix_warnings() function). Invisible characters are removed
+        // first, because they can prevent other fixers from parsing the line.
+        Box::new(invisible_character::InvisibleCharacterFixer::default()),
+        Box::new(comment_style::CommentStyleFixer::default()),
+        Box::new(key_whitespace::KeyWhitespaceFixer::default()),
+        Box::new(export_style::ExportStyleFixer::default()),
         Box::new(key_without_value::KeyWithoutValueFixer::default()),
+        Box::new(duplicated_delimiter::DuplicatedDelimiterFixer::default()),
         Box::new(lowercase_key::LowercaseKeyFixer::default()),
         Box::new(space_character::SpaceCharacterFixer::default()),
         Box::new(trailing_whitespace::TrailingWhitespaceFixer::default()),
+        Box::new(trailing_comma::TrailingCommaFixer::default()),
+        Box::new(whitespace_report::WhitespaceReportFixer::default()),
+        Box::new(whitespace_value::WhitespaceValueFixer::default()),
+        Box::new(empty_value_style::EmptyValueStyleFixer::default()),
+        Box::new(padded_number::PaddedNumberFixer::default()),
+        Box::new(smart_quote::SmartQuoteFixer::default()),
+        Box::new(redundant_quotes::RedundantQuotesFixer::default()),
         Box::new(quote_character::QuoteCharacterFixer::default()),
         // Then we should run the fixers that handle the line entry collection at whole.
         // And at the end we should run the fixer for ExtraBlankLine check (because the previous
         // fixers can create additional extra blank lines).
+        Box::new(comment_spacing::CommentSpacingFixer::default()),
         Box::new(ending_blank_line::EndingBlankLineFixer::default()),
+        // DuplicatedKey removes lines before the lines of its warnings, so it runs last to keep
+        // the line numbers of the warnings of the other fixers valid
+        Box::new(duplicated_key::DuplicatedKeyFixer::default()),
     ]
 }
 
+/// Returns the fixer of the check with the name, if the check is fixable
+fn fixer(check_name: &str) -> Option<Box<dyn Fix>> {
+    fixlist()
+        .into_iter()
+        .find(|fixer| fixer.name() == check_name)
+}
+
+pub fn available_fix_names() -> Vec<String> {
+    fixlist()
+        .iter()
+        .map(|fixer| fixer.name().to_string())
+        .collect()
+}
+
 pub fn run(warnings: &mut [Warning], lines: &mut Vec<LineEntry>) -> usize {
     if warnings.is_empty() {
         return 0;
@@ -79,9 +143,73 @@ pub fn run(warnings: &mut [Warning], lines: &mut Vec<LineEntry>) -> usize {
     count
 }
 
+// The passes of `run_until_fixed`, to stop fixers which would undo each other
+const MAX_PASSES: usize = 10;
+
+/// Fixes the warnings like `run`, then checks the fixed lines again and fixes the warnings
+/// which appeared after the fixes (e.g. a line which became a comment needs a blank line
+/// before it), so fixing the lines again changes nothing. The warnings fixed in the later
+/// passes are added to `warnings`, the warnings which don't pass `retain` are not fixed
+pub fn run_until_fixed(
+    warnings: &mut Vec<Warning>,
+    lines: &mut Vec<LineEntry>,
+    config: &Config,
+    retain: impl Fn(&Warning) -> bool,
+) -> usize {
+    let mut count = run(warnings, lines);
+    let mut pass_count = count;
+
+    for _ in 1..MAX_PASSES {
+        if pass_count == 0 {
+            break;
+        }
+
+        let mut pass_warnings = checks::run(lines, config);
+        pass_warnings.retain(&retain);
+        pass_count = run(&mut pass_warnings, lines);
+        count += pass_count;
+        warnings.extend(pass_warnings.into_iter().filter(|w| w.is_fixed));
+    }
+
+    count
+}
+
+/// Returns the lines which would replace the line of the warning after its fix,
+/// the lines themselves are not changed. Warnings which can't be fixed have no suggestion
+pub fn suggest(warning: &Warning, lines: &[LineEntry]) -> Vec<String> {
+    let fixer = match fixer(&warning.check_name) {
+        Some(fixer) => fixer,
+        None => return Vec::new(),
+    };
+
+    let mut fixed_lines = lines.to_vec();
+    let mut warning = warning.clone();
+    if fixer.fix_warnings(vec![&mut warning], &mut fixed_lines) != Some(1) {
+        return Vec::new();
+    }
+
+    // A line is removed by DuplicatedKey before the line of the warning, which stays as is
+    if fixed_lines.len() < lines.len() {
+        return Vec::new();
+    }
+
+    // Some fixers insert lines before or after the line of the warning
+    let start = warning.line_number() - 1;
+    let end = start + fixed_lines.len().saturating_sub(lines.len());
+    fixed_lines
+        .get(start..=end)
+        .unwrap_or_default()
+        .iter()
+        .map(|line| line.raw_string.trim_end_matches(LF).to_string())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::checks::{self, ExportStyle};
+    use crate::config::Config;
+    use crate::fs_utils::get_content;
     use std::path::PathBuf;
 
     fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
@@ -151,6 +279,66 @@ mod tests {
         assert!(!warnings[0].is_fixed);
     }
 
+    #[test]
+    fn fixer_test() {
+        let lowercase_key = fixer("LowercaseKey").expect("LowercaseKey fixer");
+        let mut line = line_entry(1, 1, "foo=bar");
+
+        assert!(lowercase_key.fix(&mut line));
+        assert_eq!("FOO=bar", line.raw_string);
+        // The line is rebuilt the same way, it is not changed
+        assert!(!lowercase_key.fix(&mut line));
+        // The fixers of whole files don't fix single lines
+        assert!(!fixer("EndingBlankLine")
+            .expect("EndingBlankLine fixer")
+            .fix(&mut line));
+        assert!(fixer("UnorderedKey").is_none());
+    }
+
+    #[test]
+    fn suggest_test() {
+        let lines = vec![
+            line_entry(1, 3, "A=B"),
+            line_entry(2, 3, "c=d"),
+            blank_line_entry(3, 3),
+        ];
+        let warning = Warning::new(
+            lines[1].clone(),
+            "LowercaseKey",
+            String::from("The c key should be in uppercase"),
+        );
+
+        assert_eq!(vec![String::from("C=d")], suggest(&warning, &lines));
+        assert_eq!("c=d", lines[1].raw_string);
+    }
+
+    #[test]
+    fn suggest_with_inserted_line_test() {
+        let lines = vec![line_entry(1, 2, "A=B"), line_entry(2, 2, "C=D")];
+        let warning = Warning::new(
+            lines[1].clone(),
+            "EndingBlankLine",
+            String::from("No blank line at the end of the file"),
+        );
+
+        assert_eq!(
+            vec![String::from("C=D"), String::new()],
+            suggest(&warning, &lines)
+        );
+    }
+
+    #[test]
+    fn suggest_unfixable_warning_test() {
+        let lines = vec![line_entry(1, 2, "UNFIXABLE-"), blank_line_entry(2, 2)];
+        let warning = Warning::new(
+            lines[0].clone(),
+            "Unfixable",
+            String::from("The UNFIXABLE- key is not fixable"),
+        );
+
+        assert!(suggest(&warning, &lines).is_empty());
+    }
+
     #[test]
     fn run_when_lines_do_not_fit_numbers_test() {
         let mut lines = vec![
@@ -186,6 +374,7 @@ mod tests {
 
         fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
             if line.raw_string.chars().count() > 5 {
+                line.raw_string = line.raw_string.to_uppercase();
                 Some(())
             } else {
                 None
@@ -218,4 +407,75 @@ mod tests {
 
         assert!(!warning.is_fixed)
     }
+
+    fn line_entries(content: &str) -> Vec<LineEntry> {
+        let (fe, strs) = FileEntry::from_content(PathBuf::from(".env"), content).unwrap();
+        strs.into_iter()
+            .enumerate()
+            .map(|(i, str)| LineEntry {
+                number: i + 1,
+                file: fe.clone(),
+                raw_string: str,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fixed_lines_pass_their_checks_test() {
+        let asserts = vec![
+            ("InvisibleCharacter", "A=\u{200B}1\n"),
+            ("CommentStyle", "# a\n; b\nA=1\n"),
+            ("KeyWhitespace", "export  A=1\n"),
+            ("ExportStyle", "export A=1\nB=2\n"),
+            ("KeyWithoutValue", "A\n"),
+            ("DuplicatedDelimiter", "A==1\n"),
+            ("LowercaseKey", "a=1\n"),
+            ("SpaceCharacter", "A = 1\n"),
+            ("TrailingWhitespace", "A=1  \n"),
+            ("TrailingComma", "A=1,2,\n"),
+            ("WhitespaceReport", " A = 1 \n"),
+            ("WhitespaceValue", "A=\"   \"\n"),
+            ("PaddedNumber", "A=\" 1 \" # b\n"),
+            ("EmptyValueStyle", "A=\nB=\nC=''\n"),
+            ("SmartQuote", "A=“a b”\n"),
+            ("RedundantQuotes", "A=\"1\"\n"),
+            ("QuoteCharacter", "A='1'\n"),
+            (
+                "CommentSpacing",
+                "# a\nA=1\n\n# b\nB=2\n\n# c\nC=3\n# d\nD=4\n",
+            ),
+            ("EndingBlankLine", "A=1"),
+            ("DuplicatedKey", "A=1\nB=2\nA='1'\nA=1\n"),
+        ];
+        assert_eq!(available_fix_names().len(), asserts.len());
+
+        for (check_name, content) in asserts {
+            let mut config = Config {
+                enable: vec![String::from(check_name)],
+                ..Config::default()
+            };
+            // ExportStyle reports nothing with its default policy
+            config.checks.export_style.style = ExportStyle::Forbid;
+            let mut lines = line_entries(content);
+            let mut warnings: Vec<Warning> = checks::run(&lines, &config)
+                .into_iter()
+                .filter(|w| w.check_name == check_name)
+                .collect();
+            assert!(!warnings.is_empty(), "No warnings of {}", check_name);
+
+            run(&mut warnings, &mut lines);
+            let fixed_content = get_content(&lines, LF);
+            let lines = line_entries(&fixed_content);
+            let remaining = checks::run(&lines, &config)
+                .into_iter()
+                .filter(|w| w.check_name == check_name)
+                .count();
+
+            assert_eq!(
+                0, remaining,
+                "Fixed content of {}: {:?}",
+                check_name, fixed_content
+            );
+        }
+    }
 }
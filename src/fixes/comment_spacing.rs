@@ -0,0 +1,109 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct CommentSpacingFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for CommentSpacingFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "CommentSpacing",
+        }
+    }
+}
+
+impl Fix for CommentSpacingFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn fix_warnings(
+        &self,
+        mut warnings: Vec<&mut Warning>,
+        lines: &mut Vec<LineEntry>,
+    ) -> Option<usize> {
+        let file = lines.first()?.file.clone();
+
+        // Lines are inserted from the end, so the numbers of the remaining warnings stay valid
+        warnings.sort_by_key(|w| std::cmp::Reverse(w.line_number()));
+        for warning in &mut warnings {
+            let index = warning.line_number() - 1;
+            if index == 0 || index >= lines.len() {
+                return None;
+            }
+
+            lines.insert(
+                index,
+                LineEntry {
+                    number: index + 1,
+                    file: file.clone(),
+                    raw_string: String::new(),
+                },
+            );
+            warning.mark_as_fixed();
+        }
+
+        let total_lines = lines.len();
+        for (index, line) in lines.iter_mut().enumerate() {
+            line.number = index + 1;
+            line.file.total_lines = total_lines;
+        }
+
+        Some(warnings.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_warnings_test() {
+        let fixer = CommentSpacingFixer::default();
+        let mut lines = vec![
+            line_entry(1, 6, "A=B"),
+            line_entry(2, 6, "# C"),
+            line_entry(3, 6, "C=D"),
+            line_entry(4, 6, "# E"),
+            line_entry(5, 6, "E=F"),
+            line_entry(6, 6, "\n"),
+        ];
+        let mut warnings = [
+            Warning::new(lines[1].clone(), "CommentSpacing", String::from("")),
+            Warning::new(lines[3].clone(), "CommentSpacing", String::from("")),
+        ];
+
+        assert_eq!(
+            Some(2),
+            fixer.fix_warnings(warnings.iter_mut().collect(), &mut lines)
+        );
+        assert!(warnings.iter().all(|w| w.is_fixed));
+        assert_eq!(
+            vec![
+                line_entry(1, 8, "A=B"),
+                line_entry(2, 8, ""),
+                line_entry(3, 8, "# C"),
+                line_entry(4, 8, "C=D"),
+                line_entry(5, 8, ""),
+                line_entry(6, 8, "# E"),
+                line_entry(7, 8, "E=F"),
+                line_entry(8, 8, "\n"),
+            ],
+            lines
+        );
+    }
+}
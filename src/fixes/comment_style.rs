@@ -0,0 +1,64 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct CommentStyleFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for CommentStyleFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "CommentStyle",
+        }
+    }
+}
+
+impl Fix for CommentStyleFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    // Warnings are only reported for the comments of the unexpected style, so it is swapped
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let index = line.raw_string.find(['#', ';'])?;
+        let prefix = if line.raw_string[index..].starts_with('#') {
+            ";"
+        } else {
+            "#"
+        };
+        line.raw_string.replace_range(index..=index, prefix);
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = CommentStyleFixer::default();
+
+        let mut line = line_entry("  ; Cache; Redis");
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("  # Cache; Redis", line.raw_string);
+
+        let mut line = line_entry("# Cache");
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("; Cache", line.raw_string);
+    }
+}
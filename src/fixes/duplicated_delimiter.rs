@@ -0,0 +1,56 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct DuplicatedDelimiterFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for DuplicatedDelimiterFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "DuplicatedDelimiter",
+        }
+    }
+}
+
+impl Fix for DuplicatedDelimiterFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let key = line.get_key()?;
+        let value = line.get_value()?;
+        line.raw_string = format!(
+            "{}{}={}",
+            line.export_prefix(),
+            key,
+            value.trim_start_matches('=')
+        );
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = DuplicatedDelimiterFixer::default();
+        let mut line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from("export FOO===dGVzdA=="),
+        };
+
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("export FOO=dGVzdA==", line.raw_string);
+    }
+}
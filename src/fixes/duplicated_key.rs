@@ -0,0 +1,193 @@
+use super::Fix;
+use crate::checks::normalized_value;
+use crate::common::*;
+use std::collections::BTreeSet;
+
+pub(crate) struct DuplicatedKeyFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for DuplicatedKeyFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "DuplicatedKey",
+        }
+    }
+}
+
+/// Returns the index of the earlier definition of the key of the line, if all the earlier
+/// definitions have the same value, so removing one of them doesn't change the loaded value
+fn find_redundant_definition(lines: &[LineEntry], index: usize) -> Option<usize> {
+    let line = lines.get(index)?;
+    let key = line.get_key()?;
+    let value = normalized_value(line);
+
+    let definitions: Vec<usize> = (0..index)
+        .filter(|&i| lines[i].get_key().as_ref() == Some(&key))
+        .collect();
+    if definitions
+        .iter()
+        .any(|&i| normalized_value(&lines[i]) != value)
+    {
+        return None;
+    }
+
+    definitions.last().copied()
+}
+
+impl Fix for DuplicatedKeyFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn fix_warnings(
+        &self,
+        warnings: Vec<&mut Warning>,
+        lines: &mut Vec<LineEntry>,
+    ) -> Option<usize> {
+        // Duplicates with a different value are not fixed, the right value can't be chosen.
+        // The fixed warnings are reported on the removed lines, the later definitions stay
+        let mut removed = BTreeSet::new();
+        let mut count = 0;
+        for warning in warnings {
+            let index = warning.line_number() - 1;
+            if let Some(redundant) = find_redundant_definition(lines, index) {
+                removed.insert(redundant);
+                warning.set_line(lines[redundant].clone());
+                warning.mark_as_fixed();
+                count += 1;
+            }
+        }
+
+        // Lines are removed from the end, so the indexes of the remaining lines stay valid
+        for index in removed.into_iter().rev() {
+            lines.remove(index);
+        }
+
+        let total_lines = lines.len();
+        for (index, line) in lines.iter_mut().enumerate() {
+            line.number = index + 1;
+            line.file.total_lines = total_lines;
+        }
+
+        Some(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_warnings_test() {
+        let fixer = DuplicatedKeyFixer::default();
+        let mut lines = vec![
+            line_entry(1, 5, "FOO=bar"),
+            line_entry(2, 5, "BAR=baz"),
+            line_entry(3, 5, "FOO='bar' # the same"),
+            line_entry(4, 5, "FOO=bar"),
+            line_entry(5, 5, "\n"),
+        ];
+        let mut warnings = [
+            Warning::new(
+                lines[2].clone(),
+                "DuplicatedKey",
+                String::from("The FOO key is duplicated with the same value"),
+            ),
+            Warning::new(
+                lines[3].clone(),
+                "DuplicatedKey",
+                String::from("The FOO key is duplicated with the same value"),
+            ),
+        ];
+
+        assert_eq!(
+            Some(2),
+            fixer.fix_warnings(warnings.iter_mut().collect(), &mut lines)
+        );
+        assert_eq!(
+            vec![
+                line_entry(1, 3, "BAR=baz"),
+                line_entry(2, 3, "FOO=bar"),
+                line_entry(3, 3, "\n")
+            ],
+            lines
+        );
+        assert!(warnings.iter().all(|w| w.is_fixed));
+        // The warnings are moved to the removed lines
+        assert_eq!(
+            vec![1, 3],
+            warnings.iter().map(|w| w.line_number()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn three_identical_definitions_test() {
+        let fixer = DuplicatedKeyFixer::default();
+        let mut lines = vec![
+            line_entry(1, 3, "FOO=bar"),
+            line_entry(2, 3, "FOO=bar"),
+            line_entry(3, 3, "FOO=bar"),
+        ];
+        let mut warnings: Vec<Warning> = lines[1..]
+            .iter()
+            .map(|line| {
+                Warning::new(
+                    line.clone(),
+                    "DuplicatedKey",
+                    String::from("The FOO key is duplicated with the same value"),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            Some(2),
+            fixer.fix_warnings(warnings.iter_mut().collect(), &mut lines)
+        );
+        assert_eq!(vec![line_entry(1, 1, "FOO=bar")], lines);
+        assert_eq!(
+            vec![1, 2],
+            warnings.iter().map(|w| w.line_number()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn different_value_test() {
+        let fixer = DuplicatedKeyFixer::default();
+        let mut lines = vec![
+            line_entry(1, 3, "FOO=bar"),
+            line_entry(2, 3, "FOO=baz"),
+            line_entry(3, 3, "FOO=bar"),
+        ];
+        let mut warnings: Vec<Warning> = lines[1..]
+            .iter()
+            .map(|line| {
+                Warning::new(
+                    line.clone(),
+                    "DuplicatedKey",
+                    String::from("The FOO key is duplicated with a different value"),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            Some(0),
+            fixer.fix_warnings(warnings.iter_mut().collect(), &mut lines)
+        );
+        assert_eq!(3, lines.len());
+        assert!(warnings.iter().all(|w| !w.is_fixed));
+    }
+}
@@ -0,0 +1,84 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct EmptyValueStyleFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for EmptyValueStyleFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "EmptyValueStyle",
+        }
+    }
+}
+
+impl Fix for EmptyValueStyleFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    // Only the values of the other style are reported, so a quoted value loses its quotes
+    // and a bare value gets double quotes
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let parsed = line.parse();
+        let value = match parsed.quote {
+            Some(_) => "",
+            None => "\"\"",
+        };
+        let comment = parsed
+            .comment
+            .map(|comment| format!(" {}", comment))
+            .unwrap_or_default();
+
+        line.raw_string = format!(
+            "{}{}={}{}",
+            line.export_prefix(),
+            parsed.key?,
+            value,
+            comment
+        );
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_quoted_value_test() {
+        let fixer = EmptyValueStyleFixer::default();
+
+        let mut line = line_entry("SECRET_KEY=\"\" # later");
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("SECRET_KEY= # later", line.raw_string);
+
+        let mut line = line_entry("export TOKEN=''");
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("export TOKEN=", line.raw_string);
+    }
+
+    #[test]
+    fn fix_bare_value_test() {
+        let fixer = EmptyValueStyleFixer::default();
+
+        let mut line = line_entry("API_KEY=");
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("API_KEY=\"\"", line.raw_string);
+    }
+}
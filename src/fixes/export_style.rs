@@ -0,0 +1,71 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct ExportStyleFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for ExportStyleFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "ExportStyle",
+        }
+    }
+}
+
+impl Fix for ExportStyleFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    // Only the lines which break the policy are reported, so the prefix of an exported key
+    // is removed and an unexported key gets it. The whitespace at the start of the line is kept
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let trimmed = line.raw_string.trim_start();
+        let indent = &line.raw_string[..line.raw_string.len() - trimmed.len()];
+
+        let rest = if line.is_exported() {
+            String::from(trimmed.strip_prefix(EXPORT_PREFIX)?.trim_start())
+        } else {
+            format!("{}{}", EXPORT_PREFIX, trimmed)
+        };
+        line.raw_string = format!("{}{}", indent, rest);
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = ExportStyleFixer::default();
+        let asserts = vec![
+            ("export FOO=BAR", "FOO=BAR"),
+            ("export  FOO=BAR # comment", "FOO=BAR # comment"),
+            ("FOO=BAR", "export FOO=BAR"),
+            (" FOO=BAR", " export FOO=BAR"),
+        ];
+
+        for (str, expected) in asserts {
+            let mut line = line_entry(str);
+            assert_eq!(Some(()), fixer.fix_line(&mut line));
+            assert_eq!(expected, line.raw_string, "Line: {}", str);
+        }
+    }
+}
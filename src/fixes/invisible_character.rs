@@ -0,0 +1,48 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct InvisibleCharacterFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for InvisibleCharacterFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "InvisibleCharacter",
+        }
+    }
+}
+
+impl Fix for InvisibleCharacterFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        line.raw_string.retain(|c| !is_invisible_char(c));
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = InvisibleCharacterFixer::default();
+        let mut line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from("\u{FEFF}FOO\u{200B}=b\u{00A0}ar"),
+        };
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("FOO=bar", line.raw_string);
+    }
+}
@@ -0,0 +1,75 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct KeyWhitespaceFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for KeyWhitespaceFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "KeyWhitespace",
+        }
+    }
+}
+
+impl Fix for KeyWhitespaceFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    // The whitespace at the start of the line is kept, it is reported by LeadingCharacter
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let start =
+            line.raw_string.len() - line.raw_string.trim_start().len() + EXPORT_PREFIX.len();
+        let rest = line.raw_string.get(start..)?;
+        let whitespace_len = rest.len() - rest.trim_start().len();
+        if !line.is_exported() || whitespace_len == 0 {
+            return None;
+        }
+        line.raw_string
+            .replace_range(start..start + whitespace_len, "");
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = KeyWhitespaceFixer::default();
+
+        for (str, expected) in &[
+            ("export  FOO=BAR", "export FOO=BAR"),
+            ("export \t FOO=BAR # comment", "export FOO=BAR # comment"),
+        ] {
+            let mut line = line_entry(str);
+            assert_eq!(Some(()), fixer.fix_line(&mut line));
+            assert_eq!(*expected, line.raw_string);
+        }
+    }
+
+    #[test]
+    fn fix_line_without_whitespace_test() {
+        let fixer = KeyWhitespaceFixer::default();
+        let mut line = line_entry("export FOO=BAR");
+
+        assert_eq!(None, fixer.fix_line(&mut line));
+    }
+}
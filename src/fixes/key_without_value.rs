@@ -19,6 +19,11 @@ impl Fix for KeyWithoutValueFixer<'_> {
     }
 
     fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        // A `;` comment could already be turned into a `#` one by the CommentStyle fixer
+        if line.is_comment() {
+            return None;
+        }
+
         line.raw_string.push('=');
 
         Some(())
@@ -21,7 +21,7 @@ impl Fix for LowercaseKeyFixer<'_> {
     fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
         let key = line.get_key()?;
         let key = key.to_uppercase();
-        line.raw_string = format!("{}={}", key, line.get_value()?);
+        line.raw_string = format!("{}{}={}", line.export_prefix(), key, line.get_value()?);
 
         Some(())
     }
@@ -48,6 +48,38 @@ mod tests {
         assert_eq!("FOO=BAR", line.raw_string);
     }
 
+    #[test]
+    fn fix_line_with_digits_test() {
+        let fixer = LowercaseKeyFixer::default();
+        let mut line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from("log4j_2nd_LEVEL=info"),
+        };
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("LOG4J_2ND_LEVEL=info", line.raw_string);
+    }
+
+    #[test]
+    fn fix_exported_line_test() {
+        let fixer = LowercaseKeyFixer::default();
+        let mut line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from("export foO=BAR"),
+        };
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("export FOO=BAR", line.raw_string);
+    }
+
     #[test]
     fn fix_warnings_test() {
         let fixer = LowercaseKeyFixer::default();
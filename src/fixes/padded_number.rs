@@ -0,0 +1,87 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct PaddedNumberFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for PaddedNumberFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "PaddedNumber",
+        }
+    }
+}
+
+impl Fix for PaddedNumberFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    // A number doesn't need quotes, but they are kept when a comment follows the closing quote
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let parsed = line.parse();
+        let quote = parsed.quote?;
+        let value = &parsed.value?.trim()[1..];
+        let end = find_closing_quote(value, quote)?;
+        let number = value[..end].trim();
+        let rest = &value[end + 1..];
+
+        let key = parsed.key?;
+        line.raw_string = if rest.is_empty() {
+            format!("{}{}={}", line.export_prefix(), key, number)
+        } else {
+            format!(
+                "{}{}={}{}{}{}",
+                line.export_prefix(),
+                key,
+                quote.as_char(),
+                number,
+                quote.as_char(),
+                rest
+            )
+        };
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = PaddedNumberFixer::default();
+
+        let mut line = line_entry("PORT=\" 8080 \"");
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("PORT=8080", line.raw_string);
+
+        let mut line = line_entry("export RATIO=' 0.5'");
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("export RATIO=0.5", line.raw_string);
+    }
+
+    #[test]
+    fn fix_line_with_comment_test() {
+        let fixer = PaddedNumberFixer::default();
+        let mut line = line_entry("PORT=\" 8080 \" # default");
+
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("PORT=\"8080\" # default", line.raw_string);
+    }
+}
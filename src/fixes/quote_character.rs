@@ -22,7 +22,7 @@ impl Fix for QuoteCharacterFixer<'_> {
         let value = line.get_value()?;
         let pure_val = value.replace("'", "").replace("\"", "");
 
-        line.raw_string = format!("{}={}", line.get_key()?, pure_val);
+        line.raw_string = format!("{}{}={}", line.export_prefix(), line.get_key()?, pure_val);
 
         Some(())
     }
@@ -0,0 +1,75 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct RedundantQuotesFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for RedundantQuotesFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "RedundantQuotes",
+        }
+    }
+}
+
+impl Fix for RedundantQuotesFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let value = line.get_value()?;
+        let pure_val = unquote(&value).unwrap_or(&value);
+
+        line.raw_string = format!("{}{}={}", line.export_prefix(), line.get_key()?, pure_val);
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = RedundantQuotesFixer::default();
+        let mut line = line_entry(1, 1, "FOO=\"bar\"");
+
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("FOO=bar", line.raw_string);
+    }
+
+    #[test]
+    fn fix_warnings_test() {
+        let fixer = RedundantQuotesFixer::default();
+        let mut lines = vec![
+            line_entry(1, 3, "FOO='bar'"),
+            line_entry(2, 3, "Z=Y"),
+            line_entry(3, 3, "\n"),
+        ];
+        let mut warning = Warning::new(
+            lines[0].clone(),
+            "RedundantQuotes",
+            String::from("The FOO key has a value with redundant quotes"),
+        );
+
+        assert_eq!(Some(1), fixer.fix_warnings(vec![&mut warning], &mut lines));
+        assert_eq!("FOO=bar", lines[0].raw_string);
+        assert!(warning.is_fixed);
+    }
+}
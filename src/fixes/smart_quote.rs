@@ -0,0 +1,74 @@
+use super::Fix;
+use crate::checks::{smart_quoted_value, straighten_quotes};
+use crate::common::*;
+
+pub(crate) struct SmartQuoteFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for SmartQuoteFixer<'_> {
+    fn default() -> Self {
+        Self { name: "SmartQuote" }
+    }
+}
+
+impl Fix for SmartQuoteFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let (_, value) = smart_quoted_value(line)?;
+        let fixed = straighten_quotes(value)?;
+
+        // The value is replaced in place, the key and a trailing comment are kept
+        let raw = &line.raw_string;
+        let parsed = line.parse();
+        let start = parsed.value_start(raw)? + parsed.value?.find(value)?;
+        line.raw_string = format!("{}{}{}", &raw[..start], fixed, &raw[start + value.len()..]);
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = SmartQuoteFixer::default();
+        let asserts = vec![
+            (
+                "GREETING=“Hello world” # a comment",
+                "GREETING=\"Hello world\" # a comment",
+            ),
+            ("export NAME = ‘Bob’", "export NAME = 'Bob'"),
+            ("TITLE=say “hi”", "TITLE=say \"hi\""),
+            ("NAME=‘Bob’ # it’s", "NAME='Bob' # it’s"),
+            (
+                "TITLE=“Hello # world” # a comment",
+                "TITLE=\"Hello # world\" # a comment",
+            ),
+        ];
+
+        for (str, expected) in asserts {
+            let mut line = line_entry(str);
+            assert_eq!(Some(()), fixer.fix_line(&mut line));
+            assert_eq!(expected, line.raw_string);
+        }
+    }
+}
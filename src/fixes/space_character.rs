@@ -1,5 +1,6 @@
 use super::Fix;
 use crate::common::*;
+use std::collections::HashSet;
 
 pub(crate) struct SpaceCharacterFixer<'a> {
     name: &'a str,
@@ -13,15 +14,94 @@ impl Default for SpaceCharacterFixer<'_> {
     }
 }
 
+impl SpaceCharacterFixer<'_> {
+    /// Pads the key of the line, so that the equal sign is at the column
+    fn align_line(&self, line: &mut LineEntry, column: usize) -> Option<()> {
+        let key = line.get_key()?;
+        let value = line.get_value()?;
+        let prefix = line.export_prefix();
+
+        let width = column.checked_sub(prefix.len() + 1)?;
+        let key = key.trim_end();
+        if key.chars().count() > width {
+            return None;
+        }
+
+        line.raw_string = format!(
+            "{}{:<width$}={}",
+            prefix,
+            key,
+            value.trim_start(),
+            width = width
+        );
+
+        Some(())
+    }
+}
+
+/// Returns the column of the aligned keys in the group (separated by blank lines) of a line.
+/// Only the padded keys without warnings are aligned, so in the default mode there are none
+fn aligned_column(lines: &[LineEntry], index: usize, warned: &HashSet<usize>) -> Option<usize> {
+    let start = lines[..index]
+        .iter()
+        .rposition(|line| line.is_empty())
+        .map_or(0, |i| i + 1);
+    let end = lines[index..]
+        .iter()
+        .position(|line| line.is_empty())
+        .map_or(lines.len(), |i| index + i);
+
+    lines[start..end]
+        .iter()
+        .filter(|line| !line.is_comment() && !warned.contains(&line.number))
+        .find(|line| {
+            let parsed = line.parse();
+            parsed.key.is_some_and(|key| key.ends_with(' '))
+                && parsed.value.is_some_and(|value| !value.starts_with(' '))
+        })
+        .and_then(|line| line.equal_sign_column())
+}
+
 impl Fix for SpaceCharacterFixer<'_> {
     fn name(&self) -> &str {
         self.name
     }
 
+    fn fix_warnings(
+        &self,
+        warnings: Vec<&mut Warning>,
+        lines: &mut Vec<LineEntry>,
+    ) -> Option<usize> {
+        let warned: HashSet<usize> = warnings.iter().map(|w| w.line_number()).collect();
+        let mut count = 0;
+
+        for warning in warnings {
+            let index = warning.line_number() - 1;
+            let column = aligned_column(lines, index, &warned);
+            let line = lines.get_mut(index)?;
+
+            let is_fixed = match column {
+                Some(column) => self.align_line(line, column),
+                None => self.fix_line(line),
+            };
+            if is_fixed.is_some() {
+                warning.mark_as_fixed();
+                count += 1;
+            }
+        }
+
+        Some(count)
+    }
+
     fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
         let key = line.get_key()?;
         let value = line.get_value()?;
-        line.raw_string = format!("{}={}", key.trim_end(), value.trim_start());
+        line.raw_string = format!(
+            "{}{}={}",
+            line.export_prefix(),
+            key.trim_end(),
+            value.trim_start()
+        );
 
         Some(())
     }
@@ -96,7 +176,7 @@ mod tests {
                 raw_string: String::from("\n"),
             },
         ];
-        let mut warnings = vec![
+        let mut warnings = [
             Warning::new(
                 lines[0].clone(),
                 "SpaceCharacter",
@@ -116,4 +196,44 @@ mod tests {
         assert_eq!("FOO=BAR", lines[0].raw_string);
         assert_eq!("Z=Y", lines[1].raw_string);
     }
+
+    #[test]
+    fn fix_aligned_warnings_test() {
+        let fixer = SpaceCharacterFixer::default();
+        let line = |number: usize, str: &str| LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 6,
+            },
+            raw_string: String::from(str),
+        };
+        let mut lines = vec![
+            line(1, "A     =1"),
+            line(2, "BB=2"),
+            line(3, "CCC =3"),
+            line(4, ""),
+            line(5, "D =4"),
+            line(6, "\n"),
+        ];
+        let mut warnings: Vec<Warning> = [1, 2, 4]
+            .iter()
+            .map(|i| {
+                Warning::new(
+                    lines[*i].clone(),
+                    "SpaceCharacter",
+                    String::from("The message"),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            Some(3),
+            fixer.fix_warnings(warnings.iter_mut().collect(), &mut lines)
+        );
+        assert_eq!("BB    =2", lines[1].raw_string);
+        assert_eq!("CCC   =3", lines[2].raw_string);
+        assert_eq!("D=4", lines[4].raw_string);
+    }
 }
@@ -0,0 +1,80 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct TrailingCommaFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for TrailingCommaFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "TrailingComma",
+        }
+    }
+}
+
+impl Fix for TrailingCommaFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    // Only the commas are removed, a trailing comment and whitespace are kept as is
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let parsed = line.parse();
+        let value = parsed.value?;
+        let content_len = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+            Some(index) => value[..index].trim_end().len(),
+            None => value.trim_end().len(),
+        };
+
+        let end = parsed.value_start(&line.raw_string)? + content_len;
+        let start = line.raw_string[..end].trim_end_matches(',').len();
+        if start == end {
+            return None;
+        }
+        line.raw_string.replace_range(start..end, "");
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = TrailingCommaFixer::default();
+
+        for (str, expected) in &[
+            ("HOSTS=a,b,c,", "HOSTS=a,b,c"),
+            ("HOSTS=a,b,,", "HOSTS=a,b"),
+            ("export HOSTS=a,b, # hosts", "export HOSTS=a,b # hosts"),
+        ] {
+            let mut line = line_entry(str);
+            assert_eq!(Some(()), fixer.fix_line(&mut line));
+            assert_eq!(*expected, line.raw_string);
+        }
+    }
+
+    #[test]
+    fn fix_line_without_comma_test() {
+        let fixer = TrailingCommaFixer::default();
+        let mut line = line_entry("HOSTS=a,b");
+
+        assert_eq!(None, fixer.fix_line(&mut line));
+    }
+}
@@ -0,0 +1,78 @@
+use super::Fix;
+use crate::checks::{find_whitespace, WhitespaceLocation};
+use crate::common::*;
+
+pub(crate) struct WhitespaceReportFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for WhitespaceReportFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "WhitespaceReport",
+        }
+    }
+}
+
+impl Fix for WhitespaceReportFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        // The whitespace is removed from the end of the line first, so the other parts are
+        // still found at the first equal sign
+        for location in find_whitespace(&line.raw_string).into_iter().rev() {
+            let raw = &line.raw_string;
+            // The key is the rest of the line before the delimiter
+            let value_start = parse_line(raw).value_start(raw)?;
+            let (key, value) = (&raw[..value_start - 1], &raw[value_start..]);
+
+            line.raw_string = match location {
+                WhitespaceLocation::BeforeKey => raw.trim_start().to_string(),
+                WhitespaceLocation::AfterKey => format!("{}={}", key.trim_end(), value),
+                WhitespaceLocation::AfterDelimiter => format!("{}={}", key, value.trim_start()),
+                WhitespaceLocation::AfterValue => raw.trim_end().to_string(),
+            };
+        }
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = WhitespaceReportFixer::default();
+        let asserts = vec![
+            (" FOO=bar", "FOO=bar"),
+            ("FOO =bar", "FOO=bar"),
+            ("FOO=\tbar", "FOO=bar"),
+            ("FOO=bar ", "FOO=bar"),
+            (" export FOO = \"bar\" # baz ", "export FOO=\"bar\" # baz"),
+            ("FOO= ", "FOO="),
+        ];
+
+        for (str, expected) in asserts {
+            let mut line = line_entry(str);
+            assert_eq!(Some(()), fixer.fix_line(&mut line));
+            assert_eq!(expected, line.raw_string, "Line: {}", str);
+        }
+    }
+}
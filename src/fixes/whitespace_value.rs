@@ -0,0 +1,83 @@
+use super::Fix;
+use crate::common::*;
+
+pub(crate) struct WhitespaceValueFixer<'a> {
+    name: &'a str,
+}
+
+impl Default for WhitespaceValueFixer<'_> {
+    fn default() -> Self {
+        Self {
+            name: "WhitespaceValue",
+        }
+    }
+}
+
+impl Fix for WhitespaceValueFixer<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    // The fix is conservative: the value is collapsed to empty quotes only when nothing follows
+    // the closing quote, other lines are left for a manual review
+    fn fix_line(&self, line: &mut LineEntry) -> Option<()> {
+        let parsed = line.parse();
+        let quote = parsed.quote?;
+        let content = &parsed.value?.trim()[1..];
+        let end = find_closing_quote(content, quote)?;
+        if end + 1 != content.len() || !content[..end].trim().is_empty() {
+            return None;
+        }
+
+        let key = parsed.key?;
+        line.raw_string = format!(
+            "{}{}={}{}",
+            line.export_prefix(),
+            key,
+            quote.as_char(),
+            quote.as_char()
+        );
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(str: &str) -> LineEntry {
+        LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines: 1,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn fix_line_test() {
+        let fixer = WhitespaceValueFixer::default();
+
+        let mut line = line_entry("FOO=\"   \"");
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("FOO=\"\"", line.raw_string);
+
+        let mut line = line_entry("export FOO=' '");
+        assert_eq!(Some(()), fixer.fix_line(&mut line));
+        assert_eq!("export FOO=''", line.raw_string);
+    }
+
+    #[test]
+    fn fix_line_with_comment_test() {
+        let fixer = WhitespaceValueFixer::default();
+        let mut line = line_entry("FOO=\" \" # TODO");
+
+        assert_eq!(None, fixer.fix_line(&mut line));
+        assert_eq!("FOO=\" \" # TODO", line.raw_string);
+    }
+}
@@ -1,7 +1,12 @@
-use crate::common::LineEntry;
-use std::fs::File;
+use crate::common::{LineEntry, CRLF, LF};
+use glob::Pattern;
+use std::fs::{self, File};
 use std::io::{self, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The name of a file with glob patterns of paths which should be excluded from check
+pub const IGNORE_FILE_NAME: &str = ".dotenv-linterignore";
 
 /// For the Windows platform, we need to remove the UNC prefix.
 #[cfg(windows)]
@@ -12,7 +17,7 @@ pub use dunce::canonicalize;
 pub use std::fs::canonicalize;
 
 /// Returns the relative path for `target_path` relative to `base_path`
-pub fn get_relative_path(target_path: &PathBuf, base_path: &PathBuf) -> Option<PathBuf> {
+pub fn get_relative_path(target_path: &Path, base_path: &Path) -> Option<PathBuf> {
     let comp_target: Vec<_> = target_path.components().collect();
     let comp_base: Vec<_> = base_path.components().collect();
 
@@ -34,17 +39,130 @@ pub fn get_relative_path(target_path: &PathBuf, base_path: &PathBuf) -> Option<P
     Some(relative_path)
 }
 
+/// Returns patterns from the nearest `.dotenv-linterignore` file, starting from `current_dir`
+/// and walking up to the root directory.
+///
+/// Patterns are resolved relative to the directory of the ignore file.
+/// A pattern without a slash matches a file or directory at any level.
+/// Blank lines and lines starting with `#` are skipped.
+pub fn read_ignore_file(current_dir: &Path) -> Vec<Pattern> {
+    let path = match current_dir
+        .ancestors()
+        .map(|dir| dir.join(IGNORE_FILE_NAME))
+        .find(|path| path.is_file())
+    {
+        Some(path) => path,
+        None => return Vec::new(),
+    };
+
+    let dir = match path.parent().map(canonicalize) {
+        Some(Ok(dir)) => dir,
+        _ => return Vec::new(),
+    };
+
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(_) => return Vec::new(),
+    };
+
+    let base = Pattern::escape(&dir.to_string_lossy());
+
+    content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.trim_end_matches('/'))
+        .filter_map(|line| {
+            let pattern = if line.contains('/') {
+                format!("{}/{}", base, line.trim_start_matches('/'))
+            } else {
+                format!("{}/**/{}", base, line)
+            };
+
+            Pattern::new(&pattern).ok()
+        })
+        .collect()
+}
+
+/// Writes lines to the file, keeping the line ending style of the file being overwritten.
+///
 /// In the future versions we should create a backup copy, or at least notify the user about it
-pub fn write_file(path: &PathBuf, lines: Vec<LineEntry>) -> io::Result<()> {
+pub fn write_file(path: &Path, lines: &[LineEntry]) -> io::Result<()> {
+    let line_ending = match fs::read_to_string(path) {
+        Ok(content) => get_line_ending(&content),
+        Err(_) => LF,
+    };
+
     let mut file = File::create(path)?;
+    file.write_all(get_content(lines, line_ending).as_bytes())
+}
 
-    // We don't write the last line, because it contains only LF (common::FileEntry::from)
-    // and writeln! already adds LF.
-    for line in lines[..lines.len() - 1].iter() {
-        writeln!(file, "{}", line.raw_string)?;
+/// Returns the dominant line ending of the content: CRLF or LF
+pub fn get_line_ending(content: &str) -> &'static str {
+    let crlf_count = content.matches(CRLF).count();
+    let lf_count = content.matches(LF).count() - crlf_count;
+
+    if crlf_count > lf_count {
+        CRLF
+    } else {
+        LF
     }
+}
 
-    Ok(())
+/// Joins line entries back into the file content with the given line ending
+pub fn get_content(lines: &[LineEntry], line_ending: &str) -> String {
+    match lines.split_last() {
+        // The last line contains only LF (common::FileEntry::from), so every line ends with LF
+        Some((last, lines)) if last.raw_string == LF => lines
+            .iter()
+            .map(|line| format!("{}{}", line.raw_string, line_ending))
+            .collect(),
+        _ => lines
+            .iter()
+            .map(|line| line.raw_string.as_str())
+            .collect::<Vec<_>>()
+            .join(line_ending),
+    }
+}
+
+/// Parses a duration with a unit: `90s`, `10m`, `2h` or `1d`
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let index = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(index);
+
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        _ => {
+            return Err(format!(
+                "Invalid duration {}, use a number with s, m, h or d (e.g. 10m)",
+                value
+            ))
+        }
+    };
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("Invalid duration {}, the number is missing", value))?;
+
+    Ok(Duration::from_secs(number * seconds))
+}
+
+/// Checks if the file was modified within the duration. A file with an unknown modification
+/// time is kept, it is reported when it is read
+pub fn is_modified_within(path: &Path, duration: Duration) -> bool {
+    let modified = match fs::metadata(path).and_then(|m| m.modified()) {
+        Ok(modified) => modified,
+        Err(_) => return true,
+    };
+
+    // The modification time is in the future, e.g. after a clock change
+    modified
+        .elapsed()
+        .map_or(true, |elapsed| elapsed <= duration)
 }
 
 #[cfg(test)]
@@ -62,6 +180,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_duration_test() {
+        assert_eq!(Ok(Duration::from_secs(90)), parse_duration("90s"));
+        assert_eq!(Ok(Duration::from_secs(600)), parse_duration("10m"));
+        assert_eq!(Ok(Duration::from_secs(7200)), parse_duration("2h"));
+        assert_eq!(Ok(Duration::from_secs(86400)), parse_duration("1d"));
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("m").is_err());
+        assert!(parse_duration("10 m").is_err());
+        assert!(parse_duration("1w").is_err());
+    }
+
     #[test]
     #[cfg(not(windows))]
     fn test_relative_path() {
@@ -130,6 +260,65 @@ mod tests {
         dir.close().expect("temp dir deleted");
     }
 
+    #[test]
+    #[cfg(not(windows))]
+    fn read_ignore_file_test() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let subdir = dir.path().join("subdir");
+        fs::create_dir(&subdir).expect("create subdir");
+        fs::write(
+            dir.path().join(IGNORE_FILE_NAME),
+            "# Comment\n\n.env.test\nconfig/*.env\n",
+        )
+        .expect("write ignore file");
+
+        let patterns = read_ignore_file(&subdir);
+        let base = canonicalize(dir.path()).expect("canonical path");
+        let is_ignored = |path: &str| patterns.iter().any(|p| p.matches_path(&base.join(path)));
+
+        assert_eq!(2, patterns.len());
+        assert!(is_ignored(".env.test"));
+        assert!(is_ignored("subdir/.env.test"));
+        assert!(is_ignored("config/.local.env"));
+        assert!(!is_ignored(".env"));
+        assert!(!is_ignored("subdir/config/.local.env"));
+
+        dir.close().expect("temp dir deleted");
+    }
+
+    #[test]
+    fn read_ignore_file_without_file_test() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+
+        assert!(read_ignore_file(dir.path()).is_empty());
+
+        dir.close().expect("temp dir deleted");
+    }
+
+    #[test]
+    fn get_content_without_ending_blank_line_test() {
+        let fe = FileEntry {
+            path: PathBuf::from(".env"),
+            file_name: String::from(".env"),
+            total_lines: 2,
+        };
+
+        let lines = vec![
+            LineEntry {
+                number: 1,
+                file: fe.clone(),
+                raw_string: String::from("A=B"),
+            },
+            LineEntry {
+                number: 2,
+                file: fe,
+                raw_string: String::from("Z=Y"),
+            },
+        ];
+
+        assert_eq!("A=B\nZ=Y", get_content(&lines, LF));
+    }
+
     #[test]
     fn write_file_test() {
         let file_name = String::from(".env");
@@ -160,7 +349,7 @@ mod tests {
             },
         ];
 
-        assert!(write_file(&path, lines).is_ok());
+        assert!(write_file(&path, &lines).is_ok());
         assert_eq!(
             b"A=B\nZ=Y\n",
             fs::read(path.as_path()).expect("file read").as_slice()
@@ -168,4 +357,53 @@ mod tests {
 
         dir.close().expect("temp dir deleted");
     }
+
+    #[test]
+    fn get_line_ending_test() {
+        assert_eq!(LF, get_line_ending("A=B\nC=D\n"));
+        assert_eq!(CRLF, get_line_ending("A=B\r\nC=D\r\n"));
+        assert_eq!(CRLF, get_line_ending("A=B\r\nC=D\r\nE=F\n"));
+        assert_eq!(LF, get_line_ending("A=B\r\nC=D\n"));
+        assert_eq!(LF, get_line_ending("A=B"));
+    }
+
+    #[test]
+    fn write_file_with_crlf_test() {
+        let file_name = String::from(".env");
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join(&file_name);
+        fs::write(&path, "a=B\r\nC=D\r\n").expect("write file");
+
+        let fe = FileEntry {
+            path: path.clone(),
+            file_name,
+            total_lines: 3,
+        };
+
+        let lines = vec![
+            LineEntry {
+                number: 1,
+                file: fe.clone(),
+                raw_string: String::from("A=B"),
+            },
+            LineEntry {
+                number: 2,
+                file: fe.clone(),
+                raw_string: String::from("C=D"),
+            },
+            LineEntry {
+                number: 3,
+                file: fe,
+                raw_string: String::from("\n"),
+            },
+        ];
+
+        assert!(write_file(&path, &lines).is_ok());
+        assert_eq!(
+            b"A=B\r\nC=D\r\n",
+            fs::read(path.as_path()).expect("file read").as_slice()
+        );
+
+        dir.close().expect("temp dir deleted");
+    }
 }
@@ -1,72 +1,998 @@
+use crate::baseline::Baseline;
 use crate::common::*;
+use crate::diff::ChangedLines;
 
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
-use std::path::PathBuf;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
 
+mod baseline;
 mod checks;
 mod common;
+mod compose;
+mod config;
+mod diff;
 mod fixes;
 mod fs_utils;
+mod output;
+mod resolve;
 
-pub use checks::available_check_names;
+pub use checks::{active_check_names, available_check_names, explain, run_check};
+pub use common::{
+    parse_line, Encoding, FileEntry, FileResult, LineEntry, LintError, ParsedLine, QuoteStyle,
+    Severity, Span, Stats, Warning,
+};
+pub use config::{
+    ChecksConfig, Config, ConfigBuilder, EffectiveConfig, GeneratedConfig, Profile, CONFIG_SCHEMA,
+};
+pub use fs_utils::parse_duration;
+pub use output::{
+    format_checkstyle, format_config, format_config_json, format_dump, format_dump_json,
+    format_json, format_lsp_json, format_merge, format_merge_json, format_tap, lsp_diagnostics,
+    write_json, DumpedFile, DumpedKey, LspDiagnostic, LspFile, LspPosition, LspRange, MergedKey,
+    OutputTemplate, Summary,
+};
+
+pub fn run(args: &clap::ArgMatches, current_dir: &Path) -> Result<Vec<Warning>, Box<dyn Error>> {
+    run_with_stats(args, current_dir).map(|(warnings, _)| warnings)
+}
+
+/// Runs the checks and returns the warnings with statistics of the run
+pub fn run_with_stats(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+) -> Result<(Vec<Warning>, Stats), Box<dyn Error>> {
+    run_files(args, current_dir, None).map(|(warnings, stats, _)| (warnings, stats))
+}
+
+/// Runs the checks like `run` and returns the results grouped by files, sorted by paths.
+/// Every checked file has a result, the files which couldn't be read have the error instead
+/// of the warnings, so one unreadable file doesn't fail the whole run
+pub fn lint_files(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+) -> Result<Vec<FileResult>, Box<dyn Error>> {
+    lint_files_with_stats(args, current_dir).map(|(results, _)| results)
+}
+
+/// Runs the checks like `lint_files` and returns the results with statistics of the run
+pub fn lint_files_with_stats(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+) -> Result<(Vec<FileResult>, Stats), Box<dyn Error>> {
+    let (warnings, stats, mut files) = run_files(args, current_dir, None)?;
+    let mut errors: Vec<FileResult> = stats
+        .skipped
+        .iter()
+        .map(|(path, reason)| FileResult::from_error(path.clone(), reason.clone()))
+        .collect();
+
+    // The paths of the files are rebased like the paths of the warnings, so they still match
+    if let Some(base) = args.value_of("path-base") {
+        let rebase = Rebase::new(current_dir, base)?;
+        for file in files.iter_mut() {
+            rebase.path(&mut file.path);
+        }
+        for result in errors.iter_mut() {
+            rebase.path(&mut result.file.path);
+        }
+    }
+
+    Ok((group_by_file(warnings, files, errors), stats))
+}
+
+// Puts the warnings into the results of their files, the warnings keep their order
+fn group_by_file(
+    warnings: Vec<Warning>,
+    files: Vec<FileEntry>,
+    errors: Vec<FileResult>,
+) -> Vec<FileResult> {
+    let mut results: Vec<FileResult> = Vec::new();
+    let mut indexes: HashMap<PathBuf, usize> = HashMap::new();
+
+    // Compose files have a file entry for each block, they are merged into one result
+    for file in files {
+        if !indexes.contains_key(&file.path) {
+            indexes.insert(file.path.clone(), results.len());
+            results.push(FileResult::new(file));
+        }
+    }
+
+    for warning in warnings {
+        let index = match indexes.get(warning.file_path()) {
+            Some(&index) => index,
+            None => {
+                indexes.insert(warning.file_path().to_path_buf(), results.len());
+                results.push(FileResult::new(warning.file().clone()));
+                results.len() - 1
+            }
+        };
+        results[index].warnings.push(warning);
+    }
+
+    results.extend(errors);
+    results.sort_by(|a, b| a.file.path.cmp(&b.file.path));
+
+    results
+}
+
+/// Runs the checks like `run_with_stats` and passes the warnings of each file to `on_file`
+/// as soon as the file is checked. The warnings of the checks comparing several files are
+/// passed last, so the returned warnings are sorted by paths only within each file
+pub fn run_streaming(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+    on_file: &mut dyn FnMut(&[Warning]),
+) -> Result<(Vec<Warning>, Stats), Box<dyn Error>> {
+    run_files(args, current_dir, Some(on_file)).map(|(warnings, stats, _)| (warnings, stats))
+}
+
+// Receives the warnings of each checked file when streaming
+type OnFile<'a> = &'a mut dyn FnMut(&[Warning]);
+
+// The warnings, the statistics and the entries of the checked files of a run
+type RunOutput = (Vec<Warning>, Stats, Vec<FileEntry>);
 
 #[allow(clippy::redundant_closure)]
-pub fn run(args: &clap::ArgMatches, current_dir: &PathBuf) -> Result<Vec<Warning>, Box<dyn Error>> {
-    let mut file_paths: Vec<PathBuf> = Vec::new();
-    let mut skip_checks: Vec<&str> = Vec::new();
-    let mut excluded_paths: Vec<PathBuf> = Vec::new();
+fn run_files(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+    mut on_file: Option<OnFile<'_>>,
+) -> Result<RunOutput, Box<dyn Error>> {
+    let is_compose = args.is_present("compose");
+    let encoding = args
+        .value_of("encoding")
+        .and_then(Encoding::from_name)
+        .unwrap_or(Encoding::Utf8);
+    let config = get_config(args, current_dir)?;
+    let changed_lines = get_changed_lines(args, current_dir)?;
+    let is_changed = |w: &Warning| match &changed_lines {
+        Some(changed) => changed.contains(w.file_path(), w.line_number()),
+        None => true,
+    };
 
-    let is_recursive = args.is_present("recursive");
+    let file_skips = FileSkips::from_values(args.values_of("skip-in").into_iter().flatten())?;
 
-    if let Some(skip) = args.values_of("skip") {
-        skip_checks = skip.collect();
+    let mut stats = Stats::default();
+    let sources = get_sources(args, current_dir, &config)?;
+
+    let baseline = match args.value_of("baseline") {
+        Some(path) => Some(Baseline::from_file(Path::new(path))?),
+        None => None,
+    };
+    let ignore_lines = args.is_present("baseline-ignore-lines");
+    // With `--fail-fast` the remaining files are skipped after a file with a reported warning
+    let is_fail_fast = args.is_present("fail-fast");
+    let is_reported = |result: &[Warning]| match &baseline {
+        Some(baseline) => {
+            let mut result = result.to_vec();
+            number_occurrences(&mut result);
+            !baseline.filter(result, ignore_lines).is_empty()
+        }
+        None => !result.is_empty(),
+    };
+    let mut is_stopped = false;
+    // With `--fail-on-error` the run stops at the first file which can't be read
+    let is_fail_on_error = args.is_present("fail-on-error");
+    let has_io_error = |stats: &Stats| is_fail_on_error && !stats.skipped.is_empty();
+
+    // Dedups, filters, rebases and sorts the warnings of the run, or of a single file when streaming
+    let finish = |mut warnings: Vec<Warning>| -> Result<Vec<Warning>, Box<dyn Error>> {
+        if !args.is_present("no-dedup") {
+            dedup_warnings(&mut warnings);
+        }
+
+        // The occurrences are numbered before the filter, the baseline matches the fingerprints
+        number_occurrences(&mut warnings);
+        if let Some(baseline) = &baseline {
+            warnings = baseline.filter(warnings, ignore_lines);
+        }
+
+        if let Some(base) = args.value_of("path-base") {
+            rebase_paths(&mut warnings, current_dir, base)?;
+        }
+
+        finish_warnings(&mut warnings, &config);
+
+        if is_fail_fast {
+            warnings.truncate(1);
+        }
+
+        Ok(warnings)
+    };
+
+    let is_fix = args.is_present("fix");
+    let is_patch = args.is_present("patch");
+    // The warnings of the checks comparing several files come after all the files, so the
+    // warnings are held back to the end of the run to keep them sorted by paths
+    let is_held_back = on_file.is_some() && checks::has_multi_file_checks(&config);
+    let mut warnings: Vec<Warning> = Vec::new();
+    let mut emit = |result: Vec<Warning>| -> Result<(), Box<dyn Error>> {
+        match on_file.as_mut().filter(|_| !is_held_back) {
+            Some(on_file) => {
+                let result = finish(result)?;
+                on_file(&result);
+                warnings.extend(result);
+            }
+            None => warnings.extend(result),
+        }
+        Ok(())
+    };
+    let mut files: Vec<Vec<LineEntry>> = Vec::new();
+    // The entries of the checked files, including the files without lines
+    let mut checked: Vec<FileEntry> = Vec::new();
+
+    for source in sources {
+        if has_io_error(&stats) {
+            is_stopped = true;
+            break;
+        }
+
+        let path = match source {
+            Source::File(path) => path,
+            Source::Missing(path, reason) => {
+                stats.skipped.push((path, reason));
+                continue;
+            }
+            Source::Git(object, content) => {
+                let result = git_object_line_entries(object, &content)
+                    .map(|lines| {
+                        stats.files += 1;
+                        stats.lines += lines.len();
+
+                        let mut result = checks::run_with_stats(&lines, &config, &mut stats);
+                        result.retain(|w| is_changed(w) && !file_skips.contains(w));
+                        checked.extend(lines.first().map(|l| l.file.clone()));
+                        files.push(lines);
+                        result
+                    })
+                    .unwrap_or_default();
+
+                is_stopped = is_fail_fast && is_reported(&result);
+                emit(result)?;
+                if is_stopped {
+                    break;
+                }
+                continue;
+            }
+        };
+
+        let relative_path = match fs_utils::get_relative_path(&path, current_dir) {
+            Some(p) => p,
+            None => continue,
+        };
+
+        if is_compose && compose::is_compose_file(&relative_path) {
+            for lines in compose_line_entries(relative_path, encoding, &mut stats) {
+                let mut result = checks::run_with_stats(&lines, &config, &mut stats);
+                result.retain(|w| {
+                    is_changed(w)
+                        && !file_skips.contains(w)
+                        && !compose::LAYOUT_CHECKS.contains(&w.check_name.as_str())
+                });
+
+                is_stopped = is_fail_fast && is_reported(&result);
+                emit(result)?;
+                checked.extend(lines.first().map(|l| l.file.clone()));
+                files.push(lines);
+                if is_stopped {
+                    break;
+                }
+            }
+            if is_stopped {
+                break;
+            }
+            continue;
+        }
+
+        let content = match read_file(&relative_path, encoding, &mut stats) {
+            Ok(content) => content,
+            Err(reason) => {
+                stats.skipped.push((relative_path, reason));
+                continue;
+            }
+        };
+        let (fe, strs) = match FileEntry::from_content(relative_path.clone(), &content) {
+            Some(f) => f,
+            None => continue,
+        };
+
+        if config.generated.skip && is_generated(&strs, &config.generated) {
+            stats.generated.push(relative_path);
+            continue;
+        }
+
+        let mut lines = get_line_entries(&fe, strs);
+        stats.files += 1;
+        stats.lines += lines.len();
+
+        let mut result = checks::run_with_stats(&lines, &config, &mut stats);
+        result.retain(|w| is_changed(w) && !file_skips.contains(w));
+
+        if args.is_present("suggest") {
+            for warning in &mut result {
+                warning.suggestion = fixes::suggest(warning, &lines);
+            }
+        }
+
+        if args.is_present("resolve") {
+            let resolved = resolve::resolve_values(
+                &lines,
+                args.is_present("use-env"),
+                &config.checks.empty_production_secret.suffixes,
+            );
+            for warning in &mut result {
+                warning.resolved = resolved.get(&warning.line_number()).cloned();
+            }
+        }
+
+        if is_fix || is_patch {
+            // The fix of each warning is recorded for the JSON output
+            let fixed_lines: Vec<Vec<String>> =
+                result.iter().map(|w| fixes::suggest(w, &lines)).collect();
+
+            let is_fixable = |w: &Warning| is_changed(w) && !file_skips.contains(w);
+            let is_fixed = fixes::run_until_fixed(&mut result, &mut lines, &config, is_fixable) > 0;
+            if is_fixed {
+                if is_patch {
+                    let line_ending = fs_utils::get_line_ending(&content);
+                    let fixed = fs_utils::get_content(&lines, line_ending);
+                    stats
+                        .patch
+                        .push_str(&diff::unified_diff(&fe.path, &content, &fixed));
+                } else {
+                    fs_utils::write_file(&fe.path, &lines)?;
+                }
+            }
+
+            for (warning, fixed_lines) in result.iter_mut().zip(fixed_lines) {
+                if warning.is_fixed {
+                    warning.fixed_lines = fixed_lines;
+                }
+            }
+
+            // The fixes can remove or add lines, so the written lines are checked again for the
+            // unfixed warnings to point at their current lines
+            if is_fixed && !is_patch {
+                result.retain(|w| w.is_fixed);
+                result.extend(
+                    checks::run(&lines, &config)
+                        .into_iter()
+                        .filter(|w| is_fixable(w)),
+                );
+            }
+        }
+
+        is_stopped = is_fail_fast && is_reported(&result);
+        emit(result)?;
+        checked.push(fe);
+        files.push(lines);
+        if is_stopped {
+            break;
+        }
+    }
+
+    if !is_stopped && !has_io_error(&stats) {
+        let mut result = checks::run_multi_file(&files, &config, &mut stats);
+        result.retain(|w| is_changed(w) && !file_skips.contains(w));
+        emit(result)?;
     }
 
+    // The warnings of each file are already finished when streaming
+    if on_file.is_none() || is_held_back {
+        warnings = finish(warnings)?;
+    }
+    if let Some(on_file) = on_file.as_mut().filter(|_| is_held_back) {
+        on_file(&warnings);
+    }
+
+    Ok((warnings, stats, checked))
+}
+
+/// Finds the files to check from the arguments and the config, the paths which can't be read
+/// are added to the skipped paths of `stats`
+fn get_sources<'a>(
+    args: &'a clap::ArgMatches,
+    current_dir: &Path,
+    config: &Config,
+) -> Result<Vec<Source<'a>>, Box<dyn Error>> {
+    let mut file_paths: Vec<PathBuf> = Vec::new();
+    let mut missing_paths: Vec<(PathBuf, String)> = Vec::new();
+    let mut excluded_paths: Vec<PathBuf> = Vec::new();
+
+    let is_recursive = config.recursive;
+    let is_compose = args.is_present("compose");
+
     if let Some(excluded) = args.values_of("exclude") {
         excluded_paths = excluded
             .filter_map(|f| fs_utils::canonicalize(f).ok())
             .collect();
     }
 
-    if let Some(inputs) = args.values_of("input") {
+    let ignore_patterns = fs_utils::read_ignore_file(current_dir);
+    let excludes = Excludes {
+        paths: &excluded_paths,
+        patterns: &ignore_patterns,
+    };
+
+    let patterns = config
+        .patterns
+        .iter()
+        .map(|p| glob::Pattern::new(p).map_err(|e| format!("Invalid pattern {}: {}", p, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Missing paths are skipped, e.g. deleted files passed by a pre-commit hook.
+    // With `--ignore-missing-files` they are skipped without a notice
+    let is_missing_ignored = args.is_present("ignore-missing-files");
+    // With `--from-git` the current directory is not checked, unless it is passed explicitly
+    let is_input_default = args.is_present("from-git") && args.occurrences_of("input") == 0;
+    if let Some(inputs) = args.values_of("input").filter(|_| !is_input_default) {
         let input_paths = inputs
-            .filter_map(|s| fs_utils::canonicalize(s).ok())
+            .filter_map(|s| match fs_utils::canonicalize(s) {
+                Ok(path) => Some(path),
+                Err(e) if is_missing_ignored && e.kind() == io::ErrorKind::NotFound => None,
+                Err(e) => {
+                    missing_paths.push((PathBuf::from(s), e.to_string()));
+                    None
+                }
+            })
             .collect();
 
-        file_paths.extend(get_file_paths(input_paths, &excluded_paths, is_recursive));
+        file_paths.extend(get_file_paths(
+            input_paths,
+            &excludes,
+            &patterns,
+            is_recursive,
+            is_compose,
+        ));
     }
 
-    let is_fix = args.is_present("fix");
-    let mut warnings: Vec<Warning> = Vec::new();
+    // A file can be passed directly and found in a passed directory too, it is checked once
+    let mut seen_paths = HashSet::new();
+    file_paths.retain(|path| seen_paths.insert(path.clone()));
+    // With `--changed-within` only the recently modified files are checked, the passed ones too
+    if let Some(duration) = args.value_of("changed-within") {
+        let duration = fs_utils::parse_duration(duration)?;
+        file_paths.retain(|path| fs_utils::is_modified_within(path, duration));
+    }
+
+    // The files are checked in the order of the output, so the streamed warnings are sorted too.
+    // The paths which can't be found are in the same order, `--fail-on-error` stops at them
+    let mut sources: Vec<Source<'a>> = file_paths
+        .into_iter()
+        .map(Source::File)
+        .chain(
+            missing_paths
+                .into_iter()
+                .map(|(path, reason)| Source::Missing(path, reason)),
+        )
+        .collect();
+    sources.sort_by_cached_key(|source| match source {
+        Source::File(path) => fs_utils::get_relative_path(path, current_dir),
+        Source::Missing(path, _) => Some(path.clone()),
+        Source::Git(..) => None,
+    });
+
+    for object in args.values_of("from-git").into_iter().flatten() {
+        sources.push(Source::Git(object, read_git_object(current_dir, object)?));
+    }
+
+    Ok(sources)
+}
+
+/// Returns the settings of a run with the arguments, resolved the same way as for the run
+/// (`--print-config`). The files are not checked
+pub fn effective_config(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+) -> Result<EffectiveConfig, Box<dyn Error>> {
+    let config = get_config(args, current_dir)?;
+
+    Ok(EffectiveConfig {
+        active_checks: checks::active_check_names(&config),
+        config,
+    })
+}
+
+/// Reads the keys and the values of the files to check, as the checks see them (`--dump`).
+/// The checks are not run
+pub fn dump(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+) -> Result<Vec<DumpedFile>, Box<dyn Error>> {
+    let is_compose = args.is_present("compose");
+    let encoding = args
+        .value_of("encoding")
+        .and_then(Encoding::from_name)
+        .unwrap_or(Encoding::Utf8);
+    let config = get_config(args, current_dir)?;
+    let mut stats = Stats::default();
+
+    let mut files: Vec<Vec<LineEntry>> = Vec::new();
+    for source in get_sources(args, current_dir, &config)? {
+        let path = match source {
+            Source::File(path) => path,
+            Source::Missing(path, reason) => {
+                stats.skipped.push((path, reason));
+                continue;
+            }
+            Source::Git(object, content) => {
+                files.extend(git_object_line_entries(object, &content));
+                continue;
+            }
+        };
 
-    for path in file_paths {
-        let relative_path = match fs_utils::get_relative_path(&path, &current_dir) {
+        let relative_path = match fs_utils::get_relative_path(&path, current_dir) {
             Some(p) => p,
             None => continue,
         };
 
-        let (fe, strs) = match FileEntry::from(relative_path) {
-            Some(f) => f,
+        if is_compose && compose::is_compose_file(&relative_path) {
+            files.extend(compose_line_entries(relative_path, encoding, &mut stats));
+            continue;
+        }
+
+        let content = match read_file(&relative_path, encoding, &mut stats) {
+            Ok(content) => content,
+            Err(reason) => {
+                return Err(
+                    format!("Failed to read {}: {}", relative_path.display(), reason).into(),
+                )
+            }
+        };
+        if let Some((fe, strs)) = FileEntry::from_content(relative_path, &content) {
+            files.push(get_line_entries(&fe, strs));
+        }
+    }
+
+    Ok(files.iter().filter_map(|lines| dump_lines(lines)).collect())
+}
+
+fn dump_lines(lines: &[LineEntry]) -> Option<DumpedFile> {
+    let keys = lines
+        .iter()
+        .filter_map(|line| {
+            let key = line.get_key()?;
+            let parsed = line.parse();
+            let value = parsed.value.unwrap_or_default();
+            let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+                Some(index) => value[..index].trim(),
+                None => value.trim(),
+            };
+
+            Some(DumpedKey {
+                line: line.number,
+                key,
+                value: String::from(unquote(value).unwrap_or(value)),
+                exported: parsed.is_exported,
+            })
+        })
+        .collect();
+
+    Some(DumpedFile {
+        file: lines.first()?.file.path.display().to_string(),
+        keys,
+    })
+}
+
+/// Merges the keys of the passed files in the order of the arguments, the values of the later
+/// files override the values of the earlier ones (`--merge`). Only files can be merged, since
+/// the order of the files found in a directory is not a precedence
+pub fn merge(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+) -> Result<Vec<MergedKey>, Box<dyn Error>> {
+    let encoding = args
+        .value_of("encoding")
+        .and_then(Encoding::from_name)
+        .unwrap_or(Encoding::Utf8);
+    let mut stats = Stats::default();
+    let mut keys: Vec<MergedKey> = Vec::new();
+
+    for input in args.values_of("input").into_iter().flatten() {
+        let path = fs_utils::canonicalize(input)
+            .map_err(|e| format!("Failed to read {}: {}", input, e))?;
+        if path.is_dir() {
+            return Err(format!("Only files can be merged, {} is a directory", input).into());
+        }
+        let relative_path = fs_utils::get_relative_path(&path, current_dir).unwrap_or(path);
+
+        let content = read_file(&relative_path, encoding, &mut stats)
+            .map_err(|reason| format!("Failed to read {}: {}", relative_path.display(), reason))?;
+        let lines = match FileEntry::from_content(relative_path, &content) {
+            Some((fe, strs)) => get_line_entries(&fe, strs),
+            None => continue,
+        };
+        let file = match dump_lines(&lines) {
+            Some(file) => file,
             None => continue,
         };
 
-        let mut lines = get_line_entries(&fe, strs);
+        for dumped in file.keys {
+            // The lines are numbered from 1 in their order
+            let raw_string = lines[dumped.line - 1].raw_string.clone();
+            let merged = MergedKey {
+                key: dumped.key,
+                value: dumped.value,
+                file: file.file.clone(),
+                line: dumped.line,
+                overrides: Vec::new(),
+                raw_string,
+            };
 
-        let mut result = checks::run(&lines, &skip_checks);
-        if is_fix && fixes::run(&mut result, &mut lines) > 0 {
-            fs_utils::write_file(&fe.path, lines)?;
+            // A key keeps the position of its first definition
+            match keys.iter_mut().find(|k| k.key == merged.key) {
+                Some(key) => {
+                    let mut overrides = std::mem::take(&mut key.overrides);
+                    overrides.push(format!("{}:{}", key.file, key.line));
+                    *key = MergedKey {
+                        overrides,
+                        ..merged
+                    };
+                }
+                None => keys.push(merged),
+            }
         }
+    }
+
+    Ok(keys)
+}
+
+/// Checks the lines of the merged keys as one file (`--lint-merged`). The line numbers of the
+/// warnings are the positions of the keys in the output of `--merge`
+pub fn lint_merged(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+    keys: &[MergedKey],
+) -> Result<Vec<Warning>, Box<dyn Error>> {
+    let config = get_config(args, current_dir)?;
+    let content: String = keys.iter().map(|k| format!("{}\n", k.raw_string)).collect();
+
+    Ok(lint_str_with_config(&content, "merged", &config))
+}
+
+/// Checks the content of a single file with the default config, without touching the filesystem.
+/// The file name is used in the warnings and by the checks depending on it
+pub fn lint_str(content: &str, file_name: &str) -> Vec<Warning> {
+    lint_str_with_config(content, file_name, &Config::default())
+}
+
+/// Checks the content of a single file like `lint_str`, with the given config
+pub fn lint_str_with_config(content: &str, file_name: &str, config: &Config) -> Vec<Warning> {
+    let lines = match FileEntry::from_content(PathBuf::from(file_name), content) {
+        Some((fe, strs)) => get_line_entries(&fe, strs),
+        None => return Vec::new(),
+    };
+
+    let mut stats = Stats::default();
+    let mut warnings = checks::run_with_stats(&lines, config, &mut stats);
+    warnings.extend(checks::run_multi_file(&[lines], config, &mut stats));
+    dedup_warnings(&mut warnings);
+    number_occurrences(&mut warnings);
+    finish_warnings(&mut warnings, config);
+
+    warnings
+}
+
+/// Removes exact duplicates of the warnings (with the same file, line, check and message)
+fn dedup_warnings(warnings: &mut Vec<Warning>) {
+    let mut seen = HashSet::new();
+    warnings.retain(|w| {
+        seen.insert((
+            w.file_path().to_path_buf(),
+            w.line_number(),
+            w.check_name.clone(),
+            w.message().to_string(),
+        ))
+    });
+}
 
-        warnings.extend(result);
+/// Sorts the warnings by files and lines and numbers the occurrences of the warnings with the
+/// same fingerprint, so the fingerprints of identical lines are different
+fn number_occurrences(warnings: &mut [Warning]) {
+    warnings.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for warning in warnings.iter_mut() {
+        warning.occurrence = 0;
+        let count = counts.entry(warning.fingerprint()).or_default();
+        warning.occurrence = *count;
+        *count += 1;
+    }
+}
+
+/// Sorts the warnings by the names of their checks (`--sort-output by-check`). The sort is
+/// stable, so the warnings of a check stay sorted by files and lines
+pub fn sort_by_check(warnings: &mut [Warning]) {
+    warnings.sort_by(|a, b| a.check_name.cmp(&b.check_name));
+}
+
+// Makes the paths of the warnings relative to `base` instead of the current directory (`--path-base`)
+fn rebase_paths(warnings: &mut [Warning], current_dir: &Path, base: &str) -> Result<(), String> {
+    let rebase = Rebase::new(current_dir, base)?;
+
+    for warning in warnings {
+        let mut path = warning.file_path().to_path_buf();
+        rebase.path(&mut path);
+        warning.set_file_path(path);
+    }
+
+    Ok(())
+}
+
+// The canonical current directory and `--path-base` directory
+struct Rebase {
+    current_dir: PathBuf,
+    base: PathBuf,
+}
+
+impl Rebase {
+    fn new(current_dir: &Path, base: &str) -> Result<Self, String> {
+        let canonical = |path: &Path| {
+            fs_utils::canonicalize(path).map_err(|e| format!("Invalid --path-base {}: {}", base, e))
+        };
+        let current_dir = canonical(current_dir)?;
+        let base = canonical(&current_dir.join(base))?;
+
+        Ok(Self { current_dir, base })
+    }
+
+    // Replaces a path relative to the current directory with the path relative to the base
+    fn path(&self, path: &mut PathBuf) {
+        // The paths can go out of the current directory with `..`, they are resolved lexically
+        let absolute = self.current_dir.join(&path).components().fold(
+            PathBuf::new(),
+            |mut path, component| {
+                match component {
+                    Component::ParentDir => {
+                        path.pop();
+                    }
+                    component => path.push(component),
+                }
+                path
+            },
+        );
+
+        if let Some(relative) = fs_utils::get_relative_path(&absolute, &self.base) {
+            *path = relative;
+        }
+    }
+}
+
+// Sets the severities from the config and sorts the warnings
+fn finish_warnings(warnings: &mut [Warning], config: &Config) {
+    for warning in warnings.iter_mut() {
+        if let Some(severity) = config.severity.get(&warning.check_name) {
+            warning.severity = *severity;
+        }
+    }
+
+    // The sort is stable, so warnings of the same check on the same line keep their order
+    warnings.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+}
+
+// Paths from the `--exclude` argument and patterns from the `.dotenv-linterignore` file
+struct Excludes<'a> {
+    paths: &'a [PathBuf],
+    patterns: &'a [glob::Pattern],
+}
+
+impl Excludes<'_> {
+    fn contains(&self, path: &Path) -> bool {
+        self.paths.iter().any(|p| p == path) || self.patterns.iter().any(|p| p.matches_path(path))
+    }
+}
+
+// Checks skipped only in the files matching the globs, from the `--skip-in GLOB:CHECK_NAME` argument
+struct FileSkips {
+    skips: Vec<(glob::Pattern, String)>,
+}
+
+impl FileSkips {
+    fn from_values<'a>(values: impl Iterator<Item = &'a str>) -> Result<Self, String> {
+        let mut skips = Vec::new();
+        let names = checks::available_check_names();
+
+        for value in values {
+            let (glob, check_name) = value.rsplit_once(':').ok_or_else(|| {
+                format!(
+                    "Invalid --skip-in value {}, expected GLOB:CHECK_NAME",
+                    value
+                )
+            })?;
+            let pattern =
+                glob::Pattern::new(glob).map_err(|e| format!("Invalid pattern {}: {}", glob, e))?;
+            if !names.iter().any(|name| name == check_name) {
+                return Err(checks::unknown_check_error("--skip-in", check_name));
+            }
+
+            skips.push((pattern, String::from(check_name)));
+        }
+
+        Ok(Self { skips })
+    }
+
+    // The glob is matched with the path relative to the current directory or with the file name
+    fn contains(&self, warning: &Warning) -> bool {
+        let path = warning.file_path();
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+
+        self.skips.iter().any(|(pattern, check_name)| {
+            *check_name == warning.check_name
+                && (pattern.matches_path(path) || pattern.matches(file_name))
+        })
     }
+}
 
-    Ok(warnings)
+/// Saves the warnings to a baseline file, which can be passed to later runs with `--baseline`
+pub fn write_baseline(path: &Path, warnings: &[Warning]) -> Result<(), Box<dyn Error>> {
+    Baseline::new(warnings).write(path)
 }
 
+/// Fixes the content of a single file read from stdin and returns the fixed content
+pub fn fix_stdin(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+    content: &str,
+) -> Result<String, Box<dyn Error>> {
+    let config = get_config(args, current_dir)?;
+    let file_name = args.value_of("stdin-filename").unwrap_or(".env");
+
+    let (fe, strs) = match FileEntry::from_content(PathBuf::from(file_name), content) {
+        Some(f) => f,
+        None => return Ok(content.to_string()),
+    };
+
+    let mut lines = get_line_entries(&fe, strs);
+    let mut warnings = checks::run(&lines, &config);
+    fixes::run_until_fixed(&mut warnings, &mut lines, &config, |_| true);
+
+    Ok(fs_utils::get_content(
+        &lines,
+        fs_utils::get_line_ending(content),
+    ))
+}
+
+/// Reads the config file and merges it with the command line arguments
+fn get_config(args: &clap::ArgMatches, current_dir: &Path) -> Result<Config, Box<dyn Error>> {
+    let mut config = match args.value_of("config") {
+        Some(path) => Config::from_file(Path::new(path))?,
+        None if args.is_present("no-config") => Config::default(),
+        None => Config::find(current_dir)?,
+    };
+    config.validate()?;
+    config.apply_profile(args.value_of("profile"))?;
+
+    // The skip list of the config file is ignored, the checks from `--skip` are still skipped
+    if args.is_present("no-skip") {
+        config.skip.clear();
+    }
+
+    if let Some(skip) = args.values_of("skip") {
+        config.skip.extend(skip.map(String::from));
+    }
+
+    if let Some(enable) = args.values_of("enable") {
+        config.enable.extend(enable.map(String::from));
+    }
+
+    if let Some(only) = args.values_of("only") {
+        let names = checks::available_check_names();
+        for name in only {
+            if !names.iter().any(|n| n == name) {
+                return Err(checks::unknown_check_error("--only", name).into());
+            }
+            config.only.push(String::from(name));
+        }
+    }
+
+    if args.is_present("shell-safe") {
+        config.enable.push(String::from("ShellUnsafeValue"));
+    }
+
+    if let Some(patterns) = args.values_of("pattern") {
+        config.patterns.extend(patterns.map(String::from));
+    }
+
+    // `--recursive` and `--no-recursive` take precedence over the `recursive` setting of the config
+    if args.is_present("recursive") {
+        config.recursive = true;
+    } else if args.is_present("no-recursive") {
+        config.recursive = false;
+    }
+
+    if args.is_present("short-circuit") {
+        config.short_circuit = true;
+    }
+
+    if args.is_present("skip-generated") {
+        config.generated.skip = true;
+    }
+
+    // The `--error` argument takes precedence over the severities from the config
+    if let Some(errors) = args.values_of("error") {
+        config
+            .severity
+            .extend(errors.map(|name| (String::from(name), Severity::Error)));
+    }
+
+    Ok(config)
+}
+
+/// Reads the diff with the changed lines, when only they should be checked
+fn get_changed_lines(
+    args: &clap::ArgMatches,
+    current_dir: &Path,
+) -> Result<Option<ChangedLines>, Box<dyn Error>> {
+    if !args.is_present("diff-only") {
+        return Ok(None);
+    }
+
+    if let Some(since) = args.value_of("since") {
+        return ChangedLines::from_git(current_dir, since).map(Some);
+    }
+
+    let mut diff = String::new();
+    io::stdin().read_to_string(&mut diff)?;
+
+    Ok(Some(ChangedLines::parse(&diff)))
+}
+
+// A file to check: a path on the filesystem, a passed path which can't be found with the
+// reason, or a `REV:PATH` git object with its content
+enum Source<'a> {
+    File(PathBuf),
+    Missing(PathBuf, String),
+    Git(&'a str, String),
+}
+
+/// Reads the content of a `REV:PATH` git object (`--from-git`) with `git cat-file` in `current_dir`
+fn read_git_object(current_dir: &Path, object: &str) -> Result<String, Box<dyn Error>> {
+    if !object.contains(':') {
+        return Err(format!("Invalid --from-git {}: expected REV:PATH", object).into());
+    }
+
+    let output = Command::new("git")
+        .args(["cat-file", "blob", object])
+        .current_dir(current_dir)
+        .output()
+        .map_err(|e| format!("Failed to run git cat-file: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to read {} from git: {}",
+            object,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+        .into());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// The warnings of a git object are labeled with `REV:PATH`, while the checks depending on
+// the file name get the name of the file in the path
+fn git_object_line_entries(object: &str, content: &str) -> Option<Vec<LineEntry>> {
+    let (_, path) = object.split_once(':')?;
+    let (mut fe, strs) = FileEntry::from_content(PathBuf::from(object), content)?;
+    fe.file_name = Path::new(path).file_name()?.to_str()?.to_string();
+
+    Some(get_line_entries(&fe, strs))
+}
+
+// Files passed explicitly are always checked, the patterns are used only for files in directories
 fn get_file_paths(
     dir_entries: Vec<PathBuf>,
-    excludes: &[PathBuf],
+    excludes: &Excludes,
+    patterns: &[glob::Pattern],
     is_recursive: bool,
+    is_compose: bool,
 ) -> Vec<PathBuf> {
     let nested_paths: Vec<PathBuf> = dir_entries
         .iter()
@@ -74,13 +1000,23 @@ fn get_file_paths(
         .filter(|entry| !excludes.contains(entry))
         .filter_map(|dir| dir.read_dir().ok())
         .map(|read_dir| {
-            read_dir
+            // The order of directory entries depends on the platform and the file system
+            let mut paths: Vec<PathBuf> = read_dir
                 .filter_map(|e| e.ok())
                 .map(|e| e.path())
-                .filter(|path| FileEntry::is_env_file(path) || (is_recursive && path.is_dir()))
-                .collect()
+                .filter(|path| {
+                    is_env_file(path, patterns)
+                        || (is_compose && compose::is_compose_file(path))
+                        || (is_recursive && path.is_dir())
+                })
+                .collect();
+
+            paths.sort();
+            paths
+        })
+        .flat_map(|dir_entries| {
+            get_file_paths(dir_entries, excludes, patterns, is_recursive, is_compose)
         })
-        .flat_map(|dir_entries| get_file_paths(dir_entries, excludes, is_recursive))
         .collect();
 
     let mut file_paths: Vec<PathBuf> = dir_entries
@@ -95,6 +1031,99 @@ fn get_file_paths(
     file_paths
 }
 
+/// Checks a file name with the patterns, or with the `.env` pattern when there are none
+fn is_env_file(path: &Path, patterns: &[glob::Pattern]) -> bool {
+    if patterns.is_empty() {
+        return FileEntry::is_env_file(path);
+    }
+
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+    path.is_file() && patterns.iter().any(|p| p.matches(file_name))
+}
+
+/// Checks if one of the first lines of a file matches the marker of the generated files
+fn is_generated(lines: &[String], generated: &GeneratedConfig) -> bool {
+    let marker = match glob::Pattern::new(&generated.marker) {
+        Ok(marker) => marker,
+        Err(_) => return false,
+    };
+    let options = glob::MatchOptions {
+        case_sensitive: false,
+        ..glob::MatchOptions::new()
+    };
+
+    lines
+        .iter()
+        .take(generated.lines)
+        .any(|line| marker.matches_with(line.trim(), options))
+}
+
+/// Reads the file and transcodes it to UTF-8, the reason is returned if it can't be read
+fn read_file(path: &Path, encoding: Encoding, stats: &mut Stats) -> Result<String, String> {
+    let bytes = fs::read(path).map_err(|e| e.to_string())?;
+    let (content, is_replaced) = match encoding.decode(&bytes) {
+        Some(decoded) => decoded,
+        None => {
+            return Err(String::from(
+                "the file can't be read as text, use --encoding if it is not in UTF-8",
+            ))
+        }
+    };
+
+    if is_replaced {
+        stats.replaced.push(path.to_path_buf());
+    }
+
+    Ok(content)
+}
+
+/// Returns the entries of each `environment:` block of a docker-compose file. The blocks
+/// belong to different services, so each of them is checked like a separate file
+fn compose_line_entries(
+    path: PathBuf,
+    encoding: Encoding,
+    stats: &mut Stats,
+) -> Vec<Vec<LineEntry>> {
+    let content = match read_file(&path, encoding, stats) {
+        Ok(content) => content,
+        Err(reason) => {
+            stats.skipped.push((path, reason));
+            return Vec::new();
+        }
+    };
+
+    stats.files += 1;
+    let file_name = path
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    compose::extract(&content)
+        .into_iter()
+        .map(|entries| {
+            // The last entry of the block ends the "file" for the checks with a file-level pass
+            let file = FileEntry {
+                path: path.clone(),
+                file_name: file_name.clone(),
+                total_lines: entries.last().map_or(0, |e| e.number),
+            };
+            stats.lines += entries.len();
+
+            entries
+                .into_iter()
+                .map(|entry| LineEntry {
+                    number: entry.number,
+                    file: file.clone(),
+                    raw_string: entry.line,
+                })
+                .collect()
+        })
+        .collect()
+}
+
 fn get_line_entries(fe: &FileEntry, lines: Vec<String>) -> Vec<LineEntry> {
     let mut entries: Vec<LineEntry> = Vec::with_capacity(fe.total_lines);
 
@@ -108,3 +1137,170 @@ fn get_line_entries(fe: &FileEntry, lines: Vec<String>) -> Vec<LineEntry> {
 
     entries
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lint_str_test() {
+        let warnings = lint_str("BAR=1\nBAR=2\nfoo=bar", ".env.test");
+        let actual: Vec<String> = warnings.iter().map(|w| w.to_string()).collect();
+
+        assert_eq!(
+            vec![
+                ".env.test:2 [error] DuplicatedKey: The BAR key is duplicated with a different value",
+                ".env.test:3 EndingBlankLine: No blank line at the end of the file",
+                ".env.test:3 LowercaseKey: The foo key should be in uppercase",
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn dedup_warnings_test() {
+        let (fe, strs) =
+            FileEntry::from_content(PathBuf::from(".env"), "foo=bar\nbar=foo").expect("file entry");
+        let lines = get_line_entries(&fe, strs);
+        let warning = |number: usize, message: &str| {
+            Warning::new(
+                lines[number - 1].clone(),
+                "LowercaseKey",
+                String::from(message),
+            )
+        };
+        let mut warnings = vec![
+            warning(1, "The foo key should be in uppercase"),
+            warning(1, "Another message"),
+            warning(1, "The foo key should be in uppercase"),
+            warning(2, "The foo key should be in uppercase"),
+        ];
+        dedup_warnings(&mut warnings);
+
+        let actual: Vec<String> = warnings.iter().map(|w| w.to_string()).collect();
+        assert_eq!(
+            vec![
+                ".env:1 LowercaseKey: The foo key should be in uppercase",
+                ".env:1 LowercaseKey: Another message",
+                ".env:2 LowercaseKey: The foo key should be in uppercase",
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn number_occurrences_test() {
+        let warnings = lint_str("foo=bar\nfoo=bar\n", ".env");
+        let fingerprints: Vec<(usize, String)> = warnings
+            .iter()
+            .filter(|w| w.check_name == "LowercaseKey")
+            .map(|w| (w.occurrence, w.fingerprint()))
+            .collect();
+
+        assert_eq!(
+            vec![0, 1],
+            fingerprints.iter().map(|(o, _)| *o).collect::<Vec<_>>()
+        );
+        assert_ne!(fingerprints[0].1, fingerprints[1].1);
+    }
+
+    #[test]
+    fn sort_by_check_test() {
+        let (fe, strs) =
+            FileEntry::from_content(PathBuf::from(".env"), "foo=bar\nbar=foo").expect("file entry");
+        let lines = get_line_entries(&fe, strs);
+        let warning = |number: usize, check_name: &str| {
+            Warning::new(
+                lines[number - 1].clone(),
+                check_name,
+                String::from("Message"),
+            )
+        };
+        let mut warnings = vec![
+            warning(1, "UnorderedKey"),
+            warning(1, "LowercaseKey"),
+            warning(2, "UnorderedKey"),
+            warning(2, "LowercaseKey"),
+        ];
+        sort_by_check(&mut warnings);
+
+        let actual: Vec<String> = warnings.iter().map(|w| w.to_string()).collect();
+        assert_eq!(
+            vec![
+                ".env:1 LowercaseKey: Message",
+                ".env:2 LowercaseKey: Message",
+                ".env:1 UnorderedKey: Message",
+                ".env:2 UnorderedKey: Message",
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn group_by_file_test() {
+        let file = |path: &str| {
+            FileEntry::from_content(PathBuf::from(path), "foo=bar\n")
+                .expect("file entry")
+                .0
+        };
+        let warning = |path: &str| {
+            Warning::new(
+                get_line_entries(&file(path), vec![String::from("foo=bar")]).remove(0),
+                "LowercaseKey",
+                String::from("The foo key should be in uppercase"),
+            )
+        };
+        let results = group_by_file(
+            vec![warning("b/.env"), warning("b/.env")],
+            vec![file("b/.env"), file("a/.env"), file("b/.env")],
+            vec![FileResult::from_error(
+                PathBuf::from("a/.env.local"),
+                String::from("invalid data"),
+            )],
+        );
+
+        let actual: Vec<(String, usize, bool)> = results
+            .iter()
+            .map(|r| (r.file.to_string(), r.warnings.len(), r.error.is_some()))
+            .collect();
+        assert_eq!(
+            vec![
+                (String::from("a/.env"), 0, false),
+                (String::from("a/.env.local"), 0, true),
+                (String::from("b/.env"), 2, false),
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn is_generated_test() {
+        let generated = GeneratedConfig::default();
+        let asserts = vec![
+            ("# GENERATED - DO NOT EDIT\nFOO=bar", true),
+            ("# Code generated by a tool. DO NOT EDIT.", true),
+            (
+                "#!/bin/sh\n# This file is auto-generated, do not edit it\nFOO=bar",
+                true,
+            ),
+            ("FOO=bar\n# generated once, edit it freely", false),
+            ("A=1\nB=2\nC=3\nD=4\nE=5\n# GENERATED - DO NOT EDIT", false),
+        ];
+
+        for (content, expected) in asserts {
+            let lines: Vec<String> = content.lines().map(String::from).collect();
+            assert_eq!(
+                expected,
+                is_generated(&lines, &generated),
+                "Content: {}",
+                content
+            );
+        }
+    }
+
+    #[test]
+    fn lint_str_without_warnings_test() {
+        assert!(lint_str("FOO=bar\n", ".env").is_empty());
+        assert!(lint_str("foo=bar\n", "").is_empty());
+    }
+}
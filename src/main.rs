@@ -1,8 +1,15 @@
 use clap::Arg;
+use dotenv_linter::{FileResult, OutputTemplate, Warning};
 use std::error::Error;
 use std::ffi::OsStr;
+use std::io::{self, Read};
+use std::path::Path;
+use std::time::Instant;
 use std::{env, process};
 
+/// The exit code of a run with files which can't be read
+const ERROR_EXIT_CODE: i32 = 2;
+
 fn main() -> Result<(), Box<dyn Error>> {
     let current_dir = env::current_dir()?;
     let args = get_args(current_dir.as_os_str());
@@ -14,41 +21,434 @@ fn main() -> Result<(), Box<dyn Error>> {
         process::exit(0);
     }
 
+    if args.is_present("print-config-schema") {
+        print!("{}", dotenv_linter::CONFIG_SCHEMA);
+        process::exit(0);
+    }
+
+    if let Some(check_name) = args.value_of("explain") {
+        match dotenv_linter::explain(check_name) {
+            Some(explanation) => println!("{}", explanation),
+            None => {
+                eprintln!("Unknown check: {}\n\nAvailable checks:", check_name);
+                dotenv_linter::available_check_names()
+                    .iter()
+                    .for_each(|name| eprintln!("{}", name));
+                process::exit(1);
+            }
+        }
+        process::exit(0);
+    }
+
+    if args.is_present("print-config") {
+        let config = dotenv_linter::effective_config(&args, &current_dir)?;
+        match args.value_of("format") {
+            Some("json") => println!("{}", dotenv_linter::format_config_json(&config)),
+            _ => print!("{}", dotenv_linter::format_config(&config)),
+        }
+        process::exit(0);
+    }
+
+    if args.is_present("dump") {
+        let files = dotenv_linter::dump(&args, &current_dir)?;
+        match args.value_of("format") {
+            Some("json") => println!("{}", dotenv_linter::format_dump_json(&files)),
+            _ if files.is_empty() => {}
+            _ => println!("{}", dotenv_linter::format_dump(&files)),
+        }
+        process::exit(0);
+    }
+
+    if args.is_present("merge") {
+        let keys = dotenv_linter::merge(&args, &current_dir)?;
+        match args.value_of("format") {
+            Some("json") => println!("{}", dotenv_linter::format_merge_json(&keys)),
+            _ if keys.is_empty() => {}
+            _ => println!("{}", dotenv_linter::format_merge(&keys)),
+        }
+
+        if !args.is_present("lint-merged") {
+            process::exit(0);
+        }
+
+        let warnings = dotenv_linter::lint_merged(&args, &current_dir, &keys)?;
+        if warnings.is_empty() {
+            process::exit(0);
+        }
+
+        println!();
+        let warnings: Vec<&Warning> = warnings.iter().collect();
+        print_warnings(&warnings, false, None, &mut 0);
+        print_total(warnings.len());
+
+        let exit_code = (is_failure(&warnings, None) && !args.is_present("exit-zero")) as i32;
+        process::exit(exit_code);
+    }
+
+    if args.is_present("fix-stdin") {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content)?;
+        print!(
+            "{}",
+            dotenv_linter::fix_stdin(&args, &current_dir, &content)?
+        );
+        process::exit(0);
+    }
+
     let is_fix = args.is_present("fix");
+    let format = args.value_of("format").unwrap_or("text");
+    let is_json = format == "json";
 
-    let warnings = dotenv_linter::run(&args, &current_dir)?;
+    if args.is_present("summary-only") && !is_json {
+        clap::Error::with_description(
+            "The --summary-only flag can only be used with --format json",
+            clap::ErrorKind::ArgumentConflict,
+        )
+        .exit();
+    }
 
-    if warnings.is_empty() {
+    if args.is_present("include-source") && !is_json && !args.is_present("json-file") {
+        clap::Error::with_description(
+            "The --include-source flag can only be used with --format json or --json-file",
+            clap::ErrorKind::ArgumentConflict,
+        )
+        .exit();
+    }
+
+    if args.is_present("count") && format != "text" {
+        clap::Error::with_description(
+            &format!("The --count flag can't be used with --format {}", format),
+            clap::ErrorKind::ArgumentConflict,
+        )
+        .exit();
+    }
+
+    let is_grouped = args.is_present("group-by-file");
+    let is_by_check = args.value_of("sort-output") == Some("by-check");
+
+    // The default order is the same as the order of `--group-by-file`, only `by-check` conflicts
+    if is_by_check && is_grouped {
+        clap::Error::with_description(
+            "The --group-by-file flag can't be used with --sort-output by-check",
+            clap::ErrorKind::ArgumentConflict,
+        )
+        .exit();
+    }
+    // The text output is printed as the files are checked, the other outputs need all the warnings
+    let is_streamed = format == "text"
+        && !is_fix
+        && !args.is_present("count")
+        && !args.is_present("no-stream")
+        && !is_by_check
+        && !args.is_present("write-baseline")
+        && !args.is_present("patch");
+    let mut printed_groups = 0;
+
+    if args.is_present("output-template") && format != "text" {
+        clap::Error::with_description(
+            &format!(
+                "The --output-template option can't be used with --format {}",
+                format
+            ),
+            clap::ErrorKind::ArgumentConflict,
+        )
+        .exit();
+    }
+
+    // The template is checked before the run, so a typo doesn't waste a long run
+    let template = match args.value_of("output-template").map(OutputTemplate::parse) {
+        Some(Ok(template)) => Some(template),
+        Some(Err(e)) => clap::Error::with_description(&e, clap::ErrorKind::InvalidValue).exit(),
+        None => None,
+    };
+
+    let started = Instant::now();
+    // The results of the files are only gathered when the output is not streamed
+    let (mut warnings, results, stats) = if is_streamed {
+        let (warnings, stats) =
+            dotenv_linter::run_streaming(&args, &current_dir, &mut |warnings| {
+                let warnings: Vec<&Warning> = warnings.iter().collect();
+                print_warnings(
+                    &warnings,
+                    is_grouped,
+                    template.as_ref(),
+                    &mut printed_groups,
+                );
+            })?;
+        (warnings, Vec::new(), stats)
+    } else {
+        let (results, stats) = dotenv_linter::lint_files_with_stats(&args, &current_dir)?;
+        let warnings: Vec<Warning> = results
+            .iter()
+            .flat_map(|r| r.warnings.iter().cloned())
+            .collect();
+        (warnings, results, stats)
+    };
+
+    // The checkstyle output groups the warnings by files, so it keeps their order
+    if is_by_check && format != "checkstyle" {
+        dotenv_linter::sort_by_check(&mut warnings);
+    }
+
+    if let Some(path) = args.value_of("write-baseline") {
+        dotenv_linter::write_baseline(Path::new(path), &warnings)?;
+        println!(
+            "Baseline with {} warning(s) written to {}",
+            warnings.len(),
+            path
+        );
+        process::exit(0);
+    }
+
+    if args.is_present("patch") {
+        print!("{}", stats.patch);
         process::exit(0);
     }
 
+    let include_source = args.is_present("include-source");
+    // The report is written with the same warnings as the output, the checks are not run again
+    if let Some(path) = args.value_of("json-file") {
+        dotenv_linter::write_json(Path::new(path), &warnings, include_source)?;
+    }
+
+    for (path, reason) in &stats.skipped {
+        eprintln!("Skipped {}: {}", path.display(), reason);
+    }
+
+    if args.is_present("verbose") {
+        for path in &stats.generated {
+            eprintln!("Skipped the generated file {}", path.display());
+        }
+    }
+
+    for path in &stats.replaced {
+        eprintln!(
+            "Some characters of {} can't be decoded, they were replaced with U+FFFD",
+            path.display()
+        );
+    }
+
+    // Statistics are printed to stderr to keep stdout machine-readable
+    if args.is_present("stats") {
+        eprintln!("{}", stats);
+        eprintln!("Duration: {:?}", started.elapsed());
+    }
+
+    // Warnings suppressed by control comments fail the run in the strict mode
+    let is_strict_failure = args.is_present("strict-ignores") && stats.suppressed > 0;
+    if args.is_present("strict-ignores") {
+        eprintln!("Suppressed by ignore comments: {}", stats.suppressed);
+    }
+
+    let max_warnings = args
+        .value_of("max-warnings")
+        .and_then(|v| v.parse::<usize>().ok());
+    let unfixed: Vec<&Warning> = warnings.iter().filter(|w| !w.is_fixed).collect();
+    let is_failed = is_strict_failure || is_failure(&unfixed, max_warnings);
+    // Files which can't be read fail the run with their own exit code, the other files are
+    // still checked unless `--fail-on-error` stopped the run
+    let exit_code = if !stats.skipped.is_empty() {
+        ERROR_EXIT_CODE
+    } else {
+        (is_failed && !args.is_present("exit-zero")) as i32
+    };
+
+    if args.is_present("count") {
+        println!("{}", unfixed.len());
+        process::exit(exit_code);
+    }
+
+    if is_json {
+        println!(
+            "{}",
+            dotenv_linter::format_json(&warnings, args.is_present("summary-only"), include_source)
+        );
+        process::exit(exit_code);
+    }
+
+    if format == "checkstyle" {
+        println!("{}", dotenv_linter::format_checkstyle(&warnings));
+        process::exit(exit_code);
+    }
+
+    if format == "tap" {
+        println!("{}", dotenv_linter::format_tap(&warnings));
+        process::exit(exit_code);
+    }
+
+    if format == "lsp-json" {
+        println!("{}", dotenv_linter::format_lsp_json(&warnings));
+        process::exit(exit_code);
+    }
+
+    if warnings.is_empty() {
+        process::exit(exit_code);
+    }
+
     if is_fix {
-        if warnings.iter().any(|w| w.is_fixed) {
+        if results.iter().any(|r| r.is_fixed()) {
             println!("Fixed warnings:");
-            warnings
-                .iter()
-                .filter(|w| w.is_fixed)
-                .for_each(|w| println!("{}", w));
+            if is_by_check {
+                print_by_check(&warnings, |w| w.is_fixed, template.as_ref());
+            } else {
+                print_results(&results, |w| w.is_fixed, is_grouped, template.as_ref());
+            }
         }
 
-        if warnings.iter().any(|w| !w.is_fixed) {
+        if !unfixed.is_empty() {
             println!("\nUnfixed warnings:");
-            warnings
-                .iter()
-                .filter(|w| !w.is_fixed)
-                .for_each(|w| println!("{}", w));
-        } else {
-            process::exit(0);
+            if is_by_check {
+                print_by_check(&warnings, |w| !w.is_fixed, template.as_ref());
+            } else {
+                print_results(&results, |w| !w.is_fixed, is_grouped, template.as_ref());
+            }
         }
     } else {
-        warnings.iter().for_each(|w| println!("{}", w));
+        if is_by_check {
+            print_by_check(&warnings, |w| !w.is_fixed, template.as_ref());
+        } else if !is_streamed {
+            print_results(&results, |w| !w.is_fixed, is_grouped, template.as_ref());
+        }
 
         if !args.is_present("quiet") {
             print_total(warnings.len());
         }
     }
 
-    process::exit(1);
+    process::exit(exit_code);
+}
+
+/// Errors always fail the run, warnings fail it when there are more than `max_warnings` of them
+fn is_failure(warnings: &[&Warning], max_warnings: Option<usize>) -> bool {
+    let errors = warnings.iter().filter(|w| w.is_error()).count();
+    let warnings = warnings.len() - errors;
+
+    errors > 0 || warnings > max_warnings.unwrap_or(0)
+}
+
+// `printed_groups` is the number of the files already printed by previous calls when streaming
+fn print_warnings(
+    warnings: &[&Warning],
+    is_grouped: bool,
+    template: Option<&OutputTemplate>,
+    printed_groups: &mut usize,
+) {
+    if !is_grouped {
+        warnings.iter().for_each(|w| print_warning(w, template));
+        return;
+    }
+
+    // Files are printed in the order of their first warning, warnings of a file are sorted by lines
+    let mut groups: Vec<Vec<&Warning>> = Vec::new();
+    for &warning in warnings {
+        match groups
+            .iter_mut()
+            .find(|g| g[0].file_path() == warning.file_path())
+        {
+            Some(group) => group.push(warning),
+            None => groups.push(vec![warning]),
+        }
+    }
+
+    for group in groups.iter_mut() {
+        if *printed_groups > 0 {
+            println!();
+        }
+        *printed_groups += 1;
+
+        group.sort_by_key(|w| w.line_number());
+        print_group(&group[0].file_path().display().to_string(), group);
+    }
+}
+
+// Prints the warnings of the file results which pass the filter, the other files are skipped
+fn print_results(
+    results: &[FileResult],
+    filter: fn(&Warning) -> bool,
+    is_grouped: bool,
+    template: Option<&OutputTemplate>,
+) {
+    let mut printed_groups = 0;
+
+    for result in results {
+        let warnings: Vec<&Warning> = result.warnings.iter().filter(|w| filter(w)).collect();
+        if warnings.is_empty() {
+            continue;
+        }
+
+        if !is_grouped {
+            warnings.iter().for_each(|w| print_warning(w, template));
+            continue;
+        }
+
+        if printed_groups > 0 {
+            println!();
+        }
+        printed_groups += 1;
+        print_group(&result.file.to_string(), &warnings);
+    }
+}
+
+// Prints the warnings sorted by checks which pass the filter, under the name of each check
+fn print_by_check(
+    warnings: &[Warning],
+    filter: fn(&Warning) -> bool,
+    template: Option<&OutputTemplate>,
+) {
+    let warnings: Vec<&Warning> = warnings.iter().filter(|w| filter(w)).collect();
+
+    for (index, group) in warnings
+        .chunk_by(|a, b| a.check_name == b.check_name)
+        .enumerate()
+    {
+        if index > 0 {
+            println!();
+        }
+
+        println!("{} ({})", group[0].check_name, group.len());
+        group.iter().for_each(|w| {
+            match template {
+                Some(template) => println!("  {}", template.format(w)),
+                None => println!("  {}", w),
+            }
+            print_resolved(w, "    ");
+            print_suggestion(w, "    ");
+        });
+    }
+}
+
+fn print_warning(warning: &Warning, template: Option<&OutputTemplate>) {
+    match template {
+        Some(template) => println!("{}", template.format(warning)),
+        None => println!("{}", warning),
+    }
+    print_resolved(warning, "  ");
+    print_suggestion(warning, "  ");
+}
+
+// The warnings of a group are printed under the path without it, with an indent
+fn print_group(path: &str, warnings: &[&Warning]) {
+    println!("{}", path);
+    warnings.iter().for_each(|w| {
+        println!("  {}", w.format_without_path());
+        print_resolved(w, "    ");
+        print_suggestion(w, "    ");
+    });
+}
+
+// The resolved value is prefixed with `=`, like the result of an assignment
+fn print_resolved(warning: &Warning, indent: &str) {
+    if let Some(value) = &warning.resolved {
+        println!("{}= {}", indent, value);
+    }
+}
+
+// The lines are prefixed with `>` to tell the suggested lines from the warnings
+fn print_suggestion(warning: &Warning, indent: &str) {
+    warning
+        .suggestion
+        .iter()
+        .for_each(|line| println!("{}> {}", indent, line));
 }
 
 fn print_total(total: usize) {
@@ -58,10 +458,10 @@ fn print_total(total: usize) {
         problems += "s";
     }
 
-    println!("\n{}", format!("Found {} {}", total, problems));
+    println!("\nFound {} {}", total, problems);
 }
 
-fn get_args(current_dir: &OsStr) -> clap::ArgMatches {
+fn get_args(current_dir: &OsStr) -> clap::ArgMatches<'_> {
     clap::App::new(env!("CARGO_PKG_NAME"))
         .about(env!("CARGO_PKG_DESCRIPTION"))
         .author(env!("CARGO_PKG_AUTHORS"))
@@ -84,6 +484,16 @@ fn get_args(current_dir: &OsStr) -> clap::ArgMatches {
                 .multiple(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("pattern")
+                .long("pattern")
+                .value_name("PATTERN")
+                .help(
+                    "Checks the files matching glob patterns in directories, instead of .env files",
+                )
+                .multiple(true)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("skip")
                 .short("s")
@@ -93,28 +503,369 @@ fn get_args(current_dir: &OsStr) -> clap::ArgMatches {
                 .multiple(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("skip-in")
+                .long("skip-in")
+                .value_name("GLOB:CHECK_NAME")
+                .help("Skips the check only in the files matching the glob")
+                .multiple(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("no-skip")
+                .long("no-skip")
+                .help("Runs the checks skipped in the config file, --skip still applies"),
+        )
+        .arg(
+            Arg::with_name("enable")
+                .long("enable")
+                .value_name("CHECK_NAME")
+                .help("Enables optional checks")
+                .multiple(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("only")
+                .long("only")
+                .value_name("CHECK_NAME")
+                .help("Runs only the checks (optional ones too), --skip still applies to them")
+                .use_delimiter(true)
+                .multiple(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("shell-safe")
+                .long("shell-safe")
+                .help("Enables the ShellUnsafeValue check for files which are sourced by a shell"),
+        )
+        .arg(
+            Arg::with_name("error")
+                .long("error")
+                .value_name("CHECK_NAME")
+                .help("Reports warnings of the checks as errors")
+                .multiple(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("max-warnings")
+                .long("max-warnings")
+                .value_name("COUNT")
+                .help(
+                    "Sets the number of warnings allowed before the run fails (errors always fail)",
+                )
+                .validator(|v| v.parse::<usize>().map(|_| ()).map_err(|e| e.to_string()))
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("baseline")
+                .long("baseline")
+                .value_name("FILE_NAME")
+                .help("Doesn't report warnings saved in the baseline file")
+                .conflicts_with("write-baseline")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("baseline-ignore-lines")
+                .long("baseline-ignore-lines")
+                .help("Matches warnings with a baseline without fingerprints by files, checks and messages only")
+                .requires("baseline"),
+        )
+        .arg(
+            Arg::with_name("path-base")
+                .long("path-base")
+                .value_name("DIR")
+                .help("Shows the paths in the output relative to the directory, instead of the current one")
+                .conflicts_with("write-baseline")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("write-baseline")
+                .long("write-baseline")
+                .value_name("FILE_NAME")
+                .help("Saves the warnings to the baseline file")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("config")
+                .short("c")
+                .long("config")
+                .value_name("FILE_NAME")
+                .help("Sets a config file (default: .dotenv-linter.yml, .toml or .json)")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("no-config")
+                .long("no-config")
+                .help("Doesn't look for a config file, the default settings are used")
+                .conflicts_with("config"),
+        )
+        .arg(
+            Arg::with_name("profile")
+                .long("profile")
+                .value_name("NAME")
+                .help("Uses the settings of the profile from the config file (default: the default profile, if any)")
+                .takes_value(true)
+                .conflicts_with("no-config"),
+        )
         .arg(
             Arg::with_name("show-checks")
                 .long("show-checks")
                 .help("Shows list of available checks"),
         )
+        .arg(
+            Arg::with_name("print-config-schema")
+                .long("print-config-schema")
+                .help("Prints the JSON Schema of the config file"),
+        )
+        .arg(
+            Arg::with_name("print-config")
+                .long("print-config")
+                .help("Prints the settings of the run (the config file with the profile and the arguments applied) and the checks which are run, as YAML or with --format json")
+                .conflicts_with_all(&["fix", "patch", "count", "write-baseline", "dump", "merge"]),
+        )
+        .arg(
+            Arg::with_name("explain")
+                .long("explain")
+                .value_name("CHECK_NAME")
+                .help("Shows a detailed description of the check")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("dump")
+                .long("dump")
+                .help("Prints the keys and the values of the files as they are parsed, without checking them")
+                .conflicts_with_all(&["fix", "patch", "count", "write-baseline"]),
+        )
+        .arg(
+            Arg::with_name("merge")
+                .long("merge")
+                .help("Merges the passed files in their order (the later files override the earlier ones) and prints the keys with the files of their values")
+                .conflicts_with_all(&["fix", "patch", "count", "write-baseline", "dump"]),
+        )
+        .arg(
+            Arg::with_name("lint-merged")
+                .long("lint-merged")
+                .help("Checks the merged keys as one file, with --merge")
+                .requires("merge")
+                .conflicts_with("format"),
+        )
         .arg(
             Arg::with_name("recursive")
                 .short("r")
                 .long("recursive")
                 .help("Recursively search and check .env files"),
         )
+        .arg(
+            Arg::with_name("no-recursive")
+                .long("no-recursive")
+                .help("Checks only the top-level .env files of the directories, even if the config sets `recursive`")
+                .conflicts_with("recursive"),
+        )
+        .arg(
+            Arg::with_name("skip-generated")
+                .long("skip-generated")
+                .help("Skips the files with a generated marker in their first lines, e.g. `# GENERATED - DO NOT EDIT`"),
+        )
+        .arg(
+            Arg::with_name("changed-within")
+                .long("changed-within")
+                .value_name("DURATION")
+                .help("Checks only the files modified within the duration, e.g. 10m (s, m, h or d)")
+                .validator(|v| dotenv_linter::parse_duration(&v).map(|_| ()))
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("ignore-missing-files")
+                .long("ignore-missing-files")
+                .help("Skips the paths which don't exist without a notice"),
+        )
+        .arg(
+            Arg::with_name("encoding")
+                .long("encoding")
+                .value_name("ENCODING")
+                .help("Sets the encoding of the files, they are read as UTF-8 by default")
+                .possible_values(&dotenv_linter::Encoding::NAMES)
+                .conflicts_with("fix")
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("fix")
                 .short("f")
                 .long("fix")
                 .help("Automatically fixes warnings if possible"),
         )
+        .arg(
+            Arg::with_name("patch")
+                .long("patch")
+                .help("Writes the fixes as a unified diff to stdout, without changing the files")
+                .conflicts_with_all(&["fix", "write-baseline"]),
+        )
+        .arg(
+            Arg::with_name("fix-stdin")
+                .long("fix-stdin")
+                .help("Fixes a file read from stdin and writes the fixed content to stdout"),
+        )
+        .arg(
+            Arg::with_name("stdin-filename")
+                .long("stdin-filename")
+                .value_name("FILE_NAME")
+                .help("Sets the file name of the content read from stdin")
+                .requires("fix-stdin")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("from-git")
+                .long("from-git")
+                .value_name("REV:PATH")
+                .help("Checks a file from a git revision (e.g. HEAD~5:.env), without checking it out")
+                .conflicts_with_all(&["fix", "patch", "path-base"])
+                .multiple(true)
+                .number_of_values(1)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("diff-only")
+                .long("diff-only")
+                .help("Checks only the lines changed by a unified diff read from stdin"),
+        )
+        .arg(
+            Arg::with_name("since")
+                .long("since")
+                .value_name("REF")
+                .help("Reads the changed lines from `git diff REF` instead of stdin")
+                .requires("diff-only")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .value_name("FORMAT")
+                .help("Sets the output format")
+                .possible_values(&["text", "json", "checkstyle", "tap", "lsp-json"])
+                .default_value("text")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("summary-only")
+                .long("summary-only")
+                .help("Outputs only the summary of the warnings (with --format json)"),
+        )
+        .arg(
+            Arg::with_name("json-file")
+                .long("json-file")
+                .value_name("FILE_NAME")
+                .help("Also writes the warnings as a JSON report to the file")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("include-source")
+                .long("include-source")
+                .help(
+                "Includes the lines of the warnings in the JSON output (they may contain secrets)",
+            ),
+        )
+        .arg(
+            Arg::with_name("short-circuit")
+                .long("short-circuit")
+                .help("Skips the warnings of lower-priority checks on lines with another warning"),
+        )
+        .arg(
+            Arg::with_name("compose")
+                .long("compose")
+                .help("Also checks the environment blocks of docker-compose files")
+                .conflicts_with_all(&["fix", "fix-stdin"]),
+        )
+        .arg(
+            Arg::with_name("suggest")
+                .long("suggest")
+                .help("Shows the lines suggested by the fixers, without changing the files")
+                .conflicts_with("fix"),
+        )
+        .arg(
+            Arg::with_name("resolve")
+                .long("resolve")
+                .help("Shows the values of the lines of the warnings with the substitutions resolved within the file")
+                .conflicts_with("fix"),
+        )
+        .arg(
+            Arg::with_name("use-env")
+                .long("use-env")
+                .help("Resolves the keys which aren't defined in the file from the environment (with --resolve)")
+                .requires("resolve"),
+        )
+        .arg(
+            Arg::with_name("count")
+                .long("count")
+                .help("Outputs only the number of warnings")
+                .conflicts_with_all(&["group-by-file", "quiet", "summary-only"]),
+        )
+        .arg(
+            Arg::with_name("fail-fast")
+                .long("fail-fast")
+                .help("Stops at the first file with a warning and reports only the first warning")
+                .conflicts_with_all(&["fix", "write-baseline"]),
+        )
+        .arg(Arg::with_name("no-dedup").long("no-dedup").help(
+            "Keeps exact duplicates of the warnings (the same file, line, check and message)",
+        ))
+        .arg(
+            Arg::with_name("exit-zero")
+                .long("exit-zero")
+                .help("Exits with code 0 even if there are warnings, files which can't be read still exit with code 2"),
+        )
+        .arg(
+            Arg::with_name("fail-on-error")
+                .long("fail-on-error")
+                .help("Stops at the first file which can't be read, the other files are still checked by default"),
+        )
+        .arg(
+            Arg::with_name("output-template")
+                .long("output-template")
+                .value_name("TEMPLATE")
+                .help("Sets the format of the warnings in the text output, e.g. {file}:{line}:{check}: {message}")
+                .conflicts_with_all(&["count", "group-by-file"])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("no-stream")
+                .long("no-stream")
+                .help("Prints the text output after all files are checked, sorted by paths"),
+        )
+        .arg(
+            Arg::with_name("group-by-file")
+                .long("group-by-file")
+                .help("Groups warnings by files in the output"),
+        )
+        .arg(
+            Arg::with_name("sort-output")
+                .long("sort-output")
+                .value_name("ORDER")
+                .possible_values(&["by-file", "by-check"])
+                .default_value("by-file")
+                .help("Sorts the warnings by files and lines or groups them by checks")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("stats")
+                .long("stats")
+                .help("Shows statistics of the run (files, lines, time spent by each check)"),
+        )
+        .arg(
+            Arg::with_name("strict-ignores")
+                .long("strict-ignores")
+                .help("Reports warnings suppressed by ignore comments and fails if there are any"),
+        )
         .arg(
             Arg::with_name("quiet")
                 .short("q")
                 .long("quiet")
                 .help("Don't display additional information"),
         )
+        .arg(
+            Arg::with_name("verbose")
+                .long("verbose")
+                .help("Displays the files which were skipped as generated")
+                .conflicts_with("quiet"),
+        )
         .get_matches()
 }
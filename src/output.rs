@@ -0,0 +1,790 @@
+use crate::common::{Severity, Span, Warning};
+use crate::config::EffectiveConfig;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+#[derive(Serialize)]
+struct Report<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warnings: Option<Vec<JsonWarning<'a>>>,
+    summary: Summary,
+}
+
+#[derive(Serialize)]
+struct JsonWarning<'a> {
+    file: String,
+    line: usize,
+    check: &'a str,
+    message: &'a str,
+    severity: Severity,
+    fixed: bool,
+    fingerprint: String,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    suggestion: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolved: Option<&'a str>,
+    #[serde(skip_serializing_if = "<[Span]>::is_empty")]
+    spans: &'a [Span],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fix: Option<JsonFix<'a>>,
+}
+
+// The line of a fixed warning before the fix and the lines which replaced it
+#[derive(Serialize)]
+struct JsonFix<'a> {
+    before: &'a str,
+    after: &'a [String],
+}
+
+/// Aggregated counts of the warnings
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct Summary {
+    pub total: usize,
+    pub errors: usize,
+    pub checks: BTreeMap<String, usize>,
+    pub files: BTreeMap<String, usize>,
+}
+
+impl Summary {
+    pub fn new(warnings: &[Warning]) -> Self {
+        let mut summary = Self {
+            total: warnings.len(),
+            errors: warnings.iter().filter(|w| w.is_error()).count(),
+            ..Self::default()
+        };
+
+        for warning in warnings {
+            *summary
+                .checks
+                .entry(warning.check_name.clone())
+                .or_default() += 1;
+            *summary
+                .files
+                .entry(warning.file_path().display().to_string())
+                .or_default() += 1;
+        }
+
+        summary
+    }
+}
+
+/// Formats the warnings as a JSON report, `summary_only` omits the warnings list.
+/// The lines of the warnings are included only with `include_source`, since they may have secrets
+pub fn format_json(warnings: &[Warning], summary_only: bool, include_source: bool) -> String {
+    let report = Report {
+        warnings: if summary_only {
+            None
+        } else {
+            Some(
+                warnings
+                    .iter()
+                    .map(|w| JsonWarning {
+                        file: w.file_path().display().to_string(),
+                        line: w.line_number(),
+                        check: &w.check_name,
+                        message: w.message(),
+                        severity: w.severity,
+                        fixed: w.is_fixed,
+                        fingerprint: w.fingerprint(),
+                        suggestion: &w.suggestion,
+                        resolved: w.resolved.as_deref(),
+                        spans: &w.spans,
+                        source: if include_source {
+                            Some(w.source())
+                        } else {
+                            None
+                        },
+                        // The fix has the lines too, so it is hidden like the source
+                        fix: if include_source && w.is_fixed && !w.fixed_lines.is_empty() {
+                            Some(JsonFix {
+                                before: w.source(),
+                                after: &w.fixed_lines,
+                            })
+                        } else {
+                            None
+                        },
+                    })
+                    .collect(),
+            )
+        },
+        summary: Summary::new(warnings),
+    };
+
+    // The report consists of strings and numbers only, so it can't fail
+    serde_json::to_string(&report).expect("serialize report")
+}
+
+/// Writes the full JSON report of the warnings to a file, independently of the output format
+pub fn write_json(
+    path: &Path,
+    warnings: &[Warning],
+    include_source: bool,
+) -> Result<(), Box<dyn Error>> {
+    fs::write(path, format_json(warnings, false, include_source) + "\n")
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e).into())
+}
+
+/// Formats the warnings as a Checkstyle XML report, which is read by many CI systems.
+/// The warnings are grouped by files, the check names are the sources of the errors.
+/// Fixed warnings are not problems anymore, so only the unfixed ones are reported
+pub fn format_checkstyle(warnings: &[Warning]) -> String {
+    let mut xml =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n");
+    let mut current_file: Option<&Path> = None;
+
+    for warning in warnings.iter().filter(|w| !w.is_fixed) {
+        if current_file != Some(warning.file_path()) {
+            if current_file.is_some() {
+                xml.push_str("  </file>\n");
+            }
+            xml.push_str(&format!(
+                "  <file name=\"{}\">\n",
+                escape_xml(&warning.file_path().display().to_string())
+            ));
+            current_file = Some(warning.file_path());
+        }
+
+        xml.push_str(&format!(
+            "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"{}\"/>\n",
+            warning.line_number(),
+            column(warning),
+            severity_name(warning.severity),
+            escape_xml(warning.message()),
+            escape_xml(&warning.check_name)
+        ));
+    }
+
+    if current_file.is_some() {
+        xml.push_str("  </file>\n");
+    }
+    xml.push_str("</checkstyle>");
+
+    xml
+}
+
+/// Formats the warnings as a TAP version 13 stream, every warning is a test point with a YAML
+/// block of its details. Fixed warnings are passed test points, and a run without warnings
+/// is a single passed test point. The counts of the passed and failed points are at the end
+pub fn format_tap(warnings: &[Warning]) -> String {
+    let mut tap = String::from("TAP version 13\n");
+
+    if warnings.is_empty() {
+        tap.push_str("1..1\nok 1 - No problems found\n# pass 1\n# fail 0");
+        return tap;
+    }
+
+    tap.push_str(&format!("1..{}\n", warnings.len()));
+    for (index, warning) in warnings.iter().enumerate() {
+        let (status, directive) = if warning.is_fixed {
+            ("ok", " # fixed")
+        } else {
+            ("not ok", "")
+        };
+
+        // The strings are JSON-escaped, which is a valid double-quoted YAML string
+        tap.push_str(&format!(
+            "{} {} - {}:{} {}{}\n  ---\n  file: {}\n  line: {}\n  column: {}\n  check: {}\n  severity: {}\n  message: {}\n  ...\n",
+            status,
+            index + 1,
+            warning.file_path().display(),
+            warning.line_number(),
+            warning.check_name,
+            directive,
+            yaml_string(&warning.file_path().display().to_string()),
+            warning.line_number(),
+            column(warning),
+            warning.check_name,
+            severity_name(warning.severity),
+            yaml_string(warning.message())
+        ));
+    }
+
+    let fixed = warnings.iter().filter(|w| w.is_fixed).count();
+    tap.push_str(&format!(
+        "# pass {}\n# fail {}",
+        fixed,
+        warnings.len() - fixed
+    ));
+
+    tap
+}
+
+/// A position in the convention of the Language Server Protocol: the line and the character
+/// are 0-based, and the character is an offset in UTF-16 code units (the default encoding of
+/// LSP), not in bytes or in characters like the 1-based columns of the spans
+#[derive(Debug, PartialEq, Serialize)]
+pub struct LspPosition {
+    pub line: usize,
+    pub character: usize,
+}
+
+/// A range of a line, the end position is exclusive
+#[derive(Debug, PartialEq, Serialize)]
+pub struct LspRange {
+    pub start: LspPosition,
+    pub end: LspPosition,
+}
+
+/// A warning in the shape of the `Diagnostic` of the Language Server Protocol
+#[derive(Debug, PartialEq, Serialize)]
+pub struct LspDiagnostic {
+    pub range: LspRange,
+    /// 1 for errors and 2 for warnings, like `DiagnosticSeverity`
+    pub severity: u8,
+    /// The name of the check
+    pub code: String,
+    pub source: &'static str,
+    pub message: String,
+}
+
+/// The diagnostics of a file, a language server publishes them with the URI of the file
+#[derive(Debug, PartialEq, Serialize)]
+pub struct LspFile {
+    pub file: String,
+    pub diagnostics: Vec<LspDiagnostic>,
+}
+
+/// Converts the unfixed warnings to LSP diagnostics grouped by files, in the order of the warnings.
+/// The range of a warning covers its spans, or the whole line without spans
+pub fn lsp_diagnostics(warnings: &[Warning]) -> Vec<LspFile> {
+    let mut files: Vec<LspFile> = Vec::new();
+
+    for warning in warnings.iter().filter(|w| !w.is_fixed) {
+        let file = warning.file_path().display().to_string();
+        let diagnostic = LspDiagnostic {
+            range: lsp_range(warning),
+            severity: match warning.severity {
+                Severity::Error => 1,
+                Severity::Warning => 2,
+            },
+            code: warning.check_name.clone(),
+            source: "dotenv-linter",
+            message: warning.message().to_string(),
+        };
+
+        match files.iter_mut().find(|f| f.file == file) {
+            Some(lsp_file) => lsp_file.diagnostics.push(diagnostic),
+            None => files.push(LspFile {
+                file,
+                diagnostics: vec![diagnostic],
+            }),
+        }
+    }
+
+    files
+}
+
+/// Formats the warnings as JSON, an array of the files with their LSP diagnostics
+pub fn format_lsp_json(warnings: &[Warning]) -> String {
+    // The diagnostics consist of strings and numbers only, so it can't fail
+    serde_json::to_string(&lsp_diagnostics(warnings)).expect("serialize diagnostics")
+}
+
+// The spans are counted in characters from 1, the LSP characters in UTF-16 code units from 0
+fn lsp_range(warning: &Warning) -> LspRange {
+    let source = warning.source().trim_end_matches(['\r', '\n']);
+    let (start, end) = match (warning.spans.first(), warning.spans.last()) {
+        (Some(first), Some(last)) => (first.column - 1, last.column - 1 + last.length),
+        _ => (0, source.chars().count()),
+    };
+    let utf16_offset =
+        |chars: usize| -> usize { source.chars().take(chars).map(char::len_utf16).sum() };
+    let line = warning.line_number() - 1;
+
+    LspRange {
+        start: LspPosition {
+            line,
+            character: utf16_offset(start),
+        },
+        end: LspPosition {
+            line,
+            character: utf16_offset(end),
+        },
+    }
+}
+
+fn yaml_string(value: &str) -> String {
+    serde_json::to_string(value).expect("serialize string")
+}
+
+// Escapes the characters which can't be in the attribute values of XML
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+// The column of a warning is the column of its first span, or 1 without spans
+fn column(warning: &Warning) -> usize {
+    warning.spans.first().map_or(1, |span| span.column)
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+/// A key of a file with its value as the linter reads it, without quotes and comments (`--dump`)
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DumpedKey {
+    pub line: usize,
+    pub key: String,
+    pub value: String,
+    pub exported: bool,
+}
+
+/// The keys of a file in the order of their lines, duplicated keys are kept
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DumpedFile {
+    pub file: String,
+    pub keys: Vec<DumpedKey>,
+}
+
+/// Formats the dumped keys grouped by files. The values are in quotes with escaped special
+/// characters, so the whitespace and invisible characters of the values can be seen
+pub fn format_dump(files: &[DumpedFile]) -> String {
+    files
+        .iter()
+        .map(|file| {
+            let keys: String = file
+                .keys
+                .iter()
+                .map(|k| {
+                    let export = if k.exported { " (exported)" } else { "" };
+                    format!("\n  {} {}={:?}{}", k.line, k.key, k.value, export)
+                })
+                .collect();
+            format!("{}{}", file.file, keys)
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+/// Formats the dumped keys as JSON, an array of the files with their keys
+pub fn format_dump_json(files: &[DumpedFile]) -> String {
+    // The files consist of strings, numbers and booleans only, so it can't fail
+    serde_json::to_string(files).expect("serialize dump")
+}
+
+/// A key of the merged files with the value of its last definition (`--merge`)
+#[derive(Debug, PartialEq, Serialize)]
+pub struct MergedKey {
+    pub key: String,
+    pub value: String,
+    /// The file and the line of the value
+    pub file: String,
+    pub line: usize,
+    /// The overridden definitions of the key in the earlier files, as `file:line`
+    pub overrides: Vec<String>,
+    /// The line of the value, it is checked with `--lint-merged`
+    #[serde(skip)]
+    pub(crate) raw_string: String,
+}
+
+/// Formats the merged keys in the order of their first definitions, with the files of their
+/// values and the definitions they override
+pub fn format_merge(keys: &[MergedKey]) -> String {
+    keys.iter()
+        .map(|k| {
+            let overrides = if k.overrides.is_empty() {
+                String::new()
+            } else {
+                format!(", overrides {}", k.overrides.join(", "))
+            };
+            format!(
+                "{}={:?} from {}:{}{}",
+                k.key, k.value, k.file, k.line, overrides
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Formats the merged keys as JSON, an array of the keys with their values and files
+pub fn format_merge_json(keys: &[MergedKey]) -> String {
+    // The keys consist of strings and numbers only, so it can't fail
+    serde_json::to_string(keys).expect("serialize merged keys")
+}
+
+/// Formats the effective settings of a run as YAML, like a config file
+pub fn format_config(config: &EffectiveConfig) -> String {
+    // The settings consist of strings, numbers, booleans and maps with string keys, so it can't fail
+    serde_yaml::to_string(config).expect("serialize config")
+}
+
+/// Formats the effective settings of a run as JSON
+pub fn format_config_json(config: &EffectiveConfig) -> String {
+    serde_json::to_string_pretty(config).expect("serialize config")
+}
+
+/// Placeholders of an output template, `{{` and `}}` are literal braces
+const TEMPLATE_PLACEHOLDERS: &[&str] = &["file", "line", "column", "severity", "check", "message"];
+
+// A part of an output template
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Text(String),
+    Placeholder(&'static str),
+}
+
+/// Format of the warnings in the text output, e.g. `{file}:{line}:{check}: {message}`
+#[derive(Debug, PartialEq)]
+pub struct OutputTemplate {
+    segments: Vec<Segment>,
+}
+
+impl OutputTemplate {
+    /// Parses the template, an unknown or unclosed placeholder is an error
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => {
+                                return Err(format!(
+                                    "Unclosed placeholder {{{} in the output template",
+                                    name
+                                ))
+                            }
+                        }
+                    }
+
+                    let placeholder = TEMPLATE_PLACEHOLDERS
+                        .iter()
+                        .find(|p| **p == name)
+                        .ok_or_else(|| {
+                            format!(
+                                "Unknown placeholder {{{}}} in the output template, available: {}",
+                                name,
+                                TEMPLATE_PLACEHOLDERS
+                                    .iter()
+                                    .map(|p| format!("{{{}}}", p))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            )
+                        })?;
+
+                    if !text.is_empty() {
+                        segments.push(Segment::Text(std::mem::take(&mut text)));
+                    }
+                    segments.push(Segment::Placeholder(placeholder));
+                }
+                '}' => {
+                    return Err(String::from(
+                        "Unmatched } in the output template, use }} for a brace",
+                    ))
+                }
+                c => text.push(c),
+            }
+        }
+
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Formats a warning, its column is the column of the first span or 1 without spans
+    pub fn format(&self, warning: &Warning) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.clone(),
+                Segment::Placeholder("file") => warning.file_path().display().to_string(),
+                Segment::Placeholder("line") => warning.line_number().to_string(),
+                Segment::Placeholder("column") => column(warning).to_string(),
+                Segment::Placeholder("severity") => String::from(severity_name(warning.severity)),
+                Segment::Placeholder("check") => warning.check_name.clone(),
+                Segment::Placeholder(_) => warning.message().to_string(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::*;
+    use std::path::PathBuf;
+
+    fn warning(path: &str, number: usize, check_name: &str) -> Warning {
+        let line = LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(path),
+                file_name: path.to_string(),
+                total_lines: number,
+            },
+            raw_string: String::from("foo=bar"),
+        };
+
+        Warning::new(line, check_name, String::from("The message"))
+    }
+
+    #[test]
+    fn summary_test() {
+        let warnings = vec![
+            warning(".env", 1, "LowercaseKey"),
+            warning(".env", 2, "LowercaseKey"),
+            warning(".env.test", 1, "DuplicatedKey"),
+        ];
+        let summary = Summary::new(&warnings);
+
+        assert_eq!(3, summary.total);
+        assert_eq!(Some(&2), summary.checks.get("LowercaseKey"));
+        assert_eq!(Some(&1), summary.checks.get("DuplicatedKey"));
+        assert_eq!(Some(&2), summary.files.get(".env"));
+        assert_eq!(Some(&1), summary.files.get(".env.test"));
+    }
+
+    #[test]
+    fn format_json_test() {
+        let warnings = vec![warning(".env", 1, "LowercaseKey")];
+
+        assert_eq!(
+            r#"{"warnings":[{"file":".env","line":1,"check":"LowercaseKey","message":"The message","severity":"warning","fixed":false,"fingerprint":"31323c55fc7a1558"}],"summary":{"total":1,"errors":0,"checks":{"LowercaseKey":1},"files":{".env":1}}}"#,
+            format_json(&warnings, false, false)
+        );
+        assert_eq!(
+            r#"{"summary":{"total":1,"errors":0,"checks":{"LowercaseKey":1},"files":{".env":1}}}"#,
+            format_json(&warnings, true, false)
+        );
+    }
+
+    #[test]
+    fn format_checkstyle_test() {
+        let mut error = warning(".env", 2, "QuoteCharacter");
+        error.severity = Severity::Error;
+        let warnings = vec![
+            warning(".env", 1, "LowercaseKey"),
+            error,
+            warning("a&b.env", 1, "LowercaseKey"),
+        ];
+
+        assert_eq!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <checkstyle version=\"4.3\">\n  \
+            <file name=\".env\">\n    \
+            <error line=\"1\" column=\"1\" severity=\"warning\" message=\"The message\" source=\"LowercaseKey\"/>\n    \
+            <error line=\"2\" column=\"1\" severity=\"error\" message=\"The message\" source=\"QuoteCharacter\"/>\n  \
+            </file>\n  \
+            <file name=\"a&amp;b.env\">\n    \
+            <error line=\"1\" column=\"1\" severity=\"warning\" message=\"The message\" source=\"LowercaseKey\"/>\n  \
+            </file>\n\
+            </checkstyle>",
+            format_checkstyle(&warnings)
+        );
+    }
+
+    #[test]
+    fn format_tap_test() {
+        let mut fixed = warning(".env", 2, "QuoteCharacter");
+        fixed.is_fixed = true;
+        let warnings = vec![warning(".env", 1, "LowercaseKey"), fixed];
+
+        assert_eq!(
+            "TAP version 13\n\
+            1..2\n\
+            not ok 1 - .env:1 LowercaseKey\n  \
+            ---\n  \
+            file: \".env\"\n  \
+            line: 1\n  \
+            column: 1\n  \
+            check: LowercaseKey\n  \
+            severity: warning\n  \
+            message: \"The message\"\n  \
+            ...\n\
+            ok 2 - .env:2 QuoteCharacter # fixed\n  \
+            ---\n  \
+            file: \".env\"\n  \
+            line: 2\n  \
+            column: 1\n  \
+            check: QuoteCharacter\n  \
+            severity: warning\n  \
+            message: \"The message\"\n  \
+            ...\n\
+            # pass 1\n\
+            # fail 1",
+            format_tap(&warnings)
+        );
+        assert_eq!(
+            "TAP version 13\n1..1\nok 1 - No problems found\n# pass 1\n# fail 0",
+            format_tap(&[])
+        );
+    }
+
+    #[test]
+    fn lsp_diagnostics_test() {
+        let mut error = warning(".env", 2, "UnescapedQuote");
+        error.severity = Severity::Error;
+        error.spans = vec![Span {
+            column: 5,
+            length: 1,
+        }];
+        let mut fixed = warning(".env", 3, "LowercaseKey");
+        fixed.is_fixed = true;
+        let warnings = vec![warning(".env", 1, "LowercaseKey"), error, fixed];
+
+        assert_eq!(
+            r#"[{"file":".env","diagnostics":[{"range":{"start":{"line":0,"character":0},"end":{"line":0,"character":7}},"severity":2,"code":"LowercaseKey","source":"dotenv-linter","message":"The message"},{"range":{"start":{"line":1,"character":4},"end":{"line":1,"character":5}},"severity":1,"code":"UnescapedQuote","source":"dotenv-linter","message":"The message"}]}]"#,
+            format_lsp_json(&warnings)
+        );
+    }
+
+    #[test]
+    fn lsp_range_utf16_test() {
+        let line = LineEntry {
+            number: 1,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: String::from(".env"),
+                total_lines: 1,
+            },
+            raw_string: String::from("EMOJI=😀 a\"b\n"),
+        };
+        let mut warning = Warning::new(line, "UnescapedQuote", String::from("The message"));
+        warning.spans = vec![Span {
+            column: 10,
+            length: 1,
+        }];
+
+        // The emoji is one character of the span columns and two UTF-16 code units
+        assert_eq!(
+            LspRange {
+                start: LspPosition {
+                    line: 0,
+                    character: 10,
+                },
+                end: LspPosition {
+                    line: 0,
+                    character: 11,
+                },
+            },
+            lsp_range(&warning)
+        );
+    }
+
+    #[test]
+    fn escape_xml_test() {
+        assert_eq!(
+            "The &lt;KEY&gt; key has &quot;a&quot; &amp; &apos;b&apos;",
+            escape_xml("The <KEY> key has \"a\" & 'b'")
+        );
+    }
+
+    #[test]
+    fn format_merge_test() {
+        let keys = vec![
+            MergedKey {
+                key: String::from("DB_HOST"),
+                value: String::from("db.internal"),
+                file: String::from(".env.local"),
+                line: 1,
+                overrides: vec![String::from(".env:2")],
+                raw_string: String::from("DB_HOST=db.internal"),
+            },
+            MergedKey {
+                key: String::from("PORT"),
+                value: String::from("5432"),
+                file: String::from(".env"),
+                line: 3,
+                overrides: Vec::new(),
+                raw_string: String::from("PORT=5432"),
+            },
+        ];
+
+        assert_eq!(
+            "DB_HOST=\"db.internal\" from .env.local:1, overrides .env:2\nPORT=\"5432\" from .env:3",
+            format_merge(&keys)
+        );
+        assert_eq!(
+            r#"[{"key":"DB_HOST","value":"db.internal","file":".env.local","line":1,"overrides":[".env:2"]},{"key":"PORT","value":"5432","file":".env","line":3,"overrides":[]}]"#,
+            format_merge_json(&keys)
+        );
+    }
+
+    #[test]
+    fn format_dump_test() {
+        let files = vec![
+            DumpedFile {
+                file: String::from(".env"),
+                keys: vec![
+                    DumpedKey {
+                        line: 1,
+                        key: String::from("FOO"),
+                        value: String::from("bar baz"),
+                        exported: false,
+                    },
+                    DumpedKey {
+                        line: 3,
+                        key: String::from("BAR"),
+                        value: String::from("\t"),
+                        exported: true,
+                    },
+                ],
+            },
+            DumpedFile {
+                file: String::from(".env.test"),
+                keys: Vec::new(),
+            },
+        ];
+
+        assert_eq!(
+            ".env\n  1 FOO=\"bar baz\"\n  3 BAR=\"\\t\" (exported)\n\n.env.test",
+            format_dump(&files)
+        );
+        assert_eq!(
+            r#"[{"file":".env","keys":[{"line":1,"key":"FOO","value":"bar baz","exported":false},{"line":3,"key":"BAR","value":"\t","exported":true}]},{"file":".env.test","keys":[]}]"#,
+            format_dump_json(&files)
+        );
+    }
+
+    #[test]
+    fn output_template_test() {
+        let template =
+            OutputTemplate::parse("{file}:{line}:{column}: {severity} {check}: {message} {{x}}")
+                .expect("valid template");
+
+        assert_eq!(
+            ".env:2:1: warning LowercaseKey: The message {x}",
+            template.format(&warning(".env", 2, "LowercaseKey"))
+        );
+    }
+
+    #[test]
+    fn invalid_output_template_test() {
+        assert_eq!(
+            Err(String::from("Unknown placeholder {path} in the output template, available: {file}, {line}, {column}, {severity}, {check}, {message}")),
+            OutputTemplate::parse("{path}: {message}")
+        );
+        assert!(OutputTemplate::parse("{file").is_err());
+        assert!(OutputTemplate::parse("file}").is_err());
+    }
+}
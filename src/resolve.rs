@@ -0,0 +1,179 @@
+use crate::common::*;
+use std::collections::HashMap;
+use std::env;
+
+/// Resolves the substitutions of the values with the keys defined above them in the file
+/// (`--resolve`), by the line numbers. Only the lines with substitutions are resolved.
+/// The keys which aren't defined in the file are taken from the environment with `use_env`,
+/// otherwise they are left as is. The lines of secrets (the keys with the `secret_suffixes`)
+/// and the lines referencing secrets are not resolved, so their values are never printed
+pub(crate) fn resolve_values(
+    lines: &[LineEntry],
+    use_env: bool,
+    secret_suffixes: &[String],
+) -> HashMap<usize, String> {
+    let is_secret = |key: &str| {
+        let key = key.to_uppercase();
+        secret_suffixes.iter().any(|s| key.ends_with(s.as_str()))
+    };
+
+    let mut values: HashMap<String, String> = HashMap::new();
+    let mut resolved = HashMap::new();
+
+    for line in lines {
+        let (key, value) = match (line.get_key(), get_value(line)) {
+            (Some(key), Some(value)) => (key, value),
+            _ => continue,
+        };
+
+        // Single-quoted values are literal
+        if line.parse().quote == Some(QuoteStyle::Single) {
+            values.insert(key, value);
+            continue;
+        }
+
+        let references = get_substitution_keys(&value);
+        let value = substitute(&value, |name| {
+            values
+                .get(name)
+                .cloned()
+                .or_else(|| env::var(name).ok().filter(|_| use_env))
+        });
+
+        if !references.is_empty() && !is_secret(&key) && !references.iter().any(|r| is_secret(r)) {
+            resolved.insert(line.number, value.clone());
+        }
+        values.insert(key, value);
+    }
+
+    resolved
+}
+
+// The value of the line without quotes and a comment
+fn get_value(line: &LineEntry) -> Option<String> {
+    let parsed = line.parse();
+    let value = parsed.value?;
+    let value = match parsed.comment.and_then(|comment| value.rfind(comment)) {
+        Some(index) => value[..index].trim(),
+        None => value.trim(),
+    };
+
+    Some(String::from(unquote(value).unwrap_or(value)))
+}
+
+/// Replaces `${KEY}` and `$KEY` substitutions with the values returned by `lookup`.
+/// `${KEY:-default}` is replaced with the default when the key has no value,
+/// the other substitutions without values are kept as is
+fn substitute(value: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut result = String::new();
+    let mut rest = value;
+
+    while let Some(index) = rest.find('$') {
+        result.push_str(&rest[..index]);
+        let after = &rest[index + 1..];
+
+        let (name, default, len) = match after.strip_prefix('{') {
+            Some(braced) => match braced.find('}') {
+                Some(end) => {
+                    let inner = &braced[..end];
+                    match inner.split_once(":-") {
+                        Some((name, default)) => (name, Some(default), end + 2),
+                        None => (inner, None, end + 2),
+                    }
+                }
+                None => ("", None, 0),
+            },
+            None => {
+                let end = after
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                (&after[..end], None, end)
+            }
+        };
+
+        let is_name = !name.is_empty()
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+        let substitution = &rest[index..index + 1 + len];
+        match lookup(name).or_else(|| default.map(String::from)) {
+            Some(value) if is_name => result.push_str(&value),
+            _ => result.push_str(substitution),
+        }
+
+        rest = &rest[index + 1 + len..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn line_entry(number: usize, total_lines: usize, str: &str) -> LineEntry {
+        LineEntry {
+            number,
+            file: FileEntry {
+                path: PathBuf::from(".env"),
+                file_name: ".env".to_string(),
+                total_lines,
+            },
+            raw_string: String::from(str),
+        }
+    }
+
+    #[test]
+    fn substitute_test() {
+        let lookup = |name: &str| match name {
+            "HOST" => Some(String::from("localhost")),
+            "PORT" => Some(String::from("80")),
+            _ => None,
+        };
+        let asserts = vec![
+            ("http://${HOST}:$PORT/", "http://localhost:80/"),
+            ("${USER:-admin}@$HOST", "admin@localhost"),
+            ("${MISSING} $MISSING", "${MISSING} $MISSING"),
+            ("$1 costs $", "$1 costs $"),
+            ("${HOST", "${HOST"),
+            ("no substitutions", "no substitutions"),
+        ];
+
+        for (value, expected) in asserts {
+            assert_eq!(expected, substitute(value, lookup), "Value: {}", value);
+        }
+    }
+
+    #[test]
+    fn resolve_values_test() {
+        let lines = vec![
+            line_entry(1, 6, "HOST=localhost"),
+            line_entry(2, 6, "URL=\"http://${HOST}/${PATH_PREFIX}\" # api"),
+            line_entry(3, 6, "API_URL=$URL/v1"),
+            line_entry(4, 6, "LITERAL='$HOST'"),
+            line_entry(5, 6, "DB_PASSWORD=secret"),
+            line_entry(6, 6, "DSN=postgres://admin:${DB_PASSWORD}@$HOST"),
+        ];
+        let suffixes = vec![String::from("_PASSWORD")];
+        let mut expected = HashMap::new();
+        expected.insert(2, String::from("http://localhost/${PATH_PREFIX}"));
+        expected.insert(3, String::from("http://localhost/${PATH_PREFIX}/v1"));
+
+        assert_eq!(expected, resolve_values(&lines, false, &suffixes));
+    }
+
+    #[test]
+    fn resolve_from_env_test() {
+        let lines = vec![line_entry(1, 1, "BIN=${PATH}")];
+
+        assert_eq!(
+            Some(&String::from("${PATH}")),
+            resolve_values(&lines, false, &[]).get(&1)
+        );
+        assert_eq!(
+            env::var("PATH").ok().as_ref(),
+            resolve_values(&lines, true, &[]).get(&1)
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::common::TestDir;
+use std::fs;
 use std::path::Path;
 
 #[test]
@@ -72,7 +73,7 @@ fn checks_two_specific_files() {
 
     let args = &[testfile_2.as_str(), testfile_3.as_str()];
     let expected_output = format!(
-        "{}:2 DuplicatedKey: The FOO key is duplicated\n{}:1 SpaceCharacter: The line has spaces around equal sign\n\nFound 2 problems\n",
+        "{}:2 DuplicatedKey: The FOO key is duplicated with the same value\n{}:1 SpaceCharacter: The line has spaces around equal sign\n\nFound 2 problems\n",
         Path::new(&testdir.relative_path(&subdir))
             .join(testfile_3.shortname_as_str())
             .to_str().expect("multi-platform path to test .env file"),
@@ -93,11 +94,32 @@ fn checks_one_specific_file_and_one_path() {
 
     let args = &[testfile_2.as_str(), subdir.as_str()];
     let expected_output = format!(
-        "{}:2 UnorderedKey: The BAR key should go before the FOO key\n{}:2 DuplicatedKey: The FOO key is duplicated\n\nFound 2 problems\n",
-        testfile_2.shortname_as_str(),
+        "{}:2 DuplicatedKey: The FOO key is duplicated with the same value\n{}:2 UnorderedKey: The BAR key should go before the FOO key\n\nFound 2 problems\n",
         Path::new(&testdir.relative_path(&subdir))
             .join(testfile_3.shortname_as_str())
             .to_str().expect("multi-platform path to test .env file"),
+        testfile_2.shortname_as_str(),
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn checks_file_in_specific_path_once() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "foo=bar\n");
+    let subdir = testdir.subdir();
+    let testfile_2 = subdir.create_testfile(".env.dev", "bar=foo\n");
+
+    // Without deduplication of the warnings a file checked twice would have duplicated warnings
+    let args = &["--no-dedup", testfile.as_str(), ".", subdir.as_str()];
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The foo key should be in uppercase\n{}:1 LowercaseKey: The bar key should be in uppercase\n\nFound 2 problems\n",
+        testfile.shortname_as_str(),
+        Path::new(&testdir.relative_path(&subdir))
+            .join(testfile_2.shortname_as_str())
+            .to_str()
+            .expect("multi-platform path to test .env file"),
     );
 
     testdir.test_command_fail_with_args(args, expected_output);
@@ -117,3 +139,67 @@ fn checks_one_specific_file_twice() {
 
     test_dir.test_command_fail_with_args(args, expected_output);
 }
+
+#[test]
+fn skips_missing_and_binary_files() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=bar\n");
+    let binary_path = Path::new(test_dir.as_str()).join(".env.bin");
+    fs::write(&binary_path, [0xff, 0xfe, 0x00]).expect("write binary file");
+
+    let args = &[".env.deleted", ".env.bin", testfile.as_str()];
+    let output = test_dir.command_output_with_args(args);
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+    assert_eq!(expected_output.as_bytes(), output.as_slice());
+
+    test_dir.test_command_error_with_args(args, "Skipped .env.bin: the file can't be read as text");
+}
+
+#[test]
+fn skipped_files_fail_the_run_with_their_code() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=bar\n");
+
+    let args = &[".env.deleted", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args_and_code(args, 2, expected_output);
+}
+
+#[test]
+fn ignores_missing_files() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "FOO=bar\n");
+
+    let args = &["--ignore-missing-files", ".env.deleted", testfile.as_str()];
+    assert_eq!("", test_dir.command_stderr_with_args(args));
+
+    test_dir.test_command_success_with_args(args);
+}
+
+#[test]
+fn stops_at_skipped_files_with_fail_on_error() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=bar\n");
+    let testfile_2 = test_dir.create_testfile(".env.c", "bar=foo\n");
+
+    // The files are checked in the order of their paths, `.env.c` is after the missing file
+    let args = &[
+        "--fail-on-error",
+        testfile.as_str(),
+        ".env.b",
+        testfile_2.as_str(),
+    ];
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args_and_code(args, 2, expected_output);
+}
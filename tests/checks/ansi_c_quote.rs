@@ -0,0 +1,20 @@
+use crate::common::TestDir;
+
+#[test]
+fn ansi_c_quote() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO=$'line1\\nline2'\nSEPARATOR='\\t'\n");
+    let args = &[
+        "--enable",
+        "AnsiCQuote",
+        "--skip",
+        "QuoteCharacter",
+        testfile.as_str(),
+    ];
+    let expected_output = format!(
+        "{}:1 AnsiCQuote: The FOO key has a value with the `$'...'` quoting of bash, dotenv loaders read it as is\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
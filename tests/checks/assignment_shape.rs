@@ -0,0 +1,32 @@
+use crate::common::TestDir;
+
+#[test]
+fn assignment_shape() {
+    let testdir = TestDir::new();
+    let key = "DATABASE_CONNECTION_STRING_FOR_THE_REPORTING_SERVICE";
+    let testfile = testdir.create_testfile(".env", &format!("A=SECRET_KEY=abc\n{}=\nHOST=\n", key));
+    let args = &["--enable", "AssignmentShape", testfile.as_str()];
+    let expected_output = format!(
+        "{0}:1 AssignmentShape: The A key has a suspicious shape: a key of 1 character, the line may be generated wrong\n{0}:2 AssignmentShape: The {1} key has a suspicious shape: an empty value and a key of 52 characters, the line may be generated wrong\n\nFound 2 problems\n",
+        testfile.shortname_as_str(),
+        key
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn thresholds_from_config() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=1\nDATABASE_URL=\nDB=postgres\n");
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [AssignmentShape]\nchecks:\n  AssignmentShape:\n    min_key_length: 3\n    max_empty_key_length: 10\n",
+    );
+    let expected_output = format!(
+        "{0}:1 AssignmentShape: The A key has a suspicious shape: a key of 1 character, the line may be generated wrong\n{0}:2 AssignmentShape: The DATABASE_URL key has a suspicious shape: an empty value and a key of 12 characters, the line may be generated wrong\n{0}:3 AssignmentShape: The DB key has a suspicious shape: a key of 2 characters, the line may be generated wrong\n\nFound 3 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
@@ -0,0 +1,15 @@
+use crate::common::TestDir;
+
+#[test]
+fn backslash_path() {
+    let testdir = TestDir::new();
+    let testfile =
+        testdir.create_testfile(".env", "DATA_DIR=C:\\Users\\me\\data\nTMP_DIR=C:/tmp\n");
+    let args = &["--enable", "BackslashPath", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 BackslashPath: The DATA_DIR key has a value with backslashes, use forward slashes or single quotes\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,17 @@
+use crate::common::TestDir;
+
+#[test]
+fn mixed_booleans() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "ENABLE_CACHE=true\nENABLE_LOGS=true\nENABLE_METRICS=1\n",
+    );
+    let args = &["--enable", "BooleanConsistency", testfile.as_str()];
+    let expected_output = format!(
+        "{}:3 BooleanConsistency: The ENABLE_METRICS key should have a boolean value like `true` or `false`, as the other boolean values\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
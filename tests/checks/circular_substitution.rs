@@ -0,0 +1,23 @@
+use crate::common::TestDir;
+
+#[test]
+fn correct_file() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=foo\nB=${A}\n");
+    let args = &[testfile.as_str()];
+
+    testdir.test_command_success_with_args(args);
+}
+
+#[test]
+fn circular_substitution() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=${B}\nB=${A}\n");
+    let args = &[testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 CircularSubstitution: Circular substitution detected: A -> B -> A\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
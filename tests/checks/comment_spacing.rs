@@ -0,0 +1,17 @@
+use crate::common::TestDir;
+
+#[test]
+fn comment_spacing() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "# Database\nDB=db\n\n# Cache\nREDIS=redis\n\n# Mail\nMAIL=mail\n# Queue\nQUEUE=queue\n",
+    );
+    let args = &["--enable", "CommentSpacing", testfile.as_str()];
+    let expected_output = format!(
+        "{}:9 CommentSpacing: The comment should be preceded by a blank line\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
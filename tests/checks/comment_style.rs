@@ -0,0 +1,22 @@
+use crate::common::TestDir;
+
+#[test]
+fn comment_style() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "# Database\nDB=db\n; Cache\n");
+    let args = &[
+        "--enable",
+        "CommentStyle",
+        "--skip",
+        "LeadingCharacter",
+        "KeyWithoutValue",
+        "--",
+        testfile.as_str(),
+    ];
+    let expected_output = format!(
+        "{}:3 CommentStyle: The comment should start with #\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
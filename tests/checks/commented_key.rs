@@ -0,0 +1,29 @@
+use crate::common::TestDir;
+
+#[test]
+fn commented_keys() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "# The host of the app\n# OLD_HOST=localhost\nHOST=app\n",
+    );
+    let args = &["--enable", "CommentedKey", testfile.as_str()];
+    let expected_output = format!(
+        "{}:2 CommentedKey: The OLD_HOST key is commented out, consider removing it\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn threshold_from_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [CommentedKey]\nchecks:\n  CommentedKey:\n    threshold: 1\n",
+    );
+    testdir.create_testfile(".env", "# OLD_HOST=localhost\nHOST=app\n");
+
+    testdir.test_command_success();
+}
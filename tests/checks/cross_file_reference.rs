@@ -0,0 +1,23 @@
+use crate::common::TestDir;
+
+#[test]
+fn disabled_by_default() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "HOST=localhost\n");
+    testdir.create_testfile(".env.local", "URL=http://${HOST}\n");
+
+    testdir.test_command_success();
+}
+
+#[test]
+fn reference_to_other_file() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "HOST=localhost\n");
+    testdir.create_testfile(".env.local", "URL=http://${HOST}\n");
+    let args = &["--enable", "CrossFileReference"];
+    let expected_output = String::from(
+        ".env.local:1 CrossFileReference: The URL key references HOST, which is defined in .env, but not in its file\n\nFound 1 problem\n",
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,13 @@
+use crate::common::TestDir;
+
+#[test]
+fn duplicated_delimiter() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO==BAR\nTOKEN=dGVzdA==\n");
+    let expected_output = format!(
+        "{}:1 DuplicatedDelimiter: The FOO key has a duplicated delimiter\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
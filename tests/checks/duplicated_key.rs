@@ -0,0 +1,55 @@
+use crate::common::TestDir;
+
+#[test]
+fn reports_duplicates_in_order_of_lines() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "A=1\nB=1\nA=2\nC=1\nB=2\nA=3\nC=2\n");
+    testdir.create_testfile(".env.test", "C=1\nC=2\nA=1\nA=2\n");
+    let args = &["--only", "DuplicatedKey"];
+    let expected_output = String::from(
+        ".env:3 [error] DuplicatedKey: The A key is duplicated with a different value
+.env:5 [error] DuplicatedKey: The B key is duplicated with a different value
+.env:6 [error] DuplicatedKey: The A key is duplicated with a different value
+.env:7 [error] DuplicatedKey: The C key is duplicated with a different value
+.env.test:2 [error] DuplicatedKey: The C key is duplicated with a different value
+.env.test:4 [error] DuplicatedKey: The A key is duplicated with a different value
+
+Found 6 problems
+",
+    );
+
+    // The order doesn't depend on the hashes of the keys, which are different in each run
+    for _ in 0..3 {
+        let output = testdir.command_output_with_args(args);
+        assert_eq!(expected_output, String::from_utf8_lossy(&output));
+    }
+}
+
+#[test]
+fn reports_duplicates_with_the_same_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=1\nB=2\nA='1'\n");
+    let args = &["--only", "DuplicatedKey", "--", testfile.as_str()];
+    let expected_output = format!(
+        "{}:3 DuplicatedKey: The A key is duplicated with the same value\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn reports_duplicates_the_same_without_compare_values() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "only: [DuplicatedKey]\nchecks:\n  DuplicatedKey:\n    compare_values: false\n",
+    );
+    let testfile = testdir.create_testfile(".env", "A=1\nA=1\nB=1\nB=2\n");
+    let expected_output = format!(
+        "{0}:2 DuplicatedKey: The A key is duplicated\n{0}:4 DuplicatedKey: The B key is duplicated\n\nFound 2 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
@@ -0,0 +1,33 @@
+use crate::common::TestDir;
+
+#[test]
+fn duration_unit() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "CACHE_TTL=3600\nPOLL_INTERVAL=5s\nREQUEST_TIMEOUT=30\n",
+    );
+    let args = &["--enable", "DurationUnit", testfile.as_str()];
+    let expected_output = format!(
+        "{0}:1 DurationUnit: The CACHE_TTL key has a duration without a unit, e.g. 30s or 500ms\n{0}:3 DurationUnit: The REQUEST_TIMEOUT key has a duration without a unit, e.g. 30s or 500ms\n\nFound 2 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn unit_not_required() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [DurationUnit]\nchecks:\n  DurationUnit:\n    suffixes: [_DELAY]\n    require_unit: false\n",
+    );
+    let testfile = testdir.create_testfile(".env", "REQUEST_TIMEOUT=30\nRETRY_DELAY=5s\n");
+    let expected_output = format!(
+        "{}:2 DurationUnit: The RETRY_DELAY key has a duration with a unit, use a plain number\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
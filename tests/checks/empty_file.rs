@@ -0,0 +1,14 @@
+use crate::common::TestDir;
+
+#[test]
+fn file_without_keys() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "# The settings of the app\n");
+    let args = &["--enable", "EmptyFile", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 EmptyFile: The file has no keys, only comments and blank lines\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
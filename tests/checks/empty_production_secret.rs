@@ -0,0 +1,15 @@
+use crate::common::TestDir;
+
+#[test]
+fn empty_secret_in_production_file() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env.example", "DATABASE_PASSWORD=\n");
+    let testfile = testdir.create_testfile(".env.production", "DATABASE_PASSWORD=\n");
+    let args = &["--enable", "EmptyProductionSecret", "."];
+    let expected_output = format!(
+        "{}:1 EmptyProductionSecret: The DATABASE_PASSWORD key looks like a secret, but it has an empty value in a production file\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
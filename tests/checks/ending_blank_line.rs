@@ -19,13 +19,13 @@ fn correct_files() {
 
 #[test]
 fn incorrect_files() {
-    let contents = vec![
+    let contents = [
         "ABC=DEF\nD=BAR\nFOO=BAR",
         "C=D\r\nK=L\r\nX=Y",
         "A=B",
         "# Comment 1\n# Comment 2\n# Comment 3",
     ];
-    let expected_line_numbers = vec![3, 3, 1, 3];
+    let expected_line_numbers = [3, 3, 1, 3];
 
     for (i, content) in contents.iter().enumerate() {
         let testdir = TestDir::new();
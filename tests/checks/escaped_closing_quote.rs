@@ -0,0 +1,15 @@
+use crate::common::TestDir;
+
+#[test]
+fn escaped_closing_quote() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "A=\"x\\\"\nB=\"y\\\\\"\nC=\"z\\\\\\\"\n");
+    let args = &["--skip", "QuoteCharacter", testfile.as_str()];
+    let expected_output = format!(
+        "{a}:1 EscapedClosingQuote: The A key has a backslash before the closing quote, it escapes the quote and the value isn't closed\n\
+        {a}:3 EscapedClosingQuote: The C key has a backslash before the closing quote, it escapes the quote and the value isn't closed\n\nFound 2 problems\n",
+        a = testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,25 @@
+use crate::common::TestDir;
+
+#[test]
+fn allowed_by_default() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "export BAR=1\nFOO=2\n");
+
+    testdir.test_command_success();
+}
+
+#[test]
+fn forbidden_export() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "checks:\n  ExportStyle:\n    style: forbid\n",
+    );
+    let testfile = testdir.create_testfile(".env", "export BAR=1\nFOO=2\n");
+    let expected_output = format!(
+        "{}:1 ExportStyle: The BAR key should be defined without export\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
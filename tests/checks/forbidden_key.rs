@@ -0,0 +1,28 @@
+use crate::common::TestDir;
+
+#[test]
+fn forbidden_key() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "checks:\n  ForbiddenKey:\n    keys:\n      - key: LEGACY_API_URL\n        message: use API_URL instead\n      - key: DEBUG\n",
+    );
+    let testfile = testdir.create_testfile(
+        ".env",
+        "API_URL=https://api.example.com\nDEBUG=true\nLEGACY_API_URL=https://api.example.com\n",
+    );
+    let expected_output = format!(
+        "{0}:2 ForbiddenKey: The DEBUG key is forbidden, remove or rename it\n{0}:3 ForbiddenKey: The LEGACY_API_URL key is forbidden, use API_URL instead\n\nFound 2 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
+
+#[test]
+fn without_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "DEBUG=true\n");
+
+    testdir.test_command_success();
+}
@@ -0,0 +1,20 @@
+use crate::common::TestDir;
+
+#[test]
+fn inconsistent_ending_newline() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "FOO=BAR\n");
+    testdir.create_testfile(".env.local", "FOO=QUX\n");
+    testdir.create_testfile(".env.test", "FOO=BAZ");
+    let args = &[
+        "--enable",
+        "InconsistentEndingNewline",
+        "--skip",
+        "EndingBlankLine",
+    ];
+    let expected_output = String::from(
+        ".env.test:1 InconsistentEndingNewline: The file doesn't end with a newline, unlike most of the checked files\n\nFound 1 problem\n",
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,28 @@
+use crate::common::TestDir;
+
+#[test]
+fn inconsistent_export() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "export FOO=1\nFOO=2\n");
+    let args = &[
+        "--enable",
+        "InconsistentExport",
+        "--skip",
+        "DuplicatedKey",
+        testfile.as_str(),
+    ];
+    let expected_output = format!(
+        "{}:2 InconsistentExport: The FOO key is defined with export at line 1 and without it at line 2\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn exported_keys_are_not_reported() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "export BAR=1\nexport FOO=2\n");
+
+    testdir.test_command_success_with_args(["--enable", "InconsistentExport"]);
+}
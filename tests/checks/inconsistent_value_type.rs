@@ -0,0 +1,33 @@
+use crate::common::TestDir;
+
+#[test]
+fn disabled_by_default() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "DEBUG=true\n");
+    testdir.create_testfile(".env.prod", "DEBUG=0\n");
+
+    testdir.test_command_success();
+}
+
+#[test]
+fn consistent_types() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "DEBUG=true\nPORT=80\n");
+    testdir.create_testfile(".env.prod", "DEBUG=false\nPORT=443\n");
+    let args = &["--enable", "InconsistentValueType"];
+
+    testdir.test_command_success_with_args(args);
+}
+
+#[test]
+fn inconsistent_types() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "DEBUG=true\nPORT=80\n");
+    testdir.create_testfile(".env.prod", "DEBUG=0\nPORT=443\n");
+    let args = &["--enable", "InconsistentValueType"];
+    let expected_output = String::from(
+        ".env.prod:1 InconsistentValueType: The DEBUG key has the int type, but the bool type in .env\n\nFound 1 problem\n",
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,26 @@
+use crate::common::TestDir;
+
+#[test]
+fn index_gap() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "ITEM_0=a\nITEM_1=b\nITEM_3=d\n");
+    let args = &["--enable", "IndexGap", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 IndexGap: The ITEM_* keys have a gap in their indexes, the missing keys: ITEM_2\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn min_family_size_from_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [IndexGap]\nchecks:\n  IndexGap:\n    min_family_size: 4\n",
+    );
+    testdir.create_testfile(".env", "ITEM_0=a\nITEM_1=b\nITEM_3=d\n");
+
+    testdir.test_command_success();
+}
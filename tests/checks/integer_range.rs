@@ -0,0 +1,30 @@
+use crate::common::TestDir;
+
+#[test]
+fn integer_range() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "RETRIES=99999999999999999999\nTIMEOUT=30\n");
+    let args = &["--enable", "IntegerRange", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 IntegerRange: The RETRIES key has an integer which doesn't fit in i64\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn width_from_config() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "MAX_SIZE=4294967296\nPHONE=4294967296\n");
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [IntegerRange]\nchecks:\n  IntegerRange:\n    width: u32\n    suffixes: [_SIZE]\n",
+    );
+    let expected_output = format!(
+        "{}:1 IntegerRange: The MAX_SIZE key has an integer which doesn't fit in u32\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
@@ -0,0 +1,16 @@
+use crate::common::TestDir;
+
+#[test]
+fn invalid_control_comment() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "# dotenv-linter:off LowercaseKey\nBAR=1 # dotenv-linter:on\nFOO=2\n",
+    );
+    let expected_output = format!(
+        "{}:2 InvalidControlComment: The control comment has no effect, use `# dotenv-linter:off` or `# dotenv-linter:on` on a separate comment line\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
@@ -0,0 +1,23 @@
+use crate::common::TestDir;
+
+#[test]
+fn correct_file() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO=BAR\n");
+    let args = &[testfile.as_str()];
+
+    testdir.test_command_success_with_args(args);
+}
+
+#[test]
+fn zero_width_space() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO\u{200B}=BAR\n");
+    let args = &[testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 InvisibleCharacter: Invisible character U+200B detected at position 4\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
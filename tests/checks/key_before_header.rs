@@ -0,0 +1,30 @@
+use crate::common::TestDir;
+
+#[test]
+fn comment_after_keys() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "# Header\nA=1\n\n# The B key\nB=2\n");
+    let args = &["--enable", "KeyBeforeHeader", testfile.as_str()];
+    let expected_output = format!(
+        "{}:4 KeyBeforeHeader: The comment should be moved to the header of the file, before the first key\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn commented_keys_from_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [KeyBeforeHeader]\nchecks:\n  KeyBeforeHeader:\n    ignore_commented_keys: false\n",
+    );
+    let testfile = testdir.create_testfile(".env", "A=1\n# OLD_B=2\nB=2\n");
+    let expected_output = format!(
+        "{}:2 KeyBeforeHeader: The comment should be moved to the header of the file, before the first key\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
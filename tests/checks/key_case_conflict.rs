@@ -0,0 +1,24 @@
+use crate::common::TestDir;
+
+#[test]
+fn disabled_by_default() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "Path=/usr/bin\n");
+    testdir.create_testfile(".env.local", "PATH=/usr/local/bin\n");
+    let args = &["--skip", "LowercaseKey"];
+
+    testdir.test_command_success_with_args(args);
+}
+
+#[test]
+fn key_case_conflict() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "Path=/usr/bin\n");
+    testdir.create_testfile(".env.local", "PATH=/usr/local/bin\n");
+    let args = &["--enable", "KeyCaseConflict", "--skip", "LowercaseKey"];
+    let expected_output = String::from(
+        ".env.local:1 KeyCaseConflict: The PATH key differs only by case from the Path key in .env\n\nFound 1 problem\n",
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
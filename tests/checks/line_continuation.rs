@@ -0,0 +1,23 @@
+use crate::common::TestDir;
+
+#[test]
+fn escaped_backslash() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO=bar\\\\\n");
+    let args = &["--enable", "LineContinuation", testfile.as_str()];
+
+    testdir.test_command_success_with_args(args);
+}
+
+#[test]
+fn trailing_backslash() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO=bar\\\n");
+    let args = &["--enable", "LineContinuation", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 LineContinuation: The FOO key has a value ending with a backslash, line continuation is not supported\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,30 @@
+use crate::common::TestDir;
+
+#[test]
+fn mixed_separators() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "HOSTS=a,b;c\nPORTS=80,443\n");
+    let args = &["--enable", "ListSeparator", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 ListSeparator: The HOSTS key has a list value with inconsistent separators, use `,` to separate the items\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn separator_from_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [ListSeparator]\nchecks:\n  ListSeparator:\n    separator: semicolon\n",
+    );
+    let testfile = testdir.create_testfile(".env", "HOSTS=a;b\nPORTS=80,443\n");
+    let expected_output = format!(
+        "{}:2 ListSeparator: The PORTS key has a list value with inconsistent separators, use `;` to separate the items\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
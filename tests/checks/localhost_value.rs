@@ -0,0 +1,43 @@
+use crate::common::TestDir;
+
+#[test]
+fn localhost_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env.production",
+        "DATABASE_URL=postgres://app@localhost:5432/app\nREDIS_HOST=cache.internal\n",
+    );
+    let args = &["--enable", "LocalhostValue", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 LocalhostValue: The DATABASE_URL key has the postgres://app@localhost:5432/app value with a local host, but the file isn't a development file\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn dev_file() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env.local", "DB_HOST=127.0.0.1\n");
+    let args = &["--enable", "LocalhostValue", testfile.as_str()];
+
+    testdir.test_command_success_with_args(args);
+}
+
+#[test]
+fn dev_files_from_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [LocalhostValue]\nchecks:\n  LocalhostValue:\n    dev_files: [\"*.dev\"]\n",
+    );
+    let testfile = testdir.create_testfile(".env", "DB_HOST=localhost\n");
+    testdir.create_testfile(".env.dev", "DB_HOST=localhost\n");
+    let expected_output = format!(
+        "{}:1 LocalhostValue: The DB_HOST key has the localhost value with a local host, but the file isn't a development file\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
@@ -0,0 +1,26 @@
+use crate::common::TestDir;
+
+#[test]
+fn missing_path() {
+    let testdir = TestDir::new();
+    testdir.create_testfile("cert.pem", "");
+    let testfile = testdir.create_testfile(".env", "CERT_FILE=cert.pem\nKEY_FILE=key.pem\n");
+    let args = &["--enable", "MissingPath"];
+    let expected_output = format!(
+        "{}:2 MissingPath: The KEY_FILE key points to a missing path: key.pem\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn path_relative_to_env_file() {
+    let testdir = TestDir::new();
+    let subdir = testdir.subdir();
+    subdir.create_testfile("cert.pem", "");
+    subdir.create_testfile(".env", "CERT_FILE=cert.pem\n");
+    let args = &["--enable", "MissingPath", subdir.as_str()];
+
+    testdir.test_command_success_with_args(args);
+}
@@ -1,2 +1,61 @@
+mod ansi_c_quote;
+mod assignment_shape;
+mod backslash_path;
+mod boolean_consistency;
+mod circular_substitution;
+mod comment_spacing;
+mod comment_style;
+mod commented_key;
+mod credential_in_url;
+mod cross_file_reference;
+mod duplicated_delimiter;
+mod duplicated_key;
+mod duration_unit;
+mod empty_file;
+mod empty_production_secret;
 mod ending_blank_line;
+mod escaped_closing_quote;
+mod export_style;
 mod extra_blank_line;
+mod forbidden_key;
+mod inconsistent_ending_newline;
+mod inconsistent_export;
+mod inconsistent_value_type;
+mod index_gap;
+mod integer_range;
+mod invalid_control_comment;
+mod invisible_character;
+mod key_before_header;
+mod key_case_conflict;
+mod line_continuation;
+mod list_separator;
+mod localhost_value;
+mod missing_path;
+mod multiline_value;
+mod normalized_collision;
+mod numbered_key;
+mod numeric_key_boolean_value;
+mod pair_list;
+mod path_separator;
+mod port_value;
+mod quoted_substitution;
+mod redundant_key_prefix_value;
+mod repeated_comment;
+mod required_keys;
+mod reserved_identifier;
+mod shell_unsafe_value;
+mod smart_quote;
+mod split_assignment;
+mod swallowed_comment;
+mod systemd_compat;
+mod trailing_comma;
+mod truncated_value;
+mod unchanged_example_value;
+mod unescaped_quote;
+mod unresolved_template;
+mod unused_key;
+mod url_scheme;
+mod url_trailing_slash;
+mod whitespace_report;
+mod whitespace_value;
+mod windows_expansion;
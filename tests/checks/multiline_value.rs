@@ -0,0 +1,23 @@
+use crate::common::TestDir;
+
+#[test]
+fn multiline_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "CERT=\"-----BEGIN-----\n-----END-----\"\n");
+    let args = &[
+        "--enable",
+        "MultilineValue",
+        "--skip",
+        "QuoteCharacter",
+        "LeadingCharacter",
+        "KeyWithoutValue",
+        "--",
+        testfile.as_str(),
+    ];
+    let expected_output = format!(
+        "{}:1 MultilineValue: The CERT key has a multi-line value, it is not supported by all loaders\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
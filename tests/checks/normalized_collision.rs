@@ -0,0 +1,22 @@
+use crate::common::TestDir;
+
+#[test]
+fn disabled_by_default() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "APP-NAME=web\nAPP_NAME=api\n");
+    let args = &["--skip", "IncorrectDelimiter"];
+
+    testdir.test_command_success_with_args(args);
+}
+
+#[test]
+fn colliding_keys() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "APP_NAME=api\napp.name=web\napp-name=worker\n");
+    let args = &["--only", "NormalizedCollision"];
+    let expected_output = String::from(
+        ".env:2 NormalizedCollision: The app.name key collides with the APP_NAME key, both of them are APP_NAME after normalization\n.env:3 NormalizedCollision: The app-name key collides with the APP_NAME key, both of them are APP_NAME after normalization\n\nFound 2 problems\n",
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
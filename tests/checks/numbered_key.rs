@@ -0,0 +1,26 @@
+use crate::common::TestDir;
+
+#[test]
+fn numbered_keys() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "HOST1=a\nHOST2=b\nPORT=80\n");
+    let args = &["--enable", "NumberedKey", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 NumberedKey: The HOST1, HOST2 keys differ only by a number, consider using a single key with a list of values\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn min_group_size_from_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [NumberedKey]\nchecks:\n  NumberedKey:\n    min_group_size: 3\n",
+    );
+    testdir.create_testfile(".env", "HOST1=a\nHOST2=b\nPORT=80\n");
+
+    testdir.test_command_success();
+}
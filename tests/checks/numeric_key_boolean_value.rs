@@ -0,0 +1,14 @@
+use crate::common::TestDir;
+
+#[test]
+fn numeric_key_boolean_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "DEBUG=true\nMAX_RETRIES=true\n");
+    let args = &["--enable", "NumericKeyBooleanValue", testfile.as_str()];
+    let expected_output = format!(
+        "{}:2 NumericKeyBooleanValue: The MAX_RETRIES key should have a number, but it has a boolean value\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
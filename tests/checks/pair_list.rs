@@ -0,0 +1,29 @@
+use crate::common::TestDir;
+
+#[test]
+fn pair_list() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "CACHE_OPTIONS=ttl=60;size=100\nDB_OPTIONS=ssl=true;timeout=;=30\n",
+    );
+    let args = &["--enable", "PairList", testfile.as_str()];
+    let expected_output = format!(
+        "{}:2 PairList: The DB_OPTIONS key has the malformed `timeout=` pair, the pairs should be `key=value` separated by `;`\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn suffixes_from_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [PairList]\nchecks:\n  PairList:\n    suffixes: [_DSN]\n",
+    );
+    testdir.create_testfile(".env", "DB_OPTIONS=timeout=\n");
+
+    testdir.test_command_success();
+}
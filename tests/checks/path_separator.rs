@@ -0,0 +1,32 @@
+use crate::common::TestDir;
+
+#[test]
+fn unix_platform() {
+    let testdir = TestDir::new();
+    let testfile =
+        testdir.create_testfile(".env", "DATA_DIR=data/uploads\nLOG_FILE=logs\\app.log\n");
+    let args = &["--enable", "PathSeparator", testfile.as_str()];
+    let expected_output = format!(
+        "{}:2 PathSeparator: The LOG_FILE key has a path with `\\`, use `/` as the separator on Unix\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn windows_platform_from_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [PathSeparator]\nchecks:\n  PathSeparator:\n    platform: windows\n",
+    );
+    let testfile =
+        testdir.create_testfile(".env", "DATA_DIR=data\\uploads\nLOG_FILE=logs/app.log\n");
+    let expected_output = format!(
+        "{}:2 PathSeparator: The LOG_FILE key has a path with `/`, use `\\` as the separator on Windows\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
@@ -0,0 +1,17 @@
+use crate::common::TestDir;
+
+#[test]
+fn port_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "DB_HOST=localhost\nDB_PORT=5432\nREDIS_PORT=redis\n",
+    );
+    let args = &["--enable", "PortValue", testfile.as_str()];
+    let expected_output = format!(
+        "{}:3 PortValue: The REDIS_PORT key has a non-numeric value, a port should be a number from 1 to 65535\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,23 @@
+use crate::common::TestDir;
+
+#[test]
+fn quoted_substitution() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "HOST=localhost\nURL='${HOST}/api'\nURL_EXPANDED=\"${HOST}/api\"\n",
+    );
+    let args = &[
+        "--enable",
+        "QuotedSubstitution",
+        "--skip",
+        "QuoteCharacter",
+        testfile.as_str(),
+    ];
+    let expected_output = format!(
+        "{}:2 QuotedSubstitution: The URL key has the `${{HOST}}` reference in single quotes, it is not expanded, use double quotes to expand it\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
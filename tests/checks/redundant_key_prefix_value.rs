@@ -0,0 +1,14 @@
+use crate::common::TestDir;
+
+#[test]
+fn redundant_key_prefix_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "NAME=NAMESPACE\nTIMEOUT=TIMEOUT_30\n");
+    let args = &["--enable", "RedundantKeyPrefixValue", testfile.as_str()];
+    let expected_output = format!(
+        "{}:2 RedundantKeyPrefixValue: The value of the TIMEOUT key starts with the key name: TIMEOUT_30\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
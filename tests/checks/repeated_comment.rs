@@ -0,0 +1,32 @@
+use crate::common::TestDir;
+
+#[test]
+fn repeated_comment() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "API_KEY=xxx # TODO fill this\nAPI_SECRET=xxx # TODO fill this\nAPI_URL=xxx # TODO fill this\nPORT=8080 # public\n",
+    );
+    let args = &["--enable", "RepeatedComment", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 RepeatedComment: The `# TODO fill this` comment is on 3 lines (1, 2, 3), the values may be unfinished\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn max_count() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [RepeatedComment]\nchecks:\n  RepeatedComment:\n    max_count: 3\n",
+    );
+    testdir.create_testfile(
+        ".env",
+        "API_KEY=xxx # TODO fill this\nAPI_SECRET=xxx # TODO fill this\nAPI_URL=xxx # TODO fill this\n",
+    );
+
+    testdir.test_command_success();
+}
@@ -0,0 +1,18 @@
+use crate::common::TestDir;
+
+#[test]
+fn required_keys() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "checks:\n  RequiredKeys:\n    keys: [APP_ENV]\n    overrides:\n      - files: \"*.production\"\n        keys: [SECRET_KEY]\n",
+    );
+    testdir.create_testfile(".env", "APP_ENV=dev\n");
+    let testfile = testdir.create_testfile(".env.production", "APP_ENV=prod\n");
+    let expected_output = format!(
+        "{}:2 RequiredKeys: The SECRET_KEY key is required, but it is missing\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
@@ -0,0 +1,30 @@
+use crate::common::TestDir;
+
+#[test]
+fn reserved_identifier() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "CLASS=admin\n");
+    let args = &["--enable", "ReservedIdentifier", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 ReservedIdentifier: The CLASS key is a reserved word in Python\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn reserved_words_from_config() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "CLASS=admin\nSETTINGS=true\n");
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [ReservedIdentifier]\nchecks:\n  ReservedIdentifier:\n    language: javascript\n    words: [settings]\n",
+    );
+    let expected_output = format!(
+        "{a}:1 ReservedIdentifier: The CLASS key is a reserved word in JavaScript\n{a}:2 ReservedIdentifier: The SETTINGS key is a reserved word in JavaScript\n\nFound 2 problems\n",
+        a = testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
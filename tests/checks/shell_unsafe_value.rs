@@ -0,0 +1,13 @@
+use crate::common::TestDir;
+
+#[test]
+fn shell_unsafe_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "COMMAND=make&&make\nDIR=${HOME}/data\n");
+    let expected_output = format!(
+        "{}:1 ShellUnsafeValue: The COMMAND key has the `&` shell character in its unquoted value, quote the value if the file is sourced by a shell\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(["--shell-safe"], expected_output);
+}
@@ -0,0 +1,17 @@
+use crate::common::TestDir;
+
+#[test]
+fn smart_quote() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "GREETING=“Hello world”\nMESSAGE=it’s\nNAME=\"Bob\"\n",
+    );
+    let args = &["--only", "SmartQuote", "--", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 SmartQuote: The GREETING key has a value with curly quotes, use straight quotes\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,13 @@
+use crate::common::TestDir;
+
+#[test]
+fn split_assignment() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "DSN host=db user=app\n");
+    let expected_output = format!(
+        "{}:1 SplitAssignment: The DSN host key has a space and its value has `=`, the line is likely a value without quotes\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(["--short-circuit"], expected_output);
+}
@@ -0,0 +1,13 @@
+use crate::common::TestDir;
+
+#[test]
+fn swallowed_comment() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "BAR=#not set\nFOO=bar # set by deploy\n");
+    let expected_output = format!(
+        "{}:1 SwallowedComment: The BAR key has a `#` in its unquoted value, quote the value or put a space before the comment\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
@@ -0,0 +1,26 @@
+use crate::common::TestDir;
+
+#[test]
+fn systemd_compat() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "DATA_DIR=${HOME}/data\nexport PORT=8080\nTODAY=$(date)\n",
+    );
+    let args = &["--enable", "SystemdCompat", testfile.as_str()];
+    let expected_output = format!(
+        "{0}:1 SystemdCompat: The DATA_DIR key isn't compatible with systemd EnvironmentFile: substitutions are not expanded by all systemd versions\n{0}:2 SystemdCompat: The PORT key isn't compatible with systemd EnvironmentFile: the `export` prefix is not supported\n{0}:3 SystemdCompat: The TODAY key isn't compatible with systemd EnvironmentFile: commands in `$(...)` or backticks are not run\n\nFound 3 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn compatible_file() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "# the public port\nPORT=8080\n");
+    let args = &["--enable", "SystemdCompat", testfile.as_str()];
+
+    testdir.test_command_success_with_args(args);
+}
@@ -0,0 +1,44 @@
+use crate::common::TestDir;
+
+#[test]
+fn trailing_comma() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "HOSTS=a,b,c,\n");
+    let args = &["--enable", "TrailingComma", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 TrailingComma: The HOSTS key has a value with a trailing comma\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn trailing_comma_inside_quotes() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "SEPARATOR=','\n");
+    let args = &[
+        "--enable",
+        "TrailingComma",
+        "--skip",
+        "QuoteCharacter",
+        testfile.as_str(),
+    ];
+
+    testdir.test_command_success_with_args(args);
+}
+
+#[test]
+fn trailing_comma_skipped() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "HOSTS=a,b,c,\n");
+    let args = &[
+        "--enable",
+        "TrailingComma",
+        "--skip",
+        "TrailingComma",
+        testfile.as_str(),
+    ];
+
+    testdir.test_command_success_with_args(args);
+}
@@ -0,0 +1,23 @@
+use crate::common::TestDir;
+
+#[test]
+fn correct_file() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO=MIIBIjANBgkqh\n");
+    let args = &[testfile.as_str()];
+
+    testdir.test_command_success_with_args(args);
+}
+
+#[test]
+fn truncated_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO=MIIBIjANBgkqh...\n");
+    let args = &[testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 TruncatedValue: The FOO key has a value that looks truncated\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
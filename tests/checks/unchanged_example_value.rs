@@ -0,0 +1,32 @@
+use crate::common::TestDir;
+
+#[test]
+fn unchanged_example_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "DEBUG=true\nSECRET_KEY=replace-me\n");
+    testdir.create_testfile(".env.example", "DEBUG=false\nSECRET_KEY=replace-me\n");
+    let args = &["--enable", "UnchangedExampleValue"];
+    let expected_output = format!(
+        "{}:2 UnchangedExampleValue: The SECRET_KEY key has the same value as in .env.example, the example value should be replaced\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn example_from_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [UnchangedExampleValue]\nchecks:\n  UnchangedExampleValue:\n    example: .env.dist\n",
+    );
+    let testfile = testdir.create_testfile(".env", "SECRET_KEY=replace-me\n");
+    testdir.create_testfile(".env.dist", "SECRET_KEY=replace-me\n");
+    let expected_output = format!(
+        "{}:1 UnchangedExampleValue: The SECRET_KEY key has the same value as in .env.dist, the example value should be replaced\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
@@ -0,0 +1,17 @@
+use crate::common::TestDir;
+
+#[test]
+fn unescaped_quote() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "ESCAPED=\"he said \\\"hi\\\"\"\nGREETING=\"he said \"hi\"\"\n",
+    );
+    let args = &["--skip", "QuoteCharacter", testfile.as_str()];
+    let expected_output = format!(
+        "{}:2 UnescapedQuote: The GREETING key has an unescaped quote at column 19, inner quotes should be escaped with a backslash\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
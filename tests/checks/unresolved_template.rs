@@ -0,0 +1,29 @@
+use crate::common::TestDir;
+
+#[test]
+fn unresolved_template() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "API_KEY=<your-key-here>\nHOST={{ HOST }}\n");
+    let expected_output = format!(
+        "{0}:1 UnresolvedTemplate: The API_KEY key has the <your-key-here> template placeholder, it should be replaced with a value\n{0}:2 UnresolvedTemplate: The HOST key has the {{{{ HOST }}}} template placeholder, it should be replaced with a value\n\nFound 2 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
+
+#[test]
+fn custom_delimiters() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "checks:\n  UnresolvedTemplate:\n    delimiters:\n      - open: \"@@\"\n        close: \"@@\"\n",
+    );
+    let testfile = testdir.create_testfile(".env", "API_KEY=<key>\nTOKEN=@@TOKEN@@\n");
+    let expected_output = format!(
+        "{}:2 UnresolvedTemplate: The TOKEN key has the @@TOKEN@@ template placeholder, it should be replaced with a value\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
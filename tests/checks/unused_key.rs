@@ -0,0 +1,25 @@
+use crate::common::TestDir;
+
+#[test]
+fn disabled_by_default() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "DEBUG=true\n");
+
+    testdir.test_command_success();
+}
+
+#[test]
+fn unused_keys() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "HOST=localhost\nPORT=80\n");
+    testdir.create_testfile(".env.local", "URL=http://${HOST}\n");
+    let args = &["--enable", "UnusedKey"];
+    let expected_output = String::from(
+        ".env:2 UnusedKey: The PORT key is defined in .env, but it is never referenced\n\
+        .env.local:1 UnusedKey: The URL key is defined in .env.local, but it is never referenced\n\
+        \n\
+        Found 2 problems\n",
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,36 @@
+use crate::common::TestDir;
+
+#[test]
+fn url_scheme() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "API_URL=api.example.com\nAUTH_URL=https://auth.example.com\nSEARCH_ENDPOINT=localhost:9200\n",
+    );
+    let args = &["--enable", "UrlScheme", testfile.as_str()];
+    let expected_output = format!(
+        "{0}:1 UrlScheme: The API_URL key has a URL without a scheme, e.g. https://\n{0}:3 UrlScheme: The SEARCH_ENDPOINT key has a URL without a scheme, e.g. https://\n\nFound 2 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn scheme_not_required() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [UrlScheme]\nchecks:\n  UrlScheme:\n    suffixes: [_HOST]\n    require_scheme: false\n",
+    );
+    let testfile = testdir.create_testfile(
+        ".env",
+        "API_HOST=https://api.example.com\nAPI_URL=api.example.com\n",
+    );
+    let expected_output = format!(
+        "{}:1 UrlScheme: The API_HOST key has a URL with a scheme, use the address without it\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
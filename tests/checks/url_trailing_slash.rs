@@ -0,0 +1,33 @@
+use crate::common::TestDir;
+
+#[test]
+fn inconsistent_trailing_slash() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(
+        ".env",
+        "API_URL=https://api.example.com/\nAUTH_URL=https://auth.example.com\nCDN_URL=https://cdn.example.com\n",
+    );
+    let args = &["--enable", "UrlTrailingSlash", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 UrlTrailingSlash: The API_URL key should have a URL without a trailing slash\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn policy_from_config() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [UrlTrailingSlash]\nchecks:\n  UrlTrailingSlash:\n    policy: require\n",
+    );
+    let testfile = testdir.create_testfile(".env", "API_URL=https://api.example.com\n");
+    let expected_output = format!(
+        "{}:1 UrlTrailingSlash: The API_URL key should have a URL with a trailing slash\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail(expected_output);
+}
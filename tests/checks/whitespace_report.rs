@@ -0,0 +1,18 @@
+use crate::common::TestDir;
+
+#[test]
+fn whitespace_report() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", " A=bar\nB =baz\nC= qux\nD=quux \nE=ok\n");
+    let args = &["--only", "WhitespaceReport", "--", testfile.as_str()];
+    let expected_output = format!(
+        "{0}:1 WhitespaceReport: The A key has whitespace before the key\n\
+        {0}:2 WhitespaceReport: The B key has whitespace after the key\n\
+        {0}:3 WhitespaceReport: The C key has whitespace after the equal sign\n\
+        {0}:4 WhitespaceReport: The D key has whitespace after the value\n\
+        \nFound 4 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
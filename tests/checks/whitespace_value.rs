@@ -0,0 +1,14 @@
+use crate::common::TestDir;
+
+#[test]
+fn whitespace_value() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "BAR=\"\"\nFOO='   '\n");
+    let args = &["--skip", "QuoteCharacter", testfile.as_str()];
+    let expected_output = format!(
+        "{}:2 WhitespaceValue: The FOO key has a value with only whitespace inside quotes\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
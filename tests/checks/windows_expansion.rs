@@ -0,0 +1,14 @@
+use crate::common::TestDir;
+
+#[test]
+fn windows_expansion() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "BAR=%A%-%B%\nFOO=100%\n");
+    let args = &["--enable", "WindowsExpansion", testfile.as_str()];
+    let expected_output = format!(
+        "{}:1 WindowsExpansion: The BAR key has a value with the %NAME% expansion, it is not expanded by POSIX tools\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
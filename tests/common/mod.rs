@@ -95,6 +95,77 @@ impl TestDir {
         self.close();
     }
 
+    /// Run the default CLI binary, with command line arguments,
+    /// in this TestDir and check it succeeds with the expected output.
+    ///
+    /// This method removes the TestDir when command has finished.
+    pub fn test_command_success_with_args_and_output<I, S>(self, args: I, expected_output: String)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut cmd = Self::init_cmd();
+        let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
+        cmd.current_dir(&canonical_current_dir)
+            .args(args)
+            .assert()
+            .success()
+            .stdout(expected_output);
+
+        self.close();
+    }
+
+    /// Run the default CLI binary, with command line arguments and stdin,
+    /// in this TestDir and check it succeeds with the expected output.
+    ///
+    /// This method removes the TestDir when command has finished.
+    pub fn test_command_success_with_args_stdin_and_output<I, S>(
+        self,
+        args: I,
+        stdin: &str,
+        expected_output: String,
+    ) where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut cmd = Self::init_cmd();
+        let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
+        cmd.current_dir(&canonical_current_dir)
+            .args(args)
+            .write_stdin(stdin)
+            .assert()
+            .success()
+            .stdout(expected_output);
+
+        self.close();
+    }
+
+    /// Run the default CLI binary, with command line arguments and stdin,
+    /// in this TestDir and check it fails with the expected output.
+    ///
+    /// This method removes the TestDir when command has finished.
+    pub fn test_command_fail_with_args_stdin_and_output<I, S>(
+        self,
+        args: I,
+        stdin: &str,
+        expected_output: String,
+    ) where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut cmd = Self::init_cmd();
+        let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
+        cmd.current_dir(&canonical_current_dir)
+            .args(args)
+            .write_stdin(stdin)
+            .assert()
+            .failure()
+            .code(1)
+            .stdout(expected_output);
+
+        self.close();
+    }
+
     /// Run the default CLI binary, with command line arguments,
     /// in this TestDir and check it fails.
     ///
@@ -116,12 +187,98 @@ impl TestDir {
         self.close();
     }
 
+    /// Run the default CLI binary, with command line arguments,
+    /// in this TestDir and check it fails with the exit code.
+    ///
+    /// This method removes the TestDir when command has finished.
+    pub fn test_command_fail_with_args_and_code<I, S>(
+        self,
+        args: I,
+        code: i32,
+        expected_output: String,
+    ) where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut cmd = Self::init_cmd();
+        let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
+        cmd.current_dir(&canonical_current_dir)
+            .args(args)
+            .assert()
+            .failure()
+            .code(code)
+            .stdout(expected_output);
+
+        self.close();
+    }
+
+    /// Run the default CLI binary, with command line arguments,
+    /// in this TestDir and check it fails with the expected error message.
+    ///
+    /// This method removes the TestDir when command has finished.
+    pub fn test_command_error_with_args<I, S>(self, args: I, expected_error: &str)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut cmd = Self::init_cmd();
+        let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
+        let output = cmd
+            .current_dir(&canonical_current_dir)
+            .args(args)
+            .output()
+            .expect("command output");
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(!output.status.success());
+        assert!(
+            stderr.contains(expected_error),
+            "Expected the error {:?}, got {:?}",
+            expected_error,
+            stderr
+        );
+
+        self.close();
+    }
+
+    /// Run the default CLI binary, with command line arguments,
+    /// in this TestDir and return its stdout (the TestDir is kept).
+    pub fn command_output_with_args<I, S>(&self, args: I) -> Vec<u8>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut cmd = Self::init_cmd();
+        let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
+        cmd.current_dir(&canonical_current_dir)
+            .args(args)
+            .output()
+            .expect("command output")
+            .stdout
+    }
+
+    pub fn command_stderr_with_args<I, S>(&self, args: I) -> String
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut cmd = Self::init_cmd();
+        let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
+        let output = cmd
+            .current_dir(&canonical_current_dir)
+            .args(args)
+            .output()
+            .expect("command output");
+
+        String::from_utf8_lossy(&output.stderr).to_string()
+    }
+
     /// Run the default CLI binary, with "-f", in this TestDir and check it succeeds.
     pub fn test_command_fix_success(&self, expected_output: String) {
         let mut cmd = Self::init_cmd();
         let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
         cmd.current_dir(&canonical_current_dir)
-            .args(&["-f"])
+            .args(["-f"])
             .assert()
             .success()
             .stdout(expected_output);
@@ -132,7 +289,7 @@ impl TestDir {
         let mut cmd = Self::init_cmd();
         let canonical_current_dir = canonicalize(&self.current_dir).expect("canonical current dir");
         cmd.current_dir(&canonical_current_dir)
-            .args(&["-f"])
+            .args(["-f"])
             .assert()
             .failure()
             .code(1)
@@ -0,0 +1,23 @@
+use crate::common::TestDir;
+
+#[test]
+fn comment_spacing() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [CommentSpacing]\nchecks:\n  CommentSpacing:\n    required: true\n",
+    );
+    let testfile = testdir.create_testfile(".env", "# Database\nDB=db\n# Cache\nREDIS=redis\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:3 CommentSpacing: The comment should be preceded by a blank line\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "# Database\nDB=db\n\n# Cache\nREDIS=redis\n"
+    );
+
+    testdir.close();
+}
@@ -0,0 +1,20 @@
+use crate::common::TestDir;
+
+#[test]
+fn comment_style() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [CommentStyle]\nskip: [LeadingCharacter, KeyWithoutValue]\n",
+    );
+    let testfile = testdir.create_testfile(".env", "# Database\nDB=db\n; Cache\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:3 CommentStyle: The comment should start with #\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "# Database\nDB=db\n# Cache\n");
+
+    testdir.close();
+}
@@ -0,0 +1,16 @@
+use crate::common::TestDir;
+
+#[test]
+fn duplicated_delimiter() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO==BAR\nTOKEN=dGVzdA==\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 DuplicatedDelimiter: The FOO key has a duplicated delimiter\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "FOO=BAR\nTOKEN=dGVzdA==\n");
+
+    testdir.close();
+}
@@ -0,0 +1,39 @@
+use crate::common::TestDir;
+
+#[test]
+fn duplicated_key() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".dotenv-linter.yml", "only: [DuplicatedKey]\n");
+    let testfile = testdir.create_testfile(".env", "A=1\nB=2\nA='1'\nB=3\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 DuplicatedKey: The A key is duplicated with the same value\n\
+        \n\
+        Unfixed warnings:\n\
+        .env:3 [error] DuplicatedKey: The B key is duplicated with a different value\n",
+    );
+    testdir.test_command_fix_fail(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "B=2\nA='1'\nB=3\n");
+
+    testdir.close();
+}
+
+#[test]
+fn three_identical_definitions() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "B=1\nA=1\nA=1\nA=1\nC=1\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:2 DuplicatedKey: The A key is duplicated with the same value\n\
+        .env:3 DuplicatedKey: The A key is duplicated with the same value\n\
+        \n\
+        Unfixed warnings:\n\
+        .env:2 UnorderedKey: The A key should go before the B key\n",
+    );
+    testdir.test_command_fix_fail(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "B=1\nA=1\nC=1\n");
+
+    testdir.close();
+}
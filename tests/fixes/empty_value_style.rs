@@ -0,0 +1,46 @@
+use crate::common::TestDir;
+
+#[test]
+fn quoted_to_bare() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [EmptyValueStyle]\nskip: [QuoteCharacter]\n",
+    );
+    let testfile = testdir.create_testfile(".env", "API_KEY=\nSECRET_KEY=\"\"\nTOKEN=\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:2 EmptyValueStyle: The SECRET_KEY key should have an empty value without quotes, as the other empty values\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "API_KEY=\nSECRET_KEY=\nTOKEN=\n"
+    );
+
+    testdir.close();
+}
+
+#[test]
+fn bare_to_quoted() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [EmptyValueStyle]\nskip: [QuoteCharacter]\nchecks:\n  EmptyValueStyle:\n    style: quoted\n",
+    );
+    let testfile = testdir.create_testfile(".env", "API_KEY=\nSECRET_KEY=\"\" # later\nTOKEN=\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 EmptyValueStyle: The API_KEY key should have an empty value in quotes, as the other empty values\n\
+        .env:3 EmptyValueStyle: The TOKEN key should have an empty value in quotes, as the other empty values\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "API_KEY=\"\"\nSECRET_KEY=\"\" # later\nTOKEN=\"\"\n"
+    );
+
+    testdir.close();
+}
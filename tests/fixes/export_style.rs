@@ -0,0 +1,39 @@
+use crate::common::TestDir;
+
+#[test]
+fn forbid_export() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "checks:\n  ExportStyle:\n    style: forbid\n",
+    );
+    let testfile = testdir.create_testfile(".env", "export BAR=1\nFOO=2\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 ExportStyle: The BAR key should be defined without export\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "BAR=1\nFOO=2\n");
+
+    testdir.close();
+}
+
+#[test]
+fn require_export() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "checks:\n  ExportStyle:\n    style: require\n",
+    );
+    let testfile = testdir.create_testfile(".env", "export BAR=1\nFOO=2\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:2 ExportStyle: The FOO key should be defined with export\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "export BAR=1\nexport FOO=2\n");
+
+    testdir.close();
+}
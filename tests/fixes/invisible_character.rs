@@ -0,0 +1,32 @@
+use crate::common::TestDir;
+
+#[test]
+fn invisible_character() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "ABC\u{200B}=DEF\n\nFOO=B\u{00A0}AR\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 InvisibleCharacter: Invisible character U+200B detected at position 4\n\
+        .env:3 InvisibleCharacter: Invisible character U+00A0 detected at position 6\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "ABC=DEF\n\nFOO=BAR\n");
+
+    testdir.close();
+}
+
+#[test]
+fn null_byte() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "FOO=B\0AR\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 InvisibleCharacter: Invisible character U+0000 detected at position 6\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "FOO=BAR\n");
+
+    testdir.close();
+}
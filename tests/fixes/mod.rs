@@ -1,10 +1,22 @@
+mod padded_number;
 mod quote_character;
 
 use crate::common::TestDir;
 
+mod comment_spacing;
+mod comment_style;
+mod duplicated_delimiter;
+mod duplicated_key;
+mod empty_value_style;
 mod ending_blank_line;
+mod export_style;
+mod invisible_character;
+mod smart_quote;
 mod space_character;
+mod trailing_comma;
 mod trailing_whitespace;
+mod whitespace_report;
+mod whitespace_value;
 
 #[test]
 fn correct_file() {
@@ -25,7 +37,7 @@ fn lowercase_key() {
     let expected_output = String::from(
         "Fixed warnings:\n\
         .env:1 LowercaseKey: The abc key should be in uppercase\n\
-        .env:3 LowercaseKey: The fOO key should be in uppercase\n",
+        .env:3 LowercaseKey: The fOO key should be in uppercase (the first lowercase character is at position 1, in the fOO part)\n",
     );
     testdir.test_command_fix_success(expected_output);
 
@@ -49,6 +61,21 @@ fn key_without_value() {
     testdir.close();
 }
 
+#[test]
+fn key_whitespace() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "export  FOO=BAR\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 KeyWhitespace: The FOO key has extra whitespace after the export prefix\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "export FOO=BAR\n");
+
+    testdir.close();
+}
+
 #[test]
 fn unfixed_warnings() {
     let testdir = TestDir::new();
@@ -94,3 +121,54 @@ fn multiple_files() {
 
     testdir.close();
 }
+
+#[test]
+fn crlf_line_endings_are_preserved() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "abc=DEF\r\n\r\nFOO=BAR\r\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 LowercaseKey: The abc key should be in uppercase\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "ABC=DEF\r\n\r\nFOO=BAR\r\n");
+
+    testdir.close();
+}
+
+#[test]
+fn second_fix_changes_nothing() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "enable: [CommentSpacing, CommentStyle, TrailingComma]\nskip: [LeadingCharacter, KeyWithoutValue, UnorderedKey]\nchecks:\n  CommentSpacing:\n    required: true\n",
+    );
+    let testfile = testdir.create_testfile(
+        ".env",
+        "# Database\ndb=db\n; Cache\nexport  REDIS= redis \nZ_HOSTS=a,b,\nZ_PORT=\" 8080 \"",
+    );
+    // The comment on the 3rd line needs a blank line before it only after CommentStyle is fixed
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:2 LowercaseKey: The db key should be in uppercase\n\
+        .env:3 CommentSpacing: The comment should be preceded by a blank line\n\
+        .env:3 CommentStyle: The comment should start with #\n\
+        .env:4 KeyWhitespace: The REDIS key has extra whitespace after the export prefix\n\
+        .env:4 SpaceCharacter: The line has spaces around equal sign\n\
+        .env:4 TrailingWhitespace: Trailing whitespace detected\n\
+        .env:5 TrailingComma: The Z_HOSTS key has a value with a trailing comma\n\
+        .env:6 EndingBlankLine: No blank line at the end of the file\n\
+        .env:6 PaddedNumber: The Z_PORT key has a number with spaces inside quotes, the spaces are a part of the value\n",
+    );
+    // The quotes are removed by PaddedNumber, so QuoteCharacter changes nothing
+    testdir.test_command_fix_success(expected_output);
+
+    let fixed = "# Database\nDB=db\n\n# Cache\nexport REDIS=redis\nZ_HOSTS=a,b\nZ_PORT=8080\n";
+    assert_eq!(testfile.contents().as_str(), fixed);
+
+    testdir.test_command_fix_success(String::new());
+    assert_eq!(testfile.contents().as_str(), fixed);
+
+    testdir.test_command_success_with_args_and_output(["--patch"], String::new());
+}
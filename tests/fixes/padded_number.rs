@@ -0,0 +1,21 @@
+use crate::common::TestDir;
+
+#[test]
+fn padded_number() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".dotenv-linter.yml", "skip: [QuoteCharacter]\n");
+    let testfile = testdir.create_testfile(".env", "PORT=\" 8080 \"\nTIMEOUT=\" 30\" # seconds\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 PaddedNumber: The PORT key has a number with spaces inside quotes, the spaces are a part of the value\n\
+        .env:2 PaddedNumber: The TIMEOUT key has a number with spaces inside quotes, the spaces are a part of the value\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "PORT=8080\nTIMEOUT=\"30\" # seconds\n"
+    );
+
+    testdir.close();
+}
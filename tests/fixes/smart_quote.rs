@@ -0,0 +1,25 @@
+use crate::common::TestDir;
+
+#[test]
+fn smart_quote() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".dotenv-linter.yml", "only: [SmartQuote]\n");
+    let testfile = testdir.create_testfile(
+        ".env",
+        "GREETING=“Hello world”\nNAME=‘Bob’ # it’s him\nTITLE='say “hi”'\n",
+    );
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 SmartQuote: The GREETING key has a value with curly quotes, use straight quotes\n\
+        .env:2 SmartQuote: The NAME key has a value with curly quotes, use straight quotes\n\
+        .env:3 SmartQuote: The TITLE key has a value with curly quotes, use straight quotes\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "GREETING=\"Hello world\"\nNAME='Bob' # it’s him\nTITLE='say \"hi\"'\n"
+    );
+
+    testdir.close();
+}
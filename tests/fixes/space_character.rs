@@ -15,3 +15,26 @@ fn space_character() {
 
     testdir.close();
 }
+
+#[test]
+fn aligned_space_character() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(
+        ".dotenv-linter.yml",
+        "checks:\n  SpaceCharacter:\n    aligned: true\n",
+    );
+    let testfile = testdir.create_testfile(".env", "ABC   =DEF\nFOO   = BAR\nQUX =1\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:2 SpaceCharacter: The line has spaces around equal sign\n\
+        .env:3 SpaceCharacter: The equal sign should be at column 7 to be aligned with the other keys\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "ABC   =DEF\nFOO   =BAR\nQUX   =1\n"
+    );
+
+    testdir.close();
+}
@@ -0,0 +1,17 @@
+use crate::common::TestDir;
+
+#[test]
+fn trailing_comma() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".dotenv-linter.yml", "enable: [TrailingComma]\n");
+    let testfile = testdir.create_testfile(".env", "HOSTS=a,b,c,\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 TrailingComma: The HOSTS key has a value with a trailing comma\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "HOSTS=a,b,c\n");
+
+    testdir.close();
+}
@@ -15,3 +15,19 @@ fn trailing_whitespace() {
 
     testdir.close();
 }
+
+#[test]
+fn comment_line() {
+    let testdir = TestDir::new();
+    let testfile = testdir.create_testfile(".env", "# Database  \nABC=DEF # port \n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 TrailingWhitespace: Trailing whitespace detected\n\
+        .env:2 TrailingWhitespace: Trailing whitespace detected\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "# Database\nABC=DEF # port\n");
+
+    testdir.close();
+}
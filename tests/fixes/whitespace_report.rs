@@ -0,0 +1,24 @@
+use crate::common::TestDir;
+
+#[test]
+fn whitespace_report() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".dotenv-linter.yml", "only: [WhitespaceReport]\n");
+    let testfile = testdir.create_testfile(".env", " A=bar\nB =baz\nC= qux\nD=quux \n E = x \n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:1 WhitespaceReport: The A key has whitespace before the key\n\
+        .env:2 WhitespaceReport: The B key has whitespace after the key\n\
+        .env:3 WhitespaceReport: The C key has whitespace after the equal sign\n\
+        .env:4 WhitespaceReport: The D key has whitespace after the value\n\
+        .env:5 WhitespaceReport: The E key has whitespace before the key, after the key, after the equal sign and after the value\n",
+    );
+    testdir.test_command_fix_success(expected_output);
+
+    assert_eq!(
+        testfile.contents().as_str(),
+        "A=bar\nB=baz\nC=qux\nD=quux\nE=x\n"
+    );
+
+    testdir.close();
+}
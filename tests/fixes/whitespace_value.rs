@@ -0,0 +1,20 @@
+use crate::common::TestDir;
+
+#[test]
+fn whitespace_value() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".dotenv-linter.yml", "skip: [QuoteCharacter]\n");
+    let testfile = testdir.create_testfile(".env", "BAR=\" \" # TODO\nFOO=\"   \"\n");
+    let expected_output = String::from(
+        "Fixed warnings:\n\
+        .env:2 WhitespaceValue: The FOO key has a value with only whitespace inside quotes\n\
+        \n\
+        Unfixed warnings:\n\
+        .env:1 WhitespaceValue: The BAR key has a value with only whitespace inside quotes\n",
+    );
+    testdir.test_command_fix_fail(expected_output);
+
+    assert_eq!(testfile.contents().as_str(), "BAR=\" \" # TODO\nFOO=\"\"\n");
+
+    testdir.close();
+}
@@ -0,0 +1,55 @@
+use crate::common::TestDir;
+use std::fs::File;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+fn set_modified_ago(path: &str, ago: Duration) {
+    File::options()
+        .write(true)
+        .open(path)
+        .expect("open test file")
+        .set_modified(SystemTime::now() - ago)
+        .expect("set modification time");
+}
+
+#[test]
+fn checks_recently_modified_files() {
+    let test_dir = TestDir::new();
+    let old_file = test_dir.create_testfile(".env", "foo=bar\n");
+    set_modified_ago(old_file.as_str(), Duration::from_secs(2 * 60 * 60));
+    let new_file = test_dir.create_testfile(".env.local", "bar=baz\n");
+
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The bar key should be in uppercase\n\nFound 1 problem\n",
+        new_file.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(["--changed-within", "10m"], expected_output);
+}
+
+#[test]
+fn checks_recently_modified_files_recursively() {
+    let test_dir = TestDir::new();
+    let test_subdir = test_dir.subdir();
+    let old_file = test_subdir.create_testfile(".env", "foo=bar\n");
+    set_modified_ago(old_file.as_str(), Duration::from_secs(2 * 24 * 60 * 60));
+    let new_file = test_subdir.create_testfile(".env.local", "bar=baz\n");
+
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The bar key should be in uppercase\n\nFound 1 problem\n",
+        Path::new(&test_dir.relative_path(&test_subdir))
+            .join(new_file.shortname_as_str())
+            .to_str()
+            .expect("multi-platform path to test .env file")
+    );
+
+    test_dir.test_command_fail_with_args(["--changed-within", "1d", "-r"], expected_output);
+}
+
+#[test]
+fn invalid_duration() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+
+    test_dir.test_command_error_with_args(["--changed-within", "10"], "Invalid duration 10");
+}
@@ -0,0 +1,31 @@
+use crate::common::TestDir;
+
+#[test]
+fn compose() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+    test_dir.create_testfile(
+        "docker-compose.yml",
+        "services:\n  web:\n    environment:\n      - FOO=bar\n      - foo=baz\n  db:\n    environment:\n      DB_NAME: db\n      DB_NAME: test\n",
+    );
+    let expected_output = String::from(
+        "docker-compose.yml:5 LowercaseKey: The foo key should be in uppercase\n\
+        docker-compose.yml:9 [error] DuplicatedKey: The DB_NAME key is duplicated with a different value\n\
+        \n\
+        Found 2 problems\n",
+    );
+
+    test_dir.test_command_fail_with_args(["--compose"], expected_output);
+}
+
+#[test]
+fn compose_files_are_skipped_by_default() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+    test_dir.create_testfile(
+        "docker-compose.yml",
+        "services:\n  web:\n    environment:\n      - foo=bar\n",
+    );
+
+    test_dir.test_command_success();
+}
@@ -0,0 +1,35 @@
+use crate::common::TestDir;
+
+#[test]
+fn count() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\nbar=foo\n");
+
+    test_dir.test_command_fail_with_args(["--count"], String::from("3\n"));
+}
+
+#[test]
+fn count_with_skip() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\nbar=foo\n");
+
+    test_dir
+        .test_command_fail_with_args(["--count", "--skip", "UnorderedKey"], String::from("2\n"));
+}
+
+#[test]
+fn count_without_warnings() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+
+    test_dir.test_command_success_with_args_and_output(["--count"], String::from("0\n"));
+}
+
+#[test]
+fn count_with_exit_zero() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\n");
+
+    test_dir
+        .test_command_success_with_args_and_output(["--count", "--exit-zero"], String::from("1\n"));
+}
@@ -0,0 +1,36 @@
+use crate::common::TestDir;
+
+const DIFF: &str = "--- a/.env\n+++ b/.env\n@@ -1,2 +1,3 @@\n A=1\n+c=2\n B=3\n";
+
+#[test]
+fn reports_only_changed_lines() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "A=1\nc=2\nB=3\n");
+    let expected_output = format!(
+        "{}:2 LowercaseKey: The c key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args_stdin_and_output(["--diff-only"], DIFF, expected_output);
+}
+
+#[test]
+fn skips_unchanged_files() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "A=1\nc=2\nB=3\n");
+    test_dir.create_testfile(".env.test", "foo=bar\n");
+
+    test_dir.test_command_fail_with_args_stdin_and_output(
+        ["--diff-only", "--skip", "UnorderedKey"],
+        DIFF,
+        String::from(".env:2 LowercaseKey: The c key should be in uppercase\n\nFound 1 problem\n"),
+    );
+}
+
+#[test]
+fn since_requires_diff_only() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\n");
+
+    test_dir.test_command_error_with_args(["--since", "HEAD"], "--diff-only");
+}
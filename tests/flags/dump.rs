@@ -0,0 +1,22 @@
+use crate::common::TestDir;
+
+#[test]
+fn dump() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "export FOO=\"bar baz\" # comment\nfoo=1\n# BAR=2\n");
+    let expected_output = String::from(".env\n  1 FOO=\"bar baz\" (exported)\n  2 foo=\"1\"\n");
+
+    test_dir.test_command_success_with_args_and_output(["--dump"], expected_output);
+}
+
+#[test]
+fn dump_json() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO='bar'\n");
+    let expected_output = String::from(
+        "[{\"file\":\".env\",\"keys\":[{\"line\":1,\"key\":\"FOO\",\"value\":\"bar\",\"exported\":false}]}]\n",
+    );
+
+    test_dir
+        .test_command_success_with_args_and_output(["--dump", "--format", "json"], expected_output);
+}
@@ -0,0 +1,32 @@
+use crate::common::TestDir;
+
+#[test]
+fn exit_zero() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "FOO=bar\nFOO=bar\n");
+    let expected_output = format!(
+        "{}:2 [error] DuplicatedKey: The FOO key is duplicated with the same value\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_success_with_args_and_output(
+        ["--exit-zero", "--error", "DuplicatedKey"],
+        expected_output,
+    );
+}
+
+#[test]
+fn exit_zero_with_skipped_files() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=bar\n");
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args_and_code(
+        ["--exit-zero", testfile.as_str(), ".env.deleted"],
+        2,
+        expected_output,
+    );
+}
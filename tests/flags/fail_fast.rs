@@ -0,0 +1,41 @@
+use crate::common::TestDir;
+
+#[test]
+fn stops_at_the_first_warning() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+    test_dir.create_testfile(".env.local", "abc=bar\nfoo=bar\n");
+    test_dir.create_testfile(".env.test", "1FOO=bar\n");
+    let expected_output = String::from(
+        ".env.local:1 LowercaseKey: The abc key should be in uppercase\n\nFound 1 problem\n",
+    );
+
+    test_dir.test_command_fail_with_args(["--fail-fast"], expected_output);
+}
+
+#[test]
+fn baseline_warnings_do_not_stop_the_run() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "abc=bar\n");
+    test_dir.create_testfile(".env.test", "1FOO=bar\n");
+    let baseline = test_dir.create_testfile(
+        "baseline.json",
+        r#"{"warnings": [
+            {"file": ".env", "line": 1, "check": "LowercaseKey", "message": "The abc key should be in uppercase"}
+        ]}"#,
+    );
+    let args = &["--fail-fast", "--baseline", baseline.as_str()];
+    let expected_output = String::from(
+        ".env.test:1 LeadingCharacter: Invalid leading character detected\n\nFound 1 problem\n",
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn without_warnings() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+
+    test_dir.test_command_success_with_args(["--fail-fast"]);
+}
@@ -0,0 +1,34 @@
+use crate::common::TestDir;
+
+#[test]
+fn writes_fixed_content_to_stdout() {
+    let test_dir = TestDir::new();
+
+    test_dir.test_command_success_with_args_stdin_and_output(
+        ["--fix-stdin"],
+        "foo = bar \nBAR=\"baz\"",
+        String::from("FOO=bar\nBAR=baz\n"),
+    );
+}
+
+#[test]
+fn correct_content_is_unchanged() {
+    let test_dir = TestDir::new();
+
+    test_dir.test_command_success_with_args_stdin_and_output(
+        ["--fix-stdin", "--stdin-filename", ".env.local"],
+        "BAR=1\nFOO=2\n",
+        String::from("BAR=1\nFOO=2\n"),
+    );
+}
+
+#[test]
+fn skips_checks() {
+    let test_dir = TestDir::new();
+
+    test_dir.test_command_success_with_args_stdin_and_output(
+        ["--fix-stdin", "--skip", "LowercaseKey"],
+        "foo=bar\n",
+        String::from("foo=bar\n"),
+    );
+}
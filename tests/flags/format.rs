@@ -0,0 +1,236 @@
+use crate::common::TestDir;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn json_format() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\n");
+    let expected_output = String::from(
+        r#"{"warnings":[{"file":".env","line":1,"check":"LowercaseKey","message":"The foo key should be in uppercase","severity":"warning","fixed":false,"fingerprint":"dc0bd65085a4705f"}],"summary":{"total":1,"errors":0,"checks":{"LowercaseKey":1},"files":{".env":1}}}
+"#,
+    );
+
+    test_dir.test_command_fail_with_args(["--format", "json"], expected_output);
+}
+
+#[test]
+fn json_format_with_spans() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO-BAR-BAZ=1\n");
+    let expected_output = String::from(
+        r#"{"warnings":[{"file":".env","line":1,"check":"IncorrectDelimiter","message":"The FOO-BAR-BAZ key has incorrect delimiter (2 incorrect delimiters at columns 4, 8)","severity":"warning","fixed":false,"fingerprint":"495d847a57700e94","spans":[{"column":4,"length":1},{"column":8,"length":1}]}],"summary":{"total":1,"errors":0,"checks":{"IncorrectDelimiter":1},"files":{".env":1}}}
+"#,
+    );
+
+    test_dir.test_command_fail_with_args(["--format", "json"], expected_output);
+}
+
+#[test]
+fn json_format_without_warnings() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+    let expected_output = String::from(
+        "{\"warnings\":[],\"summary\":{\"total\":0,\"errors\":0,\"checks\":{},\"files\":{}}}\n",
+    );
+
+    test_dir.test_command_success_with_args_and_output(["--format", "json"], expected_output);
+}
+
+#[test]
+fn json_format_with_source() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\n");
+    let expected_output = String::from(
+        r#"{"warnings":[{"file":".env","line":1,"check":"LowercaseKey","message":"The foo key should be in uppercase","severity":"warning","fixed":false,"fingerprint":"dc0bd65085a4705f","source":"foo=bar"}],"summary":{"total":1,"errors":0,"checks":{"LowercaseKey":1},"files":{".env":1}}}
+"#,
+    );
+
+    test_dir.test_command_fail_with_args(["--format", "json", "--include-source"], expected_output);
+}
+
+#[test]
+fn json_format_with_fix() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=bar\n");
+    let expected_output = String::from(
+        r#"{"warnings":[{"file":".env","line":1,"check":"LowercaseKey","message":"The foo key should be in uppercase","severity":"warning","fixed":true,"fingerprint":"dc0bd65085a4705f","source":"foo=bar","fix":{"before":"foo=bar","after":["FOO=bar"]}}],"summary":{"total":1,"errors":0,"checks":{"LowercaseKey":1},"files":{".env":1}}}
+"#,
+    );
+
+    let output =
+        test_dir.command_output_with_args(["--fix", "--format", "json", "--include-source"]);
+
+    assert_eq!(String::from_utf8_lossy(&output), expected_output);
+    assert_eq!(testfile.contents().as_str(), "FOO=bar\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn json_format_with_fix_without_source() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "db_password=hunter2\n");
+
+    let output = test_dir.command_output_with_args(["--fix", "--format", "json"]);
+    let json: serde_json::Value = serde_json::from_slice(&output).expect("json output");
+
+    assert_eq!(json["warnings"][0]["fixed"], true);
+    assert!(json["warnings"][0].get("fix").is_none());
+    assert!(!String::from_utf8_lossy(&output).contains("hunter2"));
+
+    test_dir.close();
+}
+
+#[test]
+fn json_fingerprint_without_line_number() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "# Comment\nfoo=bar\n");
+    let expected_output = String::from(
+        r#"{"warnings":[{"file":".env","line":2,"check":"LowercaseKey","message":"The foo key should be in uppercase","severity":"warning","fixed":false,"fingerprint":"dc0bd65085a4705f"}],"summary":{"total":1,"errors":0,"checks":{"LowercaseKey":1},"files":{".env":1}}}
+"#,
+    );
+
+    test_dir.test_command_fail_with_args(["--format", "json"], expected_output);
+}
+
+#[test]
+fn json_fingerprints_of_identical_lines() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\nfoo=bar\n");
+    let output = test_dir.command_output_with_args(["--format", "json", "--only", "LowercaseKey"]);
+    let report: serde_json::Value = serde_json::from_slice(&output).expect("JSON report");
+    let warnings = report["warnings"].as_array().expect("warnings");
+
+    assert_eq!(2, warnings.len());
+    assert_ne!(warnings[0]["fingerprint"], warnings[1]["fingerprint"]);
+
+    test_dir.close();
+}
+
+#[test]
+fn include_source_requires_json_output() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+
+    test_dir.test_command_error_with_args(["--include-source"], "--format json or --json-file");
+}
+
+#[test]
+fn summary_only() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\nbar=foo\n");
+    test_dir.create_testfile(".env.test", "baz=qux\n");
+    let expected_output = String::from(
+        r#"{"summary":{"total":4,"errors":0,"checks":{"LowercaseKey":3,"UnorderedKey":1},"files":{".env":3,".env.test":1}}}
+"#,
+    );
+
+    test_dir.test_command_fail_with_args(["--format", "json", "--summary-only"], expected_output);
+}
+
+#[test]
+fn summary_only_requires_json_format() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+
+    test_dir.test_command_error_with_args(["--summary-only"], "--format json");
+}
+
+#[test]
+fn json_file_with_text_output() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=bar\n");
+    let report_path = Path::new(test_dir.as_str()).join("report.json");
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+    let expected_report = r#"{"warnings":[{"file":".env","line":1,"check":"LowercaseKey","message":"The foo key should be in uppercase","severity":"warning","fixed":false,"fingerprint":"dc0bd65085a4705f"}],"summary":{"total":1,"errors":0,"checks":{"LowercaseKey":1},"files":{".env":1}}}
+"#;
+
+    let output = test_dir.command_output_with_args(["--json-file", "report.json"]);
+
+    assert_eq!(expected_output.as_bytes(), output.as_slice());
+    assert_eq!(
+        expected_report,
+        fs::read_to_string(report_path).expect("read report")
+    );
+
+    test_dir.close();
+}
+
+#[test]
+fn checkstyle_format() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\n");
+    let expected_output = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <checkstyle version=\"4.3\">\n  \
+        <file name=\".env\">\n    \
+        <error line=\"1\" column=\"1\" severity=\"warning\" message=\"The foo key should be in uppercase\" source=\"LowercaseKey\"/>\n  \
+        </file>\n\
+        </checkstyle>\n",
+    );
+
+    test_dir.test_command_fail_with_args(["--format", "checkstyle"], expected_output);
+}
+
+#[test]
+fn checkstyle_format_without_warnings() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+    let expected_output = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n</checkstyle>\n",
+    );
+
+    test_dir.test_command_success_with_args_and_output(["--format", "checkstyle"], expected_output);
+}
+
+#[test]
+fn tap_format() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\n");
+    let expected_output = String::from(
+        "TAP version 13\n\
+        1..1\n\
+        not ok 1 - .env:1 LowercaseKey\n  \
+        ---\n  \
+        file: \".env\"\n  \
+        line: 1\n  \
+        column: 1\n  \
+        check: LowercaseKey\n  \
+        severity: warning\n  \
+        message: \"The foo key should be in uppercase\"\n  \
+        ...\n\
+        # pass 0\n\
+        # fail 1\n",
+    );
+
+    test_dir.test_command_fail_with_args(["--format", "tap"], expected_output);
+}
+
+#[test]
+fn tap_format_without_warnings() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+    let expected_output =
+        String::from("TAP version 13\n1..1\nok 1 - No problems found\n# pass 1\n# fail 0\n");
+
+    test_dir.test_command_success_with_args_and_output(["--format", "tap"], expected_output);
+}
+
+#[test]
+fn lsp_json_format() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\nFOO=\"a\"b\"\n");
+    let args = &["--format", "lsp-json", "--skip", "QuoteCharacter"];
+    let expected_output = String::from(
+        "[{\"file\":\".env\",\"diagnostics\":[\
+        {\"range\":{\"start\":{\"line\":1,\"character\":0},\"end\":{\"line\":1,\"character\":9}},\"severity\":1,\"code\":\"DuplicatedKey\",\"source\":\"dotenv-linter\",\"message\":\"The FOO key is duplicated with a different value\"},\
+        {\"range\":{\"start\":{\"line\":1,\"character\":6},\"end\":{\"line\":1,\"character\":7}},\"severity\":2,\"code\":\"UnescapedQuote\",\"source\":\"dotenv-linter\",\"message\":\"The FOO key has an unescaped quote at column 7, inner quotes should be escaped with a backslash\"}\
+        ]}]\n",
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,17 @@
+use crate::common::TestDir;
+
+#[test]
+fn groups_warnings_by_file() {
+    let test_dir = TestDir::new();
+    let testfile_1 = test_dir.create_testfile(".env", "A=B\nFOO=BAR \nbar=baz\n");
+    let testfile_2 = test_dir.create_testfile(".env.test", "FOO=BAR\nFOO=BAR\n");
+
+    let args = &["--group-by-file"];
+    let expected_output = format!(
+        "{}\n  2 TrailingWhitespace: Trailing whitespace detected\n  3 LowercaseKey: The bar key should be in uppercase\n\n{}\n  2 DuplicatedKey: The FOO key is duplicated with the same value\n\nFound 3 problems\n",
+        testfile_1.shortname_as_str(),
+        testfile_2.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,54 @@
+use crate::common::TestDir;
+
+#[test]
+fn merges_in_order() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "DB_HOST=localhost\nDB_PORT=5432\n");
+    testdir.create_testfile(".env.local", "DB_HOST=db.internal\nDEBUG=true\n");
+    let expected_output = String::from(
+        "DB_HOST=\"db.internal\" from .env.local:1, overrides .env:1\nDB_PORT=\"5432\" from .env:2\nDEBUG=\"true\" from .env.local:2\n",
+    );
+
+    testdir.test_command_success_with_args_and_output(
+        ["--merge", ".env", ".env.local"],
+        expected_output,
+    );
+}
+
+#[test]
+fn lint_merged() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "B=1\nA=2\n");
+    testdir.create_testfile(".env.local", "B=3\n");
+    let expected_output = String::from(
+        "B=\"3\" from .env.local:1, overrides .env:1\nA=\"2\" from .env:2\n\nmerged:2 UnorderedKey: The A key should go before the B key\n\nFound 1 problem\n",
+    );
+
+    testdir.test_command_fail_with_args(
+        ["--merge", "--lint-merged", ".env", ".env.local"],
+        expected_output,
+    );
+}
+
+#[test]
+fn merge_json() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "FOO=bar\n");
+    testdir.create_testfile(".env.local", "FOO=baz\n");
+    let expected_output = String::from(
+        "[{\"key\":\"FOO\",\"value\":\"baz\",\"file\":\".env.local\",\"line\":1,\"overrides\":[\".env:1\"]}]\n",
+    );
+
+    testdir.test_command_success_with_args_and_output(
+        ["--merge", "--format", "json", ".env", ".env.local"],
+        expected_output,
+    );
+}
+
+#[test]
+fn merge_directory() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "FOO=bar\n");
+
+    testdir.test_command_error_with_args(["--merge", "."], "Only files can be merged");
+}
@@ -1,2 +1,23 @@
+mod changed_within;
+mod compose;
+mod count;
+mod diff_only;
+mod dump;
+mod exit_zero;
+mod fail_fast;
+mod fix_stdin;
+mod format;
+mod group_by_file;
+mod merge;
+mod no_skip;
+mod no_stream;
+mod patch;
 mod quiet;
 mod recursive;
+mod resolve;
+mod short_circuit;
+mod skip_generated;
+mod sort_output;
+mod stats;
+mod strict_ignores;
+mod suggest;
@@ -0,0 +1,27 @@
+use crate::common::TestDir;
+
+#[test]
+fn no_skip() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", "skip: [LowercaseKey, UnorderedKey]\n");
+    let testfile = test_dir.create_testfile(".env", "foo=bar\nBAR=baz\n");
+    let expected_output = format!(
+        "{0}:1 LowercaseKey: The foo key should be in uppercase\n{0}:2 UnorderedKey: The BAR key should go before the foo key\n\nFound 2 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(["--no-skip"], expected_output);
+}
+
+#[test]
+fn no_skip_with_skip() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", "skip: [LowercaseKey, UnorderedKey]\n");
+    let testfile = test_dir.create_testfile(".env", "foo=bar\nBAR=baz\n");
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(["--no-skip", "--skip", "UnorderedKey"], expected_output);
+}
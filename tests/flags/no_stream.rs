@@ -0,0 +1,28 @@
+use crate::common::TestDir;
+
+#[test]
+fn holds_back_warnings_with_checks_of_several_files() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env.a", "A=1\n");
+    testdir.create_testfile(".env.b", "A=1");
+    testdir.create_testfile(".env.c", "b=1\n");
+    let args = &["--enable", "InconsistentEndingNewline"];
+    let expected_output = String::from(
+        ".env.b:1 EndingBlankLine: No blank line at the end of the file\n.env.b:1 InconsistentEndingNewline: The file doesn't end with a newline, unlike most of the checked files\n.env.c:1 LowercaseKey: The b key should be in uppercase\n\nFound 3 problems\n",
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn sorts_all_warnings_by_paths() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "DEBUG=true\n");
+    testdir.create_testfile(".env.prod", "DEBUG=0\nfoo=1\n");
+    let args = &["--enable", "InconsistentValueType", "--no-stream"];
+    let expected_output = String::from(
+        ".env.prod:1 InconsistentValueType: The DEBUG key has the int type, but the bool type in .env\n.env.prod:2 LowercaseKey: The foo key should be in uppercase\n\nFound 2 problems\n",
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
@@ -0,0 +1,23 @@
+use crate::common::TestDir;
+
+#[test]
+fn patch() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=bar\nBAR=baz\n");
+    let expected_output = "diff --git a/.env b/.env\n--- a/.env\n+++ b/.env\n@@ -1,2 +1,2 @@\n-foo=bar\n+FOO=bar\n BAR=baz\n";
+
+    let output = test_dir.command_output_with_args(["--patch"]);
+
+    assert_eq!(String::from_utf8_lossy(&output), expected_output);
+    assert_eq!(testfile.contents().as_str(), "foo=bar\nBAR=baz\n");
+
+    test_dir.close();
+}
+
+#[test]
+fn patch_without_fixes() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "BAR=baz\nFOO=bar\n");
+
+    test_dir.test_command_success_with_args_and_output(["--patch"], String::new());
+}
@@ -33,14 +33,14 @@ fn checks_files_in_deep_subdirs() {
 
     let args = &["--recursive"];
     let expected_output = format!(
-        "{}:2 UnorderedKey: The BAR key should go before the FOO key\n{}:1 EndingBlankLine: No blank line at the end of the file\n\nFound 2 problems\n",
-        Path::new(&test_dir.relative_path(&test_subdir_2))
-            .join(testfile_2.shortname_as_str())
-            .to_str()
-            .expect("multi-platform path to test .env file"),
+        "{}:1 EndingBlankLine: No blank line at the end of the file\n{}:2 UnorderedKey: The BAR key should go before the FOO key\n\nFound 2 problems\n",
         Path::new(&test_dir.relative_path(&test_subdir_3))
             .join(testfile_3.shortname_as_str())
             .to_str()
+            .expect("multi-platform path to test .env file"),
+        Path::new(&test_dir.relative_path(&test_subdir_2))
+            .join(testfile_2.shortname_as_str())
+            .to_str()
             .expect("multi-platform path to test .env file")
     );
 
@@ -80,3 +80,83 @@ fn checks_recursive_with_exclude_subdir() {
 
     test_dir.test_command_fail_with_args(args, expected_output);
 }
+
+#[test]
+fn output_is_deterministic() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=\nBAR=1\n");
+    test_dir.create_testfile(".env.b", "B=1\nA=1\n");
+    test_dir.create_testfile(".env.a", "A=1\nA=2\n");
+
+    for index in 0..3 {
+        let test_subdir = test_dir.subdir();
+        test_subdir.create_testfile(".env", &format!("KEY_{}\n", index));
+        test_subdir.create_testfile(".env.local", " FOO=\n");
+    }
+
+    let args = &["--recursive"];
+    let first_output = test_dir.command_output_with_args(args);
+    let second_output = test_dir.command_output_with_args(args);
+
+    assert!(!first_output.is_empty());
+    assert_eq!(first_output, second_output);
+
+    test_dir.close();
+}
+
+#[test]
+fn checks_recursive_from_config() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", "recursive: true\n");
+    test_dir.create_testfile("correct.env", "FOO=BAR\n");
+    let test_subdir = test_dir.subdir();
+    let testfile_2 = test_subdir.create_testfile(".incorrect.env", "1BAR=\n");
+
+    let expected_output = format!(
+        "{}:1 LeadingCharacter: Invalid leading character detected\n\nFound 1 problem\n",
+        Path::new(&test_dir.relative_path(&test_subdir))
+            .join(testfile_2.shortname_as_str())
+            .to_str()
+            .expect("multi-platform path to test .env file")
+    );
+
+    test_dir.test_command_fail(expected_output);
+}
+
+#[test]
+fn no_recursive_overrides_config() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", "recursive: true\n");
+    test_dir.create_testfile("correct.env", "FOO=BAR\n");
+    let test_subdir = test_dir.subdir();
+    test_subdir.create_testfile(".incorrect.env", "1BAR=\n");
+
+    test_dir.test_command_success_with_args(["--no-recursive"]);
+}
+
+#[test]
+fn recursive_overrides_config() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", "recursive: false\n");
+    let test_subdir = test_dir.subdir();
+    let testfile = test_subdir.create_testfile(".incorrect.env", "1BAR=\n");
+
+    let args = &["--recursive"];
+    let expected_output = format!(
+        "{}:1 LeadingCharacter: Invalid leading character detected\n\nFound 1 problem\n",
+        Path::new(&test_dir.relative_path(&test_subdir))
+            .join(testfile.shortname_as_str())
+            .to_str()
+            .expect("multi-platform path to test .env file")
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn recursive_conflicts_with_no_recursive() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\n");
+
+    test_dir.test_command_error_with_args(["--recursive", "--no-recursive"], "cannot be used with");
+}
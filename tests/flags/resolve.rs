@@ -0,0 +1,16 @@
+use crate::common::TestDir;
+
+#[test]
+fn resolve() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(
+        ".env",
+        "HOST=localhost\nurl=http://${HOST}/$PREFIX\nDB_PASSWORD=secret\nDSN=postgres://${DB_PASSWORD}@$HOST\n",
+    );
+    let expected_output = format!(
+        "{0}:2 LowercaseKey: The url key should be in uppercase\n  = http://localhost/$PREFIX\n{0}:3 UnorderedKey: The DB_PASSWORD key should go before the HOST key\n{0}:4 UnorderedKey: The DSN key should go before the HOST key\n\nFound 3 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(["--resolve"], expected_output);
+}
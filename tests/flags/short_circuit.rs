@@ -0,0 +1,29 @@
+use crate::common::TestDir;
+
+#[test]
+fn short_circuit() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", " foo-bar=x\n");
+    let expected_output = format!(
+        "{}:1 LeadingCharacter: Invalid leading character detected\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(["--short-circuit"], expected_output);
+}
+
+#[test]
+fn short_circuit_with_priority() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(
+        ".dotenv-linter.yml",
+        "short_circuit: true\npriority: [LowercaseKey, IncorrectDelimiter]\n",
+    );
+    let testfile = test_dir.create_testfile(".env", " foo-bar=x\n");
+    let expected_output = format!(
+        "{0}:1 LeadingCharacter: Invalid leading character detected\n{0}:1 LowercaseKey: The foo-bar key should be in uppercase\n\nFound 2 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail(expected_output);
+}
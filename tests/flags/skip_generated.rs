@@ -0,0 +1,53 @@
+use crate::common::TestDir;
+
+#[test]
+fn skips_generated_file() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "# GENERATED - DO NOT EDIT\nfoo=bar\n");
+    let testfile = test_dir.create_testfile(".env.local", "foo=bar\n");
+
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(["--skip-generated"], expected_output);
+}
+
+#[test]
+fn checks_generated_file_by_default() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "# GENERATED - DO NOT EDIT\nfoo=bar\n");
+
+    let expected_output = format!(
+        "{}:2 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail(expected_output);
+}
+
+#[test]
+fn reports_skipped_file_with_verbose() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(
+        ".env",
+        "# Code generated by vault-sync, do not edit\nfoo=bar\n",
+    );
+
+    let stderr = test_dir.command_stderr_with_args(["--skip-generated", "--verbose"]);
+
+    assert_eq!("Skipped the generated file .env\n", stderr);
+}
+
+#[test]
+fn custom_marker_from_config() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(
+        ".dotenv-linter.yml",
+        "generated:\n  skip: true\n  marker: '# written by deploy*'\n",
+    );
+    test_dir.create_testfile(".env", "# Written by deploy.sh\nfoo=bar\n");
+
+    test_dir.test_command_success();
+}
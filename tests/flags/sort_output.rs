@@ -0,0 +1,58 @@
+use crate::common::TestDir;
+
+#[test]
+fn groups_warnings_by_check() {
+    let test_dir = TestDir::new();
+    let testfile_1 = test_dir.create_testfile(".env", "foo=bar\nB=1\nA=2\n");
+    let testfile_2 = test_dir.create_testfile(".env.test", "bar=baz\n");
+
+    let args = &["--sort-output", "by-check"];
+    let expected_output = format!(
+        "LowercaseKey (2)\n  {0}:1 LowercaseKey: The foo key should be in uppercase\n  {1}:1 LowercaseKey: The bar key should be in uppercase\n\nUnorderedKey (2)\n  {0}:2 UnorderedKey: The B key should go before the foo key\n  {0}:3 UnorderedKey: The A key should go before the B key\n\nFound 4 problems\n",
+        testfile_1.shortname_as_str(),
+        testfile_2.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn sorts_json_warnings_by_check() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\nB=1\nA=2\nbar=baz\n");
+
+    let args = &["--sort-output", "by-check", "--format", "json"];
+    let output = test_dir.command_output_with_args(args);
+    let json: serde_json::Value = serde_json::from_slice(&output).expect("json output");
+
+    let actual: Vec<(&str, u64)> = json["warnings"]
+        .as_array()
+        .expect("warnings")
+        .iter()
+        .map(|w| (w["check"].as_str().unwrap(), w["line"].as_u64().unwrap()))
+        .collect();
+    assert_eq!(
+        vec![
+            ("LowercaseKey", 1),
+            ("LowercaseKey", 4),
+            ("UnorderedKey", 2),
+            ("UnorderedKey", 3),
+            ("UnorderedKey", 4),
+        ],
+        actual
+    );
+}
+
+#[test]
+fn sorts_by_file_by_default() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=bar\nB=1\n");
+
+    let args = &["--sort-output", "by-file"];
+    let expected_output = format!(
+        "{0}:1 LowercaseKey: The foo key should be in uppercase\n{0}:2 UnorderedKey: The B key should go before the foo key\n\nFound 2 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
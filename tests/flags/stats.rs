@@ -0,0 +1,12 @@
+use crate::common::TestDir;
+
+#[test]
+fn prints_stats_to_stderr() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\n");
+
+    test_dir.test_command_error_with_args(
+        ["--stats"],
+        "Files: 1\nLines: 2\nChecks:\n  CircularSubstitution: ",
+    );
+}
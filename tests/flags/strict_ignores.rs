@@ -0,0 +1,28 @@
+use crate::common::TestDir;
+
+#[test]
+fn ignore_comments_suppress_warnings() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(
+        ".env",
+        "# dotenv-linter:off LowercaseKey\nbar=foo\n# dotenv-linter:on LowercaseKey\n",
+    );
+
+    test_dir.test_command_success();
+}
+
+#[test]
+fn strict_ignores_reports_suppressed_warnings() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "# dotenv-linter:off\nbar=foo\nFOO=BAR\n");
+
+    test_dir.test_command_error_with_args(["--strict-ignores"], "Suppressed by ignore comments: 2");
+}
+
+#[test]
+fn strict_ignores_without_suppressed_warnings() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "BAR=foo\nFOO=BAR\n");
+
+    test_dir.test_command_success_with_args(["--strict-ignores"]);
+}
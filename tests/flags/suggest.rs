@@ -0,0 +1,18 @@
+use crate::common::TestDir;
+
+#[test]
+fn suggest() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "FOO=BAR\nbar=foo\nFOO-BAR=1");
+    let expected_output = format!(
+        "{0}:2 LowercaseKey: The bar key should be in uppercase\n  > BAR=foo\n{0}:3 EndingBlankLine: No blank line at the end of the file\n  > FOO-BAR=1\n  > \n{0}:3 IncorrectDelimiter: The FOO-BAR key has incorrect delimiter\n{0}:3 UnorderedKey: The FOO-BAR key should go before the bar key\n\nFound 4 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    let output = test_dir.command_output_with_args(["--suggest"]);
+
+    assert_eq!(expected_output.as_bytes(), output.as_slice());
+    assert_eq!(testfile.contents(), "FOO=BAR\nbar=foo\nFOO-BAR=1");
+
+    test_dir.close();
+}
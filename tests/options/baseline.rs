@@ -0,0 +1,72 @@
+use crate::common::TestDir;
+
+#[test]
+fn baseline_suppresses_existing_warnings() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "abc=bar\nfoo=bar\n");
+    let baseline = test_dir.create_testfile(
+        "baseline.json",
+        r#"{"warnings": [
+            {"file": ".env", "line": 1, "check": "LowercaseKey", "message": "The abc key should be in uppercase"}
+        ]}"#,
+    );
+    let args = &["--baseline", baseline.as_str(), "--", testfile.as_str()];
+    let expected_output = format!(
+        "{}:2 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn baseline_ignoring_lines() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "\nfoo=bar\n");
+    let baseline = test_dir.create_testfile(
+        "baseline.json",
+        r#"{"warnings": [
+            {"file": ".env", "line": 1, "check": "LowercaseKey", "message": "The foo key should be in uppercase"},
+            {"file": ".env", "line": 1, "check": "ExtraBlankLine", "message": "Extra blank line detected"}
+        ]}"#,
+    );
+    let args = &["--baseline", baseline.as_str(), "--baseline-ignore-lines"];
+
+    test_dir.test_command_success_with_args(args);
+}
+
+#[test]
+fn write_baseline() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\n");
+    let expected_output = String::from("Baseline with 1 warning(s) written to baseline.json\n");
+
+    test_dir.test_command_success_with_args_and_output(
+        ["--write-baseline", "baseline.json"],
+        expected_output,
+    );
+}
+
+#[test]
+fn baseline_matches_fingerprints_of_shifted_lines() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\nfoo=bar\n");
+    test_dir.command_output_with_args(["--write-baseline", "baseline.json"]);
+
+    let testfile = test_dir.create_testfile(".env", "# Comment\nfoo=bar\nfoo=bar\nfoo=bar\n");
+    let expected_output = format!(
+        "{}:4 DuplicatedKey: The foo key is duplicated with the same value\n{0}:4 LowercaseKey: The foo key should be in uppercase\n\nFound 2 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(["--baseline", "baseline.json"], expected_output);
+}
+
+#[test]
+fn invalid_baseline_file() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\n");
+    let baseline = test_dir.create_testfile("baseline.json", "[");
+
+    test_dir.test_command_error_with_args(["--baseline", baseline.as_str()], "Invalid baseline ");
+}
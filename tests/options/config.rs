@@ -0,0 +1,219 @@
+use crate::common::TestDir;
+
+#[test]
+fn skip_checks_from_config_file() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\nBAR=FOO\n");
+    test_dir.create_testfile(".dotenv-linter.yml", "skip:\n  - UnorderedKey\n");
+
+    test_dir.test_command_success();
+}
+
+#[test]
+fn config_from_argument() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "SERVICENAME=api\nSERVICE_NAME=api\n");
+    let config = test_dir.create_testfile(
+        "config.yml",
+        "enable: [SeparatorConsistency]\nchecks:\n  SeparatorConsistency:\n    style: underscore\n",
+    );
+
+    let args = &["--config", config.as_str()];
+    let expected_output = format!(
+        "{}:1 SeparatorConsistency: The SERVICENAME key has an inconsistent separator style\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn invalid_config_file() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\n");
+    test_dir.create_testfile(".dotenv-linter.yml", "skip: UnorderedKey\n");
+
+    let args: &[&str; 0] = &[];
+    test_dir.test_command_error_with_args(args, "Invalid config ");
+}
+
+#[test]
+fn missing_config_file() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\n");
+
+    test_dir
+        .test_command_error_with_args(["--config", "missing.yml"], "Failed to read missing.yml");
+}
+
+#[test]
+fn custom_template_from_config_file() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=BAR\n");
+    test_dir.create_testfile(
+        ".dotenv-linter.yml",
+        "templates:\n  LowercaseKey: \"Key {} is not uppercase, see https://example.com/env\"\n",
+    );
+
+    let expected_output = format!(
+        "{}:1 LowercaseKey: Key foo is not uppercase, see https://example.com/env\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail(expected_output);
+}
+
+#[test]
+fn custom_template_without_position_placeholder() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foO=BAR\n");
+    test_dir.create_testfile(
+        ".dotenv-linter.yml",
+        "templates:\n  LowercaseKey: \"Key {1} must be uppercase (JIRA-1)\"\n",
+    );
+
+    let expected_output = format!(
+        "{}:1 LowercaseKey: Key foO must be uppercase (JIRA-1)\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail(expected_output);
+}
+
+#[test]
+fn custom_template_without_placeholder() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\n");
+    test_dir.create_testfile(
+        ".dotenv-linter.yml",
+        "templates:\n  LowercaseKey: \"Key is not uppercase\"\n",
+    );
+
+    let args: &[&str; 0] = &[];
+    test_dir.test_command_error_with_args(
+        args,
+        "The template for the LowercaseKey check should contain the {1} placeholder",
+    );
+}
+
+#[test]
+fn unknown_check_in_config_file() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\n");
+    test_dir.create_testfile(".dotenv-linter.yml", "skip:\n  - UnorderedKeys\n");
+
+    let args: &[&str; 0] = &[];
+    test_dir.test_command_error_with_args(
+        args,
+        "Unknown check in skip: UnorderedKeys, did you mean UnorderedKey?",
+    );
+}
+
+#[test]
+fn toml_config_file() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\nBAR=FOO\n");
+    test_dir.create_testfile(".dotenv-linter.toml", "skip = [\"UnorderedKey\"]\n");
+
+    test_dir.test_command_success();
+}
+
+#[test]
+fn json_config_from_argument() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\nBAR=FOO\n");
+    let config = test_dir.create_testfile("config.json", "{\"skip\": [\"UnorderedKey\"]}\n");
+
+    let args = &["--config", config.as_str()];
+    test_dir.test_command_success_with_args(args);
+}
+
+#[test]
+fn malformed_json_config_file() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=BAR\n");
+    test_dir.create_testfile(".dotenv-linter.json", "{\"skip\": [\n");
+
+    let args: &[&str; 0] = &[];
+    test_dir.test_command_error_with_args(args, ".dotenv-linter.json: EOF while parsing");
+}
+
+#[test]
+fn config_from_parent_dir() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", "skip:\n  - UnorderedKey\n");
+    let subdir = test_dir.subdir();
+    subdir.create_testfile(".env", "FOO=BAR\nBAR=FOO\n");
+
+    subdir.test_command_success();
+}
+
+#[test]
+fn no_config() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "FOO=BAR\nBAR=FOO\n");
+    test_dir.create_testfile(".dotenv-linter.yml", "skip:\n  - UnorderedKey\n");
+
+    let args = &["--no-config"];
+    let expected_output = format!(
+        "{}:2 UnorderedKey: The BAR key should go before the FOO key\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn print_config_schema() {
+    let test_dir = TestDir::new();
+    let output = test_dir.command_output_with_args(["--print-config-schema"]);
+    let schema: serde_json::Value = serde_json::from_slice(&output).expect("JSON schema");
+
+    assert_eq!("dotenv-linter config", schema["title"]);
+    assert!(schema["properties"]["checks"]["properties"]["PortValue"].is_object());
+
+    test_dir.close();
+}
+
+#[test]
+fn print_config() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(
+        ".dotenv-linter.yml",
+        "skip: [UnorderedKey]\nprofiles:\n  strict:\n    enable: [ShellUnsafeValue]\n",
+    );
+    let args = [
+        "--print-config",
+        "--format",
+        "json",
+        "--profile",
+        "strict",
+        "--skip",
+        "LowercaseKey",
+    ];
+    let output = test_dir.command_output_with_args(args);
+    let config: serde_json::Value = serde_json::from_slice(&output).expect("JSON config");
+
+    assert_eq!(
+        serde_json::json!(["UnorderedKey", "LowercaseKey"]),
+        config["skip"]
+    );
+    assert_eq!(serde_json::json!(["ShellUnsafeValue"]), config["enable"]);
+    let active_checks = config["active_checks"].as_array().expect("active checks");
+    assert!(active_checks.contains(&serde_json::json!("ShellUnsafeValue")));
+    assert!(!active_checks.contains(&serde_json::json!("LowercaseKey")));
+    assert_eq!(2, config["checks"]["NumberedKey"]["min_group_size"]);
+
+    test_dir.close();
+}
+
+#[test]
+fn print_config_as_yaml() {
+    let test_dir = TestDir::new();
+    let output = test_dir.command_output_with_args(["--print-config", "--only", "LowercaseKey"]);
+
+    assert!(String::from_utf8_lossy(&output)
+        .starts_with("---\nactive_checks:\n  - LowercaseKey\nskip: []\n"));
+
+    test_dir.close();
+}
@@ -0,0 +1,29 @@
+use crate::common::TestDir;
+
+#[test]
+fn optional_check_is_not_run_by_default() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "FOO=\"BAR\"\n");
+    test_dir.test_command_success_with_args(["--skip", "QuoteCharacter", "--", testfile.as_str()]);
+}
+
+#[test]
+fn enable_optional_check() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "FOO=\"BAR\"\n");
+
+    let args = &[
+        "--enable",
+        "RedundantQuotes",
+        "--skip",
+        "QuoteCharacter",
+        "--",
+        testfile.as_str(),
+    ];
+    let expected_output = format!(
+        "{}:1 RedundantQuotes: The FOO key has a value with redundant quotes\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
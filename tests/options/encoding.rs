@@ -0,0 +1,30 @@
+use crate::common::TestDir;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn latin1_file() {
+    let testdir = TestDir::new();
+    let path = Path::new(testdir.as_str()).join(".env");
+    fs::write(&path, b"foo=caf\xe9\n").expect("write latin1 file");
+
+    let args = &["--encoding", "latin1", ".env"];
+    let expected_output = String::from(
+        ".env:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn replaced_characters() {
+    let testdir = TestDir::new();
+    let path = Path::new(testdir.as_str()).join(".env");
+    fs::write(&path, b"foo=\x81\n").expect("write windows-1252 file");
+
+    let args = &["--encoding", "windows-1252", ".env"];
+    testdir.test_command_error_with_args(
+        args,
+        "Some characters of .env can't be decoded, they were replaced with U+FFFD",
+    );
+}
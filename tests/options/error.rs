@@ -0,0 +1,61 @@
+use crate::common::TestDir;
+
+#[test]
+fn error_severity_is_printed() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "FOO=BAR\nfoo=bar\n");
+    let args = &["--error", "LowercaseKey", "--skip", "UnorderedKey"];
+    let expected_output = format!(
+        "{}:2 [error] LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn warnings_within_max_warnings() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "FOO=BAR\nfoo=bar\n");
+    let args = &["--max-warnings", "1", "--skip", "UnorderedKey"];
+    let expected_output = format!(
+        "{}:2 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_success_with_args_and_output(args, expected_output);
+}
+
+#[test]
+fn errors_fail_with_max_warnings() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "FOO=BAR\nfoo=bar\n");
+    let args = &[
+        "--max-warnings",
+        "5",
+        "--error",
+        "LowercaseKey",
+        "--skip",
+        "UnorderedKey",
+    ];
+    let expected_output = format!(
+        "{}:2 [error] LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn error_severity_from_config_file() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", "severity:\n  DuplicatedKey: error\n");
+    let testfile = test_dir.create_testfile(".env", "FOO=BAR\nFOO=BAR\n");
+    let args = &["--max-warnings", "1"];
+    let expected_output = format!(
+        "{}:2 [error] DuplicatedKey: The FOO key is duplicated with the same value\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
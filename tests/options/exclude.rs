@@ -4,7 +4,7 @@ use crate::common::TestDir;
 fn exclude_one_file() {
     let test_dir = TestDir::new();
     let testfile = test_dir.create_testfile(".env", " FOO=\n");
-    test_dir.test_command_success_with_args(&["--exclude", testfile.as_str()]);
+    test_dir.test_command_success_with_args(["--exclude", testfile.as_str()]);
 }
 
 #[test]
@@ -13,12 +13,7 @@ fn exclude_two_files() {
     let testfile_1 = test_dir.create_testfile(".env", " FOO=\n");
     let testfile_2 = test_dir.create_testfile(".loacl.env", " BAR=\n");
 
-    test_dir.test_command_success_with_args(&[
-        "-e",
-        testfile_1.as_str(),
-        "-e",
-        testfile_2.as_str(),
-    ]);
+    test_dir.test_command_success_with_args(["-e", testfile_1.as_str(), "-e", testfile_2.as_str()]);
 }
 
 #[test]
@@ -35,3 +30,31 @@ fn exclude_one_file_check_one_file() {
 
     test_dir.test_command_fail_with_args(args, expected_output);
 }
+
+#[test]
+fn exclude_files_from_ignore_file() {
+    let test_dir = TestDir::new();
+    let testfile_to_check = test_dir.create_testfile(".env", " FOO=\n");
+    test_dir.create_testfile(".env.test", " BAR=\n");
+    test_dir.create_testfile(".dotenv-linterignore", "# Test files\n\n*.test\n");
+
+    let expected_output = format!(
+        "{}:1 LeadingCharacter: Invalid leading character detected\n\nFound 1 problem\n",
+        testfile_to_check.shortname_as_str()
+    );
+
+    test_dir.test_command_fail(expected_output);
+}
+
+#[test]
+fn exclude_directory_from_ignore_file() {
+    let test_dir = TestDir::new();
+    let subdir = test_dir.subdir();
+    subdir.create_testfile(".env", " FOO=\n");
+    test_dir.create_testfile(
+        ".dotenv-linterignore",
+        &format!("{}/\n", test_dir.relative_path(&subdir)),
+    );
+
+    test_dir.test_command_success_with_args(["--recursive"]);
+}
@@ -0,0 +1,33 @@
+use crate::common::TestDir;
+
+#[test]
+fn explain_check() {
+    let test_dir = TestDir::new();
+    let expected_output = String::from(
+        "KeyWithoutValue\n\
+        \n\
+        A line with a key but without an equal sign is not an assignment. \
+        Loaders either skip it or fail to parse the file. \
+        An empty value should be written explicitly with an equal sign.\n\
+        \n\
+        Wrong:\n\
+        FOO\n\
+        \n\
+        Correct:\n\
+        FOO=\n\
+        FOO=BAR\n\
+        \n\
+        Fix: Can be fixed automatically with the --fix argument\n",
+    );
+
+    test_dir.test_command_success_with_args_and_output(
+        ["--explain", "KeyWithoutValue"],
+        expected_output,
+    );
+}
+
+#[test]
+fn explain_unknown_check() {
+    let test_dir = TestDir::new();
+    test_dir.test_command_fail_with_args(["--explain", "UnknownCheck"], String::new());
+}
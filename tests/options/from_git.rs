@@ -0,0 +1,67 @@
+use crate::common::TestDir;
+use std::process::Command;
+
+// Commits all files of the test directory to a new git repository
+fn commit_all(test_dir: &TestDir) {
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(test_dir.as_str())
+            .output()
+            .expect("git")
+            .status;
+        assert!(status.success(), "git {:?}", args);
+    };
+
+    git(&["init", "--quiet"]);
+    git(&["add", "--all"]);
+    git(&["commit", "--quiet", "--message", "Initial commit"]);
+}
+
+#[test]
+fn checks_file_from_git() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=bar\n");
+    commit_all(&test_dir);
+    std::fs::write(testfile.as_str(), "FOO=bar\n").expect("write file");
+
+    let args = &["--from-git", "HEAD:.env"];
+    let expected_output = String::from(
+        "HEAD:.env:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn checks_file_from_git_with_paths() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+    commit_all(&test_dir);
+    test_dir.create_testfile(".env.local", "BAR=1\nBAR=2\n");
+
+    let args = &["--from-git", "HEAD:.env", ".env.local"];
+    let expected_output =
+        String::from(".env.local:2 [error] DuplicatedKey: The BAR key is duplicated with a different value\n\nFound 1 problem\n");
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn missing_git_object() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+    commit_all(&test_dir);
+
+    let args = &["--from-git", "HEAD:.env.missing"];
+    test_dir.test_command_error_with_args(args, "Failed to read HEAD:.env.missing from git");
+}
+
+#[test]
+fn invalid_git_object() {
+    let test_dir = TestDir::new();
+
+    let args = &["--from-git", ".env"];
+    test_dir.test_command_error_with_args(args, "Invalid --from-git .env: expected REV:PATH");
+}
@@ -1 +1,14 @@
+mod baseline;
+mod config;
+mod enable;
+mod encoding;
+mod error;
 mod exclude;
+mod explain;
+mod from_git;
+mod only;
+mod output_template;
+mod path_base;
+mod pattern;
+mod profile;
+mod skip_in;
@@ -0,0 +1,31 @@
+use crate::common::TestDir;
+
+#[test]
+fn only_checks() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", "foo=\"bar\"\nBAR=1\n");
+
+    let args = &[
+        "--only",
+        "LowercaseKey,RedundantQuotes",
+        "--",
+        testfile.as_str(),
+    ];
+    let expected_output = format!(
+        "{0}:1 LowercaseKey: The foo key should be in uppercase\n{0}:1 RedundantQuotes: The foo key has a value with redundant quotes\n\nFound 2 problems\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn unknown_check() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+
+    test_dir.test_command_error_with_args(
+        ["--only", "LowercaseKeys"],
+        "Unknown check in --only: LowercaseKeys, did you mean LowercaseKey?",
+    );
+}
@@ -0,0 +1,27 @@
+use crate::common::TestDir;
+
+#[test]
+fn output_template() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "foo=bar\n");
+
+    let args = &[
+        "--output-template",
+        "{file}:{line}:{column}: {check} ({severity}) {message}",
+    ];
+    let expected_output = String::from(
+        ".env:1:1: LowercaseKey (warning) The foo key should be in uppercase\n\nFound 1 problem\n",
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn unknown_placeholder() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=bar\n");
+
+    let args = &["--output-template", "{path}: {message}"];
+    test_dir
+        .test_command_error_with_args(args, "Unknown placeholder {path} in the output template");
+}
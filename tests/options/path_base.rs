@@ -0,0 +1,24 @@
+use crate::common::TestDir;
+
+#[test]
+fn paths_relative_to_subdir() {
+    let testdir = TestDir::new();
+    let subdir = testdir.subdir();
+    subdir.create_testfile(".env", "foo=bar\n");
+    let subdir_path = testdir.relative_path(&subdir);
+
+    let args = &["--path-base", subdir_path.as_str(), subdir_path.as_str()];
+    let expected_output = String::from(
+        ".env:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn missing_base_dir() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "FOO=bar\n");
+
+    testdir.test_command_error_with_args(["--path-base", "missing"], "Invalid --path-base missing");
+}
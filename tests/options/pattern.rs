@@ -0,0 +1,54 @@
+use crate::common::TestDir;
+
+#[test]
+fn checks_files_matching_patterns() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", " FOO=\n");
+    let testfile = test_dir.create_testfile("app.cfg", " BAR=\n");
+    test_dir.create_testfile("app.txt", " BAZ=\n");
+
+    let args = &["--pattern", "*.cfg"];
+    let expected_output = format!(
+        "{}:1 LeadingCharacter: Invalid leading character detected\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn patterns_from_config() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", "patterns: [\"secrets.*\"]\n");
+    test_dir.create_testfile(".env", " FOO=\n");
+    let testfile = test_dir.create_testfile("secrets.env", "bar=\n");
+
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The bar key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail(expected_output);
+}
+
+#[test]
+fn explicit_files_bypass_patterns() {
+    let test_dir = TestDir::new();
+    let testfile = test_dir.create_testfile(".env", " FOO=\n");
+
+    let args = &[testfile.as_str(), "--pattern", "*.cfg"];
+    let expected_output = format!(
+        "{}:1 LeadingCharacter: Invalid leading character detected\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn invalid_pattern() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".env", "FOO=\n");
+
+    test_dir.test_command_error_with_args(["--pattern", "[env"], "Invalid pattern [env");
+}
@@ -0,0 +1,39 @@
+use crate::common::TestDir;
+
+const CONFIG: &str = "profiles:\n  default:\n    skip: [UnorderedKey]\n  strict:\n    severity:\n      UnorderedKey: error\n";
+
+#[test]
+fn default_profile() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", CONFIG);
+    test_dir.create_testfile(".env", "FOO=BAR\nBAR=FOO\n");
+
+    test_dir.test_command_success();
+}
+
+#[test]
+fn profile_from_argument() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", CONFIG);
+    let testfile = test_dir.create_testfile(".env", "FOO=BAR\nBAR=FOO\n");
+
+    let args = &["--profile", "strict"];
+    let expected_output = format!(
+        "{}:2 [error] UnorderedKey: The BAR key should go before the FOO key\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    test_dir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn unknown_profile() {
+    let test_dir = TestDir::new();
+    test_dir.create_testfile(".dotenv-linter.yml", CONFIG);
+    test_dir.create_testfile(".env", "FOO=BAR\n");
+
+    test_dir.test_command_error_with_args(
+        ["--profile", "lenient"],
+        "Unknown profile lenient, the available profiles are: default, strict",
+    );
+}
@@ -0,0 +1,40 @@
+use crate::common::TestDir;
+
+#[test]
+fn skip_check_in_one_file() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env.example", "foo=\n");
+    let testfile = testdir.create_testfile(".env", "foo=bar\n");
+
+    let args = &["--skip-in", ".env.example:LowercaseKey", "--", "."];
+    let expected_output = format!(
+        "{}:1 LowercaseKey: The foo key should be in uppercase\n\nFound 1 problem\n",
+        testfile.shortname_as_str()
+    );
+
+    testdir.test_command_fail_with_args(args, expected_output);
+}
+
+#[test]
+fn unknown_check() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "FOO=bar\n");
+
+    let args = &["--skip-in", ".env:LowercaseKeys"];
+    testdir.test_command_error_with_args(
+        args,
+        "Unknown check in --skip-in: LowercaseKeys, did you mean LowercaseKey?",
+    );
+}
+
+#[test]
+fn invalid_value() {
+    let testdir = TestDir::new();
+    testdir.create_testfile(".env", "FOO=bar\n");
+
+    let args = &["--skip-in", "LowercaseKey"];
+    testdir.test_command_error_with_args(
+        args,
+        "Invalid --skip-in value LowercaseKey, expected GLOB:CHECK_NAME",
+    );
+}